@@ -0,0 +1,67 @@
+//! Shared object that interposes allocation functions and `getppid` when preloaded.
+//!
+//! ```sh
+//! cargo build --example preload
+//! LD_PRELOAD=target/debug/examples/libpreload.so sh -c 'echo $PPID'
+//! ```
+
+use std::{ffi, sync::atomic};
+
+/// Process ID reported by the interposed `getppid`.
+const PPID: libc::pid_t = 4242;
+
+/// Memory handed out by `calloc` while its real implementation is being resolved,
+/// since `dlsym` allocates its error buffer with it.
+static mut BOOTSTRAP: [u64; 1024] = [0; 1024];
+static BOOTSTRAP_USED: atomic::AtomicUsize = atomic::AtomicUsize::new(0);
+
+fn is_bootstrap(ptr: *mut ffi::c_void) -> bool {
+    let start = (&raw const BOOTSTRAP) as usize;
+    let end = start + size_of::<[u64; 1024]>();
+
+    (start..end).contains(&(ptr as usize))
+}
+
+dynlink_posix::interpose! {
+    unsafe extern "C" fn malloc(size: usize) -> *mut ffi::c_void as real {
+        real(size)
+    }
+}
+
+dynlink_posix::interpose! {
+    unsafe extern "C" fn calloc(count: usize, size: usize) -> *mut ffi::c_void as real {
+        real(count, size)
+    } else {
+        let words = match count.checked_mul(size) {
+            Some(bytes) => bytes.div_ceil(8),
+            None => return std::ptr::null_mut(),
+        };
+
+        let reserved = BOOTSTRAP_USED.fetch_update(
+            atomic::Ordering::Relaxed,
+            atomic::Ordering::Relaxed,
+            |offset| offset.checked_add(words).filter(|end| *end <= 1024),
+        );
+
+        match reserved {
+            // the buffer is zeroed and never reused
+            Ok(offset) => (&raw mut BOOTSTRAP).cast::<u64>().add(offset).cast(),
+            Err(_) => std::ptr::null_mut(),
+        }
+    }
+}
+
+dynlink_posix::interpose! {
+    unsafe extern "C" fn free(ptr: *mut ffi::c_void) as real {
+        if !is_bootstrap(ptr) {
+            real(ptr)
+        }
+    }
+}
+
+dynlink_posix::interpose! {
+    unsafe extern "C" fn getppid() -> libc::pid_t as real {
+        let _ = real();
+        PPID
+    }
+}
@@ -0,0 +1,368 @@
+use std::ffi;
+
+pub(crate) const DT_NULL: isize = 0;
+pub(crate) const DT_NEEDED: isize = 1;
+pub(crate) const DT_PLTRELSZ: isize = 2;
+pub(crate) const DT_HASH: isize = 4;
+pub(crate) const DT_STRTAB: isize = 5;
+pub(crate) const DT_SYMTAB: isize = 6;
+pub(crate) const DT_RELA: isize = 7;
+pub(crate) const DT_RELASZ: isize = 8;
+pub(crate) const DT_STRSZ: isize = 10;
+pub(crate) const DT_SONAME: isize = 14;
+pub(crate) const DT_REL: isize = 17;
+pub(crate) const DT_RELSZ: isize = 18;
+pub(crate) const DT_PLTREL: isize = 20;
+pub(crate) const DT_JMPREL: isize = 23;
+pub(crate) const DT_GNU_HASH: isize = 0x6ffffef5;
+pub(crate) const DT_FLAGS_1: isize = 0x6ffffffb;
+
+pub(crate) const DF_1_NODELETE: usize = 0x8;
+
+pub(crate) const STB_GLOBAL: u8 = 1;
+pub(crate) const STB_WEAK: u8 = 2;
+pub(crate) const STB_GNU_UNIQUE: u8 = 10;
+pub(crate) const SHN_UNDEF: u16 = 0;
+pub(crate) const SHN_ABS: u16 = 0xfff1;
+
+#[cfg(target_arch = "x86_64")]
+pub(crate) const R_JUMP_SLOT: u32 = 7;
+#[cfg(target_arch = "x86_64")]
+pub(crate) const R_GLOB_DAT: u32 = 6;
+
+#[cfg(target_arch = "x86")]
+pub(crate) const R_JUMP_SLOT: u32 = 7;
+#[cfg(target_arch = "x86")]
+pub(crate) const R_GLOB_DAT: u32 = 6;
+
+#[cfg(target_arch = "aarch64")]
+pub(crate) const R_JUMP_SLOT: u32 = 1026;
+#[cfg(target_arch = "aarch64")]
+pub(crate) const R_GLOB_DAT: u32 = 1025;
+
+#[cfg(target_arch = "arm")]
+pub(crate) const R_JUMP_SLOT: u32 = 22;
+#[cfg(target_arch = "arm")]
+pub(crate) const R_GLOB_DAT: u32 = 21;
+
+/// Program header of the native word size.
+#[cfg(target_pointer_width = "64")]
+pub(crate) type Phdr = libc::Elf64_Phdr;
+
+/// Program header of the native word size.
+#[cfg(target_pointer_width = "32")]
+pub(crate) type Phdr = libc::Elf32_Phdr;
+
+/// Dynamic section entry of the native word size.
+#[repr(C)]
+pub(crate) struct Dyn {
+    pub(crate) d_tag: isize,
+    pub(crate) d_val: usize,
+}
+
+/// Symbol table entry of the native word size.
+#[cfg(target_pointer_width = "64")]
+#[repr(C)]
+pub(crate) struct Sym {
+    pub(crate) st_name: u32,
+    pub(crate) st_info: u8,
+    pub(crate) st_other: u8,
+    pub(crate) st_shndx: u16,
+    pub(crate) st_value: u64,
+    pub(crate) st_size: u64,
+}
+
+/// Symbol table entry of the native word size.
+#[cfg(target_pointer_width = "32")]
+#[repr(C)]
+pub(crate) struct Sym {
+    pub(crate) st_name: u32,
+    pub(crate) st_value: u32,
+    pub(crate) st_size: u32,
+    pub(crate) st_info: u8,
+    pub(crate) st_other: u8,
+    pub(crate) st_shndx: u16,
+}
+
+/// Relocation entry with an explicit addend of the native word size.
+#[repr(C)]
+pub(crate) struct Rela {
+    pub(crate) r_offset: usize,
+    pub(crate) r_info: usize,
+    pub(crate) r_addend: isize,
+}
+
+/// Relocation entry with an implicit addend of the native word size.
+#[repr(C)]
+pub(crate) struct Rel {
+    pub(crate) r_offset: usize,
+    pub(crate) r_info: usize,
+}
+
+/// Returns the symbol table index of the relocation `info`.
+#[inline]
+pub(crate) fn r_sym(info: usize) -> usize {
+    #[cfg(target_pointer_width = "64")]
+    return info >> 32;
+
+    #[cfg(target_pointer_width = "32")]
+    return info >> 8;
+}
+
+/// Returns the relocation type of the relocation `info`.
+#[inline]
+pub(crate) fn r_type(info: usize) -> u32 {
+    #[cfg(target_pointer_width = "64")]
+    return (info & 0xffff_ffff) as u32;
+
+    #[cfg(target_pointer_width = "32")]
+    return (info & 0xff) as u32;
+}
+
+/// Represents a relocation of a symbol in a loaded shared object.
+pub(crate) struct Relocation {
+    pub(crate) offset: usize,
+    pub(crate) symbol: usize,
+    pub(crate) kind: u32,
+}
+
+/// Represents the dynamic section of a loaded shared object.
+pub(crate) struct DynamicInfo {
+    pub(crate) base: usize,
+    pub(crate) dynamic: *const Dyn,
+    pub(crate) symtab: *const Sym,
+    pub(crate) strtab: *const ffi::c_char,
+    pub(crate) strsz: usize,
+    pub(crate) jmprel: usize,
+    pub(crate) pltrelsz: usize,
+    pub(crate) pltrel: isize,
+    pub(crate) rela: usize,
+    pub(crate) relasz: usize,
+    pub(crate) rel: usize,
+    pub(crate) relsz: usize,
+    pub(crate) soname: Option<usize>,
+    pub(crate) flags_1: usize,
+    pub(crate) hash: usize,
+    pub(crate) gnu_hash: usize,
+}
+
+impl DynamicInfo {
+    /// Reads the dynamic section at `dynamic` of a shared object loaded at `base`.
+    ///
+    /// # Safety
+    ///
+    /// `dynamic` must point to a null-terminated dynamic section of a loaded shared object.
+    pub(crate) unsafe fn read(base: usize, dynamic: *const Dyn) -> Self {
+        // glibc relocates addresses in the dynamic section in place, while other
+        // implementations (e.g. musl) leave them relative to the load base
+        let addr = |val: usize| if val < base { base + val } else { val };
+
+        let mut info = Self {
+            base,
+            dynamic,
+            symtab: std::ptr::null(),
+            strtab: std::ptr::null(),
+            strsz: 0,
+            jmprel: 0,
+            pltrelsz: 0,
+            pltrel: DT_NULL,
+            rela: 0,
+            relasz: 0,
+            rel: 0,
+            relsz: 0,
+            soname: None,
+            flags_1: 0,
+            hash: 0,
+            gnu_hash: 0,
+        };
+
+        let mut entry = dynamic;
+        while (*entry).d_tag != DT_NULL {
+            let val = (*entry).d_val;
+
+            match (*entry).d_tag {
+                DT_PLTRELSZ => info.pltrelsz = val,
+                DT_STRTAB => info.strtab = addr(val) as *const ffi::c_char,
+                DT_SYMTAB => info.symtab = addr(val) as *const Sym,
+                DT_RELA => info.rela = addr(val),
+                DT_RELASZ => info.relasz = val,
+                DT_STRSZ => info.strsz = val,
+                DT_REL => info.rel = addr(val),
+                DT_RELSZ => info.relsz = val,
+                DT_PLTREL => info.pltrel = val as isize,
+                DT_JMPREL => info.jmprel = addr(val),
+                DT_SONAME => info.soname = Some(val),
+                DT_FLAGS_1 => info.flags_1 = val,
+                DT_HASH => info.hash = addr(val),
+                DT_GNU_HASH => info.gnu_hash = addr(val),
+                _ => {}
+            }
+
+            entry = entry.add(1);
+        }
+
+        info
+    }
+
+    /// Returns the string at `offset` of the dynamic string table.
+    ///
+    /// # Safety
+    ///
+    /// The shared object must remain loaded while the string is used.
+    pub(crate) unsafe fn string(&self, offset: usize) -> Option<&ffi::CStr> {
+        if self.strtab.is_null() || offset >= self.strsz {
+            None
+        } else {
+            Some(ffi::CStr::from_ptr(self.strtab.add(offset)))
+        }
+    }
+
+    /// Returns the name of the symbol at `index` of the dynamic symbol table.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be a valid index of the dynamic symbol table and
+    /// the shared object must remain loaded while the name is used.
+    pub(crate) unsafe fn symbol_name(&self, index: usize) -> Option<&ffi::CStr> {
+        if self.symtab.is_null() {
+            None
+        } else {
+            self.string((*self.symtab.add(index)).st_name as usize)
+        }
+    }
+
+    /// Returns the `DT_SONAME` of the shared object.
+    ///
+    /// # Safety
+    ///
+    /// The shared object must remain loaded while the name is used.
+    pub(crate) unsafe fn soname(&self) -> Option<&ffi::CStr> {
+        self.soname.and_then(|offset| self.string(offset))
+    }
+
+    /// Returns the `DT_NEEDED` names of the shared object in declaration order.
+    ///
+    /// # Safety
+    ///
+    /// The shared object must remain loaded while the names are used.
+    pub(crate) unsafe fn needed(&self) -> Vec<&ffi::CStr> {
+        let mut needed = Vec::new();
+
+        let mut entry = self.dynamic;
+        while (*entry).d_tag != DT_NULL {
+            if (*entry).d_tag == DT_NEEDED {
+                needed.extend(self.string((*entry).d_val));
+            }
+
+            entry = entry.add(1);
+        }
+
+        needed
+    }
+
+    /// Returns the number of entries of the dynamic symbol table,
+    /// which is derived from the `DT_HASH` or `DT_GNU_HASH` table.
+    ///
+    /// # Safety
+    ///
+    /// The shared object must remain loaded.
+    pub(crate) unsafe fn symbol_count(&self) -> usize {
+        if self.hash != 0 {
+            // nbucket, nchain, where nchain equals the number of symbols
+            return *(self.hash as *const u32).add(1) as usize;
+        }
+
+        if self.gnu_hash == 0 {
+            return 0;
+        }
+
+        let header = self.gnu_hash as *const u32;
+        let nbuckets = *header as usize;
+        let symoffset = *header.add(1) as usize;
+        let bloom_size = *header.add(2) as usize;
+
+        let buckets = header.add(4).cast::<usize>().add(bloom_size).cast::<u32>();
+
+        let last = (0..nbuckets)
+            .map(|i| *buckets.add(i) as usize)
+            .max()
+            .unwrap_or(0);
+
+        if last < symoffset {
+            return symoffset;
+        }
+
+        // the chain of the last bucket ends with a hash that has the lowest bit set
+        let chains = buckets.add(nbuckets);
+        let mut index = last;
+        while *chains.add(index - symoffset) & 1 == 0 {
+            index += 1;
+        }
+
+        index + 1
+    }
+
+    /// Returns the entries of the dynamic symbol table.
+    ///
+    /// # Safety
+    ///
+    /// The shared object must remain loaded while the entries are used.
+    pub(crate) unsafe fn symbols(&self) -> &[Sym] {
+        if self.symtab.is_null() {
+            &[]
+        } else {
+            std::slice::from_raw_parts(self.symtab, self.symbol_count())
+        }
+    }
+
+    /// Returns all relocations of the `.rel(a).dyn` and `.rel(a).plt` tables.
+    ///
+    /// # Safety
+    ///
+    /// The shared object must remain loaded.
+    pub(crate) unsafe fn relocations(&self) -> Vec<Relocation> {
+        let mut relocations = Vec::new();
+
+        let mut push_rela = |table: usize, size: usize| {
+            let table = table as *const Rela;
+            for i in 0..size / std::mem::size_of::<Rela>() {
+                let rela = &*table.add(i);
+                relocations.push(Relocation {
+                    offset: rela.r_offset,
+                    symbol: r_sym(rela.r_info),
+                    kind: r_type(rela.r_info),
+                });
+            }
+        };
+
+        if self.rela != 0 {
+            push_rela(self.rela, self.relasz);
+        }
+
+        if self.jmprel != 0 && self.pltrel == DT_RELA {
+            push_rela(self.jmprel, self.pltrelsz);
+        }
+
+        let mut push_rel = |table: usize, size: usize| {
+            let table = table as *const Rel;
+            for i in 0..size / std::mem::size_of::<Rel>() {
+                let rel = &*table.add(i);
+                relocations.push(Relocation {
+                    offset: rel.r_offset,
+                    symbol: r_sym(rel.r_info),
+                    kind: r_type(rel.r_info),
+                });
+            }
+        };
+
+        if self.rel != 0 {
+            push_rel(self.rel, self.relsz);
+        }
+
+        if self.jmprel != 0 && self.pltrel == DT_REL {
+            push_rel(self.jmprel, self.pltrelsz);
+        }
+
+        relocations
+    }
+}
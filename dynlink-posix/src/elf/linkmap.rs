@@ -0,0 +1,46 @@
+use std::ffi;
+
+use crate::{
+    elf::Dyn,
+    symtab::{PosixHandle, PosixLinkingError},
+};
+
+const RTLD_DI_LINKMAP: ffi::c_int = 2;
+
+extern "C" {
+    fn dlinfo(handle: *mut ffi::c_void, request: ffi::c_int, info: *mut ffi::c_void) -> ffi::c_int;
+}
+
+/// Leading fields of the loader's link map entry of a loaded shared object.
+#[repr(C)]
+pub(crate) struct LinkMap {
+    pub(crate) l_addr: usize,
+    pub(crate) l_name: *const ffi::c_char,
+    pub(crate) l_ld: *const Dyn,
+    pub(crate) l_next: *const LinkMap,
+    pub(crate) l_prev: *const LinkMap,
+}
+
+impl LinkMap {
+    /// Returns the link map entry of the shared object owned by `handle`.
+    ///
+    /// # Safety
+    ///
+    /// Returning link map entry must not outlive the handle.
+    pub(crate) unsafe fn of(handle: &PosixHandle) -> Result<&LinkMap, PosixLinkingError> {
+        let mut link_map: *const LinkMap = std::ptr::null();
+
+        let _ = libc::dlerror();
+        let res = dlinfo(
+            handle.0,
+            RTLD_DI_LINKMAP,
+            (&mut link_map as *mut *const LinkMap).cast(),
+        );
+
+        if res == 0 && !link_map.is_null() {
+            Ok(&*link_map)
+        } else {
+            Err(PosixLinkingError::clone_from_ptr(libc::dlerror()))
+        }
+    }
+}
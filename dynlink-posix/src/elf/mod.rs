@@ -0,0 +1,5 @@
+mod dynamic;
+mod linkmap;
+
+pub(crate) use dynamic::*;
+pub(crate) use linkmap::LinkMap;
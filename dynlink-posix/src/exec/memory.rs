@@ -0,0 +1,164 @@
+use std::{error, ffi, fmt, io, ptr};
+
+/// Represents an error that occurred during mapping of executable memory.
+///
+/// `PosixExecError` contains the `errno` value of the failed `mmap` or `mprotect` call.
+pub struct PosixExecError(ffi::c_int);
+
+impl PosixExecError {
+    fn last() -> Self {
+        Self(io::Error::last_os_error().raw_os_error().unwrap_or(0))
+    }
+
+    /// Returns the `errno` value of the failed call.
+    #[inline]
+    pub fn code(&self) -> ffi::c_int {
+        self.0
+    }
+}
+
+impl Clone for PosixExecError {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl fmt::Debug for PosixExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("PosixExecError({:?})", self.0))
+    }
+}
+
+impl fmt::Display for PosixExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Error occurred executable memory mapping: {}",
+            io::Error::from_raw_os_error(self.0)
+        ))
+    }
+}
+
+impl error::Error for PosixExecError {}
+
+/// Represents anonymous pages holding machine code that are unmapped on drop.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink_posix::exec::PosixExecMemory;
+///
+/// fn main() {
+///     unsafe {
+///         // mov eax, 42; ret
+///         let memory = PosixExecMemory::new(&[0xb8, 0x2a, 0x00, 0x00, 0x00, 0xc3])
+///             .expect("code was not mapped");
+///
+///         let answer_fn: extern "C" fn() -> i32 = std::mem::transmute(memory.as_ptr());
+///         assert_eq!(42, answer_fn());
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// Pages are written while they are readable and writable and then made readable
+/// and executable, so they are never writable and executable at the same time.
+pub struct PosixExecMemory {
+    ptr: *mut ffi::c_void,
+    len: usize,
+}
+
+impl PosixExecMemory {
+    /// Maps pages holding a copy of `code` and makes them executable.
+    ///
+    /// # Safety
+    ///
+    /// `code` must be valid machine code of the running process architecture
+    /// wherever it is executed from.
+    pub unsafe fn new(code: &[u8]) -> Result<Self, PosixExecError> {
+        let page_size = libc::sysconf(libc::_SC_PAGESIZE) as usize;
+        let len = code.len().div_ceil(page_size).max(1) * page_size;
+
+        let ptr = libc::mmap(
+            ptr::null_mut(),
+            len,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+
+        if ptr == libc::MAP_FAILED {
+            return Err(PosixExecError::last());
+        }
+
+        ptr::copy_nonoverlapping(code.as_ptr(), ptr.cast::<u8>(), code.len());
+
+        if libc::mprotect(ptr, len, libc::PROT_READ | libc::PROT_EXEC) != 0 {
+            let err = PosixExecError::last();
+            libc::munmap(ptr, len);
+
+            return Err(err);
+        }
+
+        Ok(Self { ptr, len })
+    }
+
+    /// Returns the address of the first byte of the code.
+    #[inline]
+    pub fn as_ptr(&self) -> *const ffi::c_void {
+        self.ptr
+    }
+}
+
+impl Drop for PosixExecMemory {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.ptr, self.len);
+        }
+    }
+}
+
+unsafe impl Send for PosixExecMemory {}
+unsafe impl Sync for PosixExecMemory {}
+
+impl fmt::Debug for PosixExecMemory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "PosixExecMemory({:p}, {})",
+            self.ptr, self.len
+        ))
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use std::mem;
+
+    use crate::exec::PosixExecMemory;
+
+    pub fn assert_send<T: Send>() {}
+    pub fn assert_sync<T: Sync>() {}
+
+    #[test]
+    pub fn posix_exec_memory_marked_as_send_test() {
+        assert_send::<PosixExecMemory>();
+    }
+
+    #[test]
+    pub fn posix_exec_memory_marked_as_sync_test() {
+        assert_sync::<PosixExecMemory>();
+    }
+
+    #[test]
+    pub fn posix_exec_memory_executes_code_test() {
+        unsafe {
+            // mov eax, 42; ret
+            let memory = PosixExecMemory::new(&[0xb8, 0x2a, 0x00, 0x00, 0x00, 0xc3])
+                .expect("code was not mapped");
+
+            let answer_fn: extern "C" fn() -> i32 = mem::transmute(memory.as_ptr());
+            assert_eq!(42, answer_fn());
+        }
+    }
+}
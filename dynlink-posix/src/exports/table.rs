@@ -0,0 +1,64 @@
+use std::{collections::HashSet, ffi};
+
+use crate::{
+    elf::{DynamicInfo, LinkMap, SHN_ABS, SHN_UNDEF, STB_GLOBAL, STB_GNU_UNIQUE, STB_WEAK},
+    symtab::{PosixHandle, PosixLinkingError},
+};
+
+/// Returns the names of the symbols exported by the shared object owned by `handle`
+/// in the order of its dynamic symbol table.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink_posix::{
+///     exports,
+///     symtab::{PosixHandle, RTLD_LAZY, RTLD_LOCAL},
+/// };
+///
+/// fn main() {
+///     unsafe {
+///         let handle = PosixHandle::openc(c"libsum.so", RTLD_LOCAL | RTLD_LAZY)
+///             .expect("libsum handle was not opened");
+///
+///         let names = exports::exports(&handle).expect("libsum exports were not read");
+///         assert!(names.iter().any(|it| it.as_c_str() == c"sum_of"));
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// The dynamic section of the shared object must not be modified while it is read.
+///
+/// # Notes
+///
+/// Exports are the defined global, weak and unique symbols of the dynamic symbol table
+/// of the loaded shared object, so the file it was loaded from is not read.
+/// Symbols defined in several versions are listed once, and version name symbols are skipped.
+pub unsafe fn exports(handle: &PosixHandle) -> Result<Vec<ffi::CString>, PosixLinkingError> {
+    let link_map = LinkMap::of(handle)?;
+    let info = DynamicInfo::read(link_map.l_addr, link_map.l_ld);
+
+    let mut seen = HashSet::new();
+    let mut names = Vec::new();
+
+    for sym in info.symbols() {
+        let binding = sym.st_info >> 4;
+
+        if !matches!(binding, STB_GLOBAL | STB_WEAK | STB_GNU_UNIQUE)
+            || sym.st_shndx == SHN_UNDEF
+            // version definitions are absolute symbols named after the version
+            || (sym.st_shndx == SHN_ABS && sym.st_value == 0)
+        {
+            continue;
+        }
+
+        match info.string(sym.st_name as usize) {
+            Some(name) if !name.is_empty() && seen.insert(name) => names.push(name.to_owned()),
+            _ => {}
+        }
+    }
+
+    Ok(names)
+}
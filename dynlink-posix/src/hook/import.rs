@@ -0,0 +1,314 @@
+use std::{
+    error, ffi, fmt, io, marker,
+    sync::{self, atomic},
+};
+
+use pointersized::PointerSized;
+
+use crate::{
+    elf::{DynamicInfo, LinkMap, Phdr, R_GLOB_DAT, R_JUMP_SLOT},
+    symtab::{PosixHandle, PosixLinkingError},
+};
+
+/// Represents an error that occurred during import hooking.
+///
+/// `PosixHookError::Linking(err)` indicates that the loader information of the shared object is unavailable.
+/// `PosixHookError::NotImported` indicates that the shared object does not import the symbol.
+/// `PosixHookError::Protection(code)` contains a system error code of a failed memory protection change.
+pub enum PosixHookError {
+    Linking(PosixLinkingError),
+    NotImported,
+    Protection(ffi::c_int),
+}
+
+impl Clone for PosixHookError {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Linking(err) => Self::Linking(err.clone()),
+            Self::NotImported => Self::NotImported,
+            Self::Protection(code) => Self::Protection(*code),
+        }
+    }
+}
+
+impl fmt::Debug for PosixHookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linking(err) => f.write_fmt(format_args!("Linking({:?})", err)),
+            Self::NotImported => f.write_str("NotImported"),
+            Self::Protection(code) => f.write_fmt(format_args!("Protection({:?})", code)),
+        }
+    }
+}
+
+impl fmt::Display for PosixHookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linking(err) => {
+                f.write_fmt(format_args!("Error occurred import hooking: {}", err))
+            }
+            Self::NotImported => {
+                f.write_str("Error occurred import hooking: symbol is not imported")
+            }
+            Self::Protection(code) => f.write_fmt(format_args!(
+                "Error occurred import hooking: {}",
+                io::Error::from_raw_os_error(*code)
+            )),
+        }
+    }
+}
+
+impl error::Error for PosixHookError {}
+
+/// Serializes the protection changes of GOT pages made by hooking and restoring,
+/// since the protection to restore is derived from the program headers rather than the page.
+static HOOKING: sync::Mutex<()> = sync::Mutex::new(());
+
+/// Represents a patched GOT slot and the value to restore.
+struct Slot {
+    addr: usize,
+    original: usize,
+}
+
+/// Represents a redirected import of a shared object that is restored on drop.
+///
+/// # Usage
+///
+/// `PosixImportHook` is returned by `hook_import` and keeps the import redirected while it is alive.
+///
+/// ```no_run
+/// use std::ffi;
+///
+/// use dynlink_posix::{hook::hook_import, symtab::PosixHandle};
+///
+/// unsafe extern "C" fn fake_atoi(_: *const ffi::c_char) -> ffi::c_int {
+///     42
+/// }
+///
+/// fn main() {
+///     unsafe {
+///         let handle = PosixHandle::open("libimport.so")
+///             .expect("libimport handle was not opened");
+///
+///         let hook = hook_import(&handle, "atoi", fake_atoi as unsafe extern "C" fn(_) -> _)
+///             .expect("atoi import was not hooked");
+///
+///         // calls of `atoi` made by libimport.so go to `fake_atoi` until the hook is dropped
+///         drop(hook);
+///     }
+/// }
+/// ```
+pub struct PosixImportHook<'symtab, T: PointerSized> {
+    slots: Vec<Slot>,
+    marker: marker::PhantomData<(&'symtab PosixHandle, T)>,
+}
+
+impl<'symtab, T: PointerSized> PosixImportHook<'symtab, T> {
+    /// Returns the number of GOT slots redirected by this hook.
+    #[inline]
+    pub fn slots(&self) -> usize {
+        self.slots.len()
+    }
+}
+
+unsafe impl<'symtab, T: PointerSized> Send for PosixImportHook<'symtab, T> {}
+unsafe impl<'symtab, T: PointerSized> Sync for PosixImportHook<'symtab, T> {}
+
+impl<'symtab, T: PointerSized> Drop for PosixImportHook<'symtab, T> {
+    fn drop(&mut self) {
+        let _guard = HOOKING.lock().unwrap_or_else(sync::PoisonError::into_inner);
+
+        for slot in self.slots.iter().rev() {
+            let _ = unsafe { write_slot(slot.addr, slot.original) };
+        }
+    }
+}
+
+impl<'symtab, T: PointerSized> fmt::Debug for PosixImportHook<'symtab, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PosixImportHook(")?;
+        f.debug_list()
+            .entries(self.slots.iter().map(|it| it.addr as *const ffi::c_void))
+            .finish()?;
+        f.write_str(")")
+    }
+}
+
+/// Redirects the import of `symbol` by the shared object owned by `handle` to `replacement`.
+///
+/// All `JUMP_SLOT` and `GLOB_DAT` relocations of the symbol are located through the
+/// `DT_JMPREL`, `DT_RELA` and `DT_REL` tables of the shared object, and the GOT slots they
+/// point to are overwritten, temporarily making them writable if they are protected by RELRO.
+/// Other shared objects importing the same symbol are not affected.
+///
+/// The original GOT values are restored when the returned hook is dropped.
+/// Hooking and restoring are serialized across threads, so imports sharing a RELRO page
+/// can be hooked concurrently.
+///
+/// # Safety
+///
+/// Type `T` must be ABI compatible with the type of the imported symbol.
+/// The shared object must not call the import concurrently with hooking and restoring,
+/// and hooks of the same import must be dropped in the reverse order of creation.
+/// The protection of the GOT pages must not be changed by other code concurrently with hooking and restoring.
+///
+/// # Notes
+///
+/// `JUMP_SLOT` and `GLOB_DAT` relocations are not distinguished from other absolute relocations
+/// on riscv64, so import hooking is not supported there.
+pub unsafe fn hook_import<'symtab, T: PointerSized>(
+    handle: &'symtab PosixHandle,
+    symbol: &str,
+    replacement: T,
+) -> Result<PosixImportHook<'symtab, T>, PosixHookError> {
+    let link_map = LinkMap::of(handle).map_err(PosixHookError::Linking)?;
+    let info = DynamicInfo::read(link_map.l_addr, link_map.l_ld);
+
+    let replacement = (&replacement as *const T).cast::<usize>().read();
+    let mut slots = Vec::<Slot>::new();
+
+    let guard = HOOKING.lock().unwrap_or_else(sync::PoisonError::into_inner);
+
+    for relocation in info.relocations() {
+        if relocation.kind != R_JUMP_SLOT && relocation.kind != R_GLOB_DAT {
+            continue;
+        }
+
+        let is_symbol = match info.symbol_name(relocation.symbol) {
+            Some(name) => name.to_bytes() == symbol.as_bytes(),
+            None => false,
+        };
+
+        let addr = info.base + relocation.offset;
+        if !is_symbol || slots.iter().any(|it| it.addr == addr) {
+            continue;
+        }
+
+        let original = (*(addr as *const atomic::AtomicUsize)).load(atomic::Ordering::SeqCst);
+
+        if let Err(err) = write_slot(addr, replacement) {
+            drop(guard);
+            drop(PosixImportHook::<T> {
+                slots,
+                marker: marker::PhantomData,
+            });
+
+            return Err(err);
+        }
+
+        slots.push(Slot { addr, original });
+    }
+
+    if slots.is_empty() {
+        return Err(PosixHookError::NotImported);
+    }
+
+    Ok(PosixImportHook {
+        slots,
+        marker: marker::PhantomData,
+    })
+}
+
+/// Writes `value` to the GOT slot at `addr`, making its page temporarily writable.
+unsafe fn write_slot(addr: usize, value: usize) -> Result<(), PosixHookError> {
+    let page_size = libc::sysconf(libc::_SC_PAGESIZE) as usize;
+    let page = (addr & !(page_size - 1)) as *mut ffi::c_void;
+    let protection = protection_of(addr);
+
+    if protection & libc::PROT_WRITE == 0
+        && libc::mprotect(page, page_size, protection | libc::PROT_WRITE) != 0
+    {
+        return Err(PosixHookError::Protection(
+            io::Error::last_os_error().raw_os_error().unwrap_or(0),
+        ));
+    }
+
+    (*(addr as *const atomic::AtomicUsize)).store(value, atomic::Ordering::SeqCst);
+
+    if protection & libc::PROT_WRITE == 0 && libc::mprotect(page, page_size, protection) != 0 {
+        return Err(PosixHookError::Protection(
+            io::Error::last_os_error().raw_os_error().unwrap_or(0),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the memory protection of the loaded segment containing `addr`.
+///
+/// Addresses within a `PT_GNU_RELRO` segment are reported as read-only,
+/// since the loader protects them after relocation processing.
+unsafe fn protection_of(addr: usize) -> ffi::c_int {
+    struct Search {
+        addr: usize,
+        protection: Option<ffi::c_int>,
+    }
+
+    unsafe extern "C" fn callback(
+        info: *mut libc::dl_phdr_info,
+        _: libc::size_t,
+        data: *mut ffi::c_void,
+    ) -> ffi::c_int {
+        let search = &mut *data.cast::<Search>();
+        let info = &*info;
+
+        let contains = |phdr: &Phdr| {
+            let start = info.dlpi_addr as usize + phdr.p_vaddr as usize;
+            (start..start + phdr.p_memsz as usize).contains(&search.addr)
+        };
+
+        let phdrs = std::slice::from_raw_parts(info.dlpi_phdr, info.dlpi_phnum as usize);
+
+        let load = phdrs
+            .iter()
+            .find(|it| it.p_type == libc::PT_LOAD && contains(it));
+
+        if let Some(load) = load {
+            let is_relro = phdrs
+                .iter()
+                .any(|it| it.p_type == libc::PT_GNU_RELRO && contains(it));
+
+            let mut protection = libc::PROT_NONE;
+            if load.p_flags & libc::PF_R != 0 {
+                protection |= libc::PROT_READ;
+            }
+            if load.p_flags & libc::PF_W != 0 && !is_relro {
+                protection |= libc::PROT_WRITE;
+            }
+            if load.p_flags & libc::PF_X != 0 {
+                protection |= libc::PROT_EXEC;
+            }
+
+            search.protection = Some(protection);
+            1
+        } else {
+            0
+        }
+    }
+
+    let mut search = Search {
+        addr,
+        protection: None,
+    };
+
+    libc::dl_iterate_phdr(Some(callback), (&mut search as *mut Search).cast());
+    search.protection.unwrap_or(libc::PROT_READ)
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::hook::PosixImportHook;
+
+    pub fn assert_send<T: Send>() {}
+    pub fn assert_sync<T: Sync>() {}
+
+    #[test]
+    pub fn posix_import_hook_marked_as_send_test() {
+        assert_send::<PosixImportHook<'_, fn(i32, i32) -> i32>>();
+    }
+
+    #[test]
+    pub fn posix_import_hook_marked_as_sync_test() {
+        assert_sync::<PosixImportHook<'_, fn(i32, i32) -> i32>>();
+    }
+}
@@ -0,0 +1,54 @@
+use std::{cell, fmt, thread};
+
+/// Represents a per-thread mark that an interposed function is being executed.
+///
+/// # Usage
+///
+/// `ReentrancyGuard` is used by functions declared with the `interpose!` macro to detect
+/// that the replacement calls the interposed function again on the same thread
+/// (e.g. a tracing `write` that writes its own trace), so the nested call can go
+/// straight to the real implementation.
+///
+/// ```
+/// use std::cell;
+///
+/// use dynlink_posix::interpose::ReentrancyGuard;
+///
+/// thread_local! {
+///     static ACTIVE: cell::Cell<bool> = const { cell::Cell::new(false) };
+/// }
+///
+/// fn main() {
+///     let guard = ReentrancyGuard::enter(&ACTIVE).expect("Unreachable");
+///     assert!(ReentrancyGuard::enter(&ACTIVE).is_none());
+///
+///     drop(guard);
+///     assert!(ReentrancyGuard::enter(&ACTIVE).is_some());
+/// }
+/// ```
+pub struct ReentrancyGuard(&'static thread::LocalKey<cell::Cell<bool>>);
+
+impl ReentrancyGuard {
+    /// Marks `active` as entered on the calling thread and returns a guard that unmarks it on drop.
+    ///
+    /// Returns `None` if `active` is already entered on the calling thread,
+    /// or if the thread-local storage is being destroyed.
+    pub fn enter(active: &'static thread::LocalKey<cell::Cell<bool>>) -> Option<Self> {
+        match active.try_with(|it| it.replace(true)) {
+            Ok(false) => Some(Self(active)),
+            Ok(true) | Err(_) => None,
+        }
+    }
+}
+
+impl Drop for ReentrancyGuard {
+    fn drop(&mut self) {
+        let _ = self.0.try_with(|it| it.set(false));
+    }
+}
+
+impl fmt::Debug for ReentrancyGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ReentrancyGuard")
+    }
+}
@@ -0,0 +1,202 @@
+use std::{cell, ffi, fmt, marker, ptr, sync::atomic};
+
+use pointersized::PointerSized;
+
+use crate::symtab::PosixSymbol;
+
+/// Maximum number of interposed symbols that are resolved on one thread at the same time.
+const RESOLVING_DEPTH: usize = 16;
+
+thread_local! {
+    /// Addresses of the interposed symbols that are being resolved on the current thread.
+    static RESOLVING: cell::Cell<[usize; RESOLVING_DEPTH]> =
+        const { cell::Cell::new([0; RESOLVING_DEPTH]) };
+}
+
+/// Marks the interposed symbol at `address` as being resolved on the calling thread
+/// and returns the slot of the mark.
+///
+/// Returns `None` if it is already being resolved on the calling thread, if too many symbols
+/// are being resolved, or if the thread-local storage is being destroyed.
+fn mark_resolving(address: usize) -> Option<usize> {
+    RESOLVING
+        .try_with(|it| {
+            let mut resolving = it.get();
+            if resolving.contains(&address) {
+                return None;
+            }
+
+            let slot = resolving.iter().position(|it| *it == 0)?;
+            resolving[slot] = address;
+            it.set(resolving);
+
+            Some(slot)
+        })
+        .ok()
+        .flatten()
+}
+
+/// Removes the mark in `slot` made by `mark_resolving`.
+fn unmark_resolving(slot: usize) {
+    let _ = RESOLVING.try_with(|it| {
+        let mut resolving = it.get();
+        resolving[slot] = 0;
+        it.set(resolving);
+    });
+}
+
+/// Represents the "real" implementation of an interposed symbol resolved through `RTLD_NEXT`.
+///
+/// # Usage
+///
+/// `Interposed` is intended to be used as a static, usually declared by the `interpose!` macro.
+/// The symbol is looked up in the shared objects that follow the calling one in the load order,
+/// and the address is cached after the first successful lookup.
+///
+/// ```no_run
+/// use std::ffi;
+///
+/// use dynlink_posix::interpose::Interposed;
+///
+/// static REAL_GETPID: Interposed<unsafe extern "C" fn() -> ffi::c_int> = Interposed::new(c"getpid");
+///
+/// fn main() {
+///     unsafe {
+///         let real = REAL_GETPID.resolve()
+///             .expect("getpid symbol was not found");
+///
+///         println!("{}", real.apply(|getpid_fn| getpid_fn()));
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Type `T` must be ABI compatible with the type of symbol from a shared object.
+///
+/// Resolution is recursion-safe: if the lookup itself calls the same interposed function on the
+/// same thread (e.g. `dlsym` of `calloc` calling `calloc`), the nested `Interposed::resolve`
+/// returns `None` instead of recursing, so the caller can fall back to an alternative
+/// implementation. Other interposed functions called by the lookup (e.g. `dlsym` of `open`
+/// calling `malloc`) are resolved as usual.
+pub struct Interposed<T: PointerSized> {
+    symbol: &'static ffi::CStr,
+    ptr: atomic::AtomicPtr<ffi::c_void>,
+    marker: marker::PhantomData<T>,
+}
+
+impl<T: PointerSized> Interposed<T> {
+    /// Creates an interposed symbol specified by null-terminated `symbol` that is not resolved yet.
+    pub const fn new(symbol: &'static ffi::CStr) -> Self {
+        Self {
+            symbol,
+            ptr: atomic::AtomicPtr::new(ptr::null_mut()),
+            marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns the name of the interposed symbol.
+    #[inline]
+    pub fn symbol(&self) -> &'static ffi::CStr {
+        self.symbol
+    }
+
+    /// Resolves the real implementation on first call and returns it.
+    ///
+    /// Returns `None` if the symbol could not be found in the following shared objects,
+    /// or if the resolution of this symbol is already in progress on the calling thread.
+    ///
+    /// # Safety
+    ///
+    /// Type `T` must be ABI compatible with the type of symbol from a shared object.
+    pub unsafe fn resolve(&self) -> Option<PosixSymbol<'static, T>> {
+        let ptr = self.ptr.load(atomic::Ordering::Acquire);
+        if !ptr.is_null() {
+            return Some(PosixSymbol::from_ptr(ptr));
+        }
+
+        let slot = mark_resolving(self as *const Self as usize)?;
+
+        let ptr = libc::dlsym(libc::RTLD_NEXT, self.symbol.as_ptr());
+        unmark_resolving(slot);
+
+        if !ptr.is_null() {
+            self.ptr.store(ptr, atomic::Ordering::Release);
+            Some(PosixSymbol::from_ptr(ptr))
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl<T: PointerSized> Send for Interposed<T> {}
+unsafe impl<T: PointerSized> Sync for Interposed<T> {}
+
+impl<T: PointerSized> fmt::Debug for Interposed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Interposed({:?}, {:p})",
+            self.symbol,
+            self.ptr.load(atomic::Ordering::Relaxed)
+        ))
+    }
+}
+
+/// Reports that the real implementation of the interposed `symbol` is unavailable and aborts the process.
+///
+/// The message is written directly to the standard error without allocations,
+/// so it is safe to call from interposed allocation functions.
+#[cold]
+pub fn unresolved(symbol: &ffi::CStr) -> ! {
+    const PREFIX: &[u8] = b"Real implementation of interposed symbol is unavailable: ";
+
+    unsafe {
+        let _ = libc::write(libc::STDERR_FILENO, PREFIX.as_ptr().cast(), PREFIX.len());
+        let _ = libc::write(
+            libc::STDERR_FILENO,
+            symbol.as_ptr().cast(),
+            symbol.count_bytes(),
+        );
+        let _ = libc::write(libc::STDERR_FILENO, b"\n".as_ptr().cast(), 1);
+
+        libc::abort()
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use std::ffi;
+
+    use crate::interpose::{
+        interposed::{mark_resolving, unmark_resolving},
+        Interposed,
+    };
+
+    pub fn assert_send<T: Send>() {}
+    pub fn assert_sync<T: Sync>() {}
+
+    #[test]
+    pub fn interposed_marked_as_send_test() {
+        assert_send::<Interposed<unsafe extern "C" fn() -> ffi::c_int>>();
+    }
+
+    #[test]
+    pub fn interposed_marked_as_sync_test() {
+        assert_sync::<Interposed<unsafe extern "C" fn() -> ffi::c_int>>();
+    }
+
+    #[test]
+    pub fn interposed_marks_resolution_per_symbol_test() {
+        let malloc = mark_resolving(1).expect("malloc was not marked");
+        let open = mark_resolving(2).expect("open was not marked while malloc is resolved");
+
+        assert!(mark_resolving(1).is_none());
+        assert!(mark_resolving(2).is_none());
+
+        unmark_resolving(open);
+        unmark_resolving(malloc);
+
+        let malloc = mark_resolving(1).expect("malloc was not marked again");
+        unmark_resolving(malloc);
+    }
+}
@@ -0,0 +1,88 @@
+/// Declares an exported replacement of a function and binds its real implementation.
+///
+/// The macro exports an unmangled `extern "C"` function of the declared name, so when
+/// the shared object that contains it is preloaded (e.g. with `LD_PRELOAD`) or linked
+/// into the executable, calls to the function are routed to the declared body.
+/// The real implementation is resolved through `RTLD_NEXT`, cached in an `Interposed`
+/// static and bound to the identifier given after `as`.
+///
+/// The replacement is recursion-safe:
+///
+/// - if the body calls the interposed function again on the same thread,
+///   the nested call goes straight to the real implementation;
+/// - if the real implementation can't be resolved yet because the lookup itself calls
+///   the interposed function, the optional `else` block is executed instead,
+///   otherwise the process is aborted with a diagnostic message.
+///
+/// # Usage
+///
+/// ```no_run
+/// use std::{ffi, sync::atomic};
+///
+/// static OPENED: atomic::AtomicUsize = atomic::AtomicUsize::new(0);
+///
+/// dynlink_posix::interpose! {
+///     unsafe extern "C" fn open(path: *const ffi::c_char, flags: ffi::c_int, mode: ffi::c_uint) -> ffi::c_int as real {
+///         OPENED.fetch_add(1, atomic::Ordering::Relaxed);
+///         real(path, flags, mode)
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// C-variadic functions can't be defined in Rust, so variadic functions (e.g. `open`)
+/// must be declared with the fixed parameters the callers are expected to pass,
+/// which is only ABI compatible on platforms passing variadic arguments like fixed ones.
+///
+/// # Safety
+///
+/// The declared function signature must be ABI compatible with the interposed symbol.
+#[macro_export]
+macro_rules! interpose {
+    (
+        $(#[$attr:meta])*
+        unsafe extern "C" fn $name:ident($($arg:ident: $arg_ty:ty),* $(,)?) $(-> $ret:ty)? as $real:ident
+            $body:block
+        $(else $fallback:block)?
+    ) => {
+        $(#[$attr])*
+        #[unsafe(no_mangle)]
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe extern "C" fn $name($($arg: $arg_ty),*) $(-> $ret)? {
+            const SYMBOL: &::core::ffi::CStr =
+                match ::core::ffi::CStr::from_bytes_with_nul(::core::concat!(::core::stringify!($name), "\0").as_bytes()) {
+                    ::core::result::Result::Ok(symbol) => symbol,
+                    ::core::result::Result::Err(_) => ::core::panic!("Unreachable"),
+                };
+
+            static REAL: $crate::interpose::Interposed<unsafe extern "C" fn($($arg_ty),*) $(-> $ret)?> =
+                $crate::interpose::Interposed::new(SYMBOL);
+
+            ::std::thread_local! {
+                static ACTIVE: ::core::cell::Cell<bool> = const { ::core::cell::Cell::new(false) };
+            }
+
+            match REAL.resolve() {
+                ::core::option::Option::Some(real) => {
+                    let $real = real.leak();
+
+                    match $crate::interpose::ReentrancyGuard::enter(&ACTIVE) {
+                        ::core::option::Option::Some(_guard) => $body,
+                        ::core::option::Option::None => $real($($arg),*),
+                    }
+                }
+
+                ::core::option::Option::None => $crate::interpose!(@fallback SYMBOL $($fallback)?),
+            }
+        }
+    };
+
+    (@fallback $symbol:ident) => {
+        $crate::interpose::unresolved($symbol)
+    };
+
+    (@fallback $symbol:ident $fallback:block) => {
+        $fallback
+    };
+}
@@ -0,0 +1,6 @@
+mod guard;
+mod interposed;
+mod macros;
+
+pub use guard::ReentrancyGuard;
+pub use interposed::{unresolved, Interposed};
@@ -2,6 +2,13 @@
 //!
 //! This library binds around <dlfcn.h> and provides a more memory-safe API
 //! that allows dynamic linking shared objects, and use the data and functions they contains.
+//!
+//! Functions of other shared objects can be interposed with the `interpose!` macro,
+//! which resolves their real implementations through `RTLD_NEXT`, and imports of a loaded
+//! shared object can be redirected with `hook::hook_import` on Linux, where
+//! `unload::close_and_verify` also reports why a closed shared object remains loaded
+//! and `exports::exports` lists the symbols a loaded shared object exports.
+//! Machine code generated at runtime is mapped with `exec::PosixExecMemory` on Linux x86_64.
 
 #[cfg(any(
     target_os = "linux",
@@ -17,3 +24,67 @@
     target_os = "haiku",
 ))]
 pub mod symtab;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly",
+    target_os = "solaris",
+    target_os = "illumos",
+))]
+pub mod interpose;
+
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+))]
+mod elf;
+
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm"
+    )
+))]
+pub mod hook;
+
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+))]
+pub mod exports;
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+pub mod exec;
+
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+))]
+pub mod unload;
@@ -1,4 +1,4 @@
-use std::{error, ffi, fmt, os::unix::ffi::OsStrExt};
+use std::{error, ffi, fmt, mem, os::unix::ffi::OsStrExt};
 
 use pointersized::PointerSized;
 use smallvec;
@@ -94,7 +94,7 @@ impl PosixLinkingError {
     }
 
     /// Creates owned error cloned from raw c-str pointer.
-    pub(super) unsafe fn clone_from_ptr(msg: *const ffi::c_char) -> Self {
+    pub(crate) unsafe fn clone_from_ptr(msg: *const ffi::c_char) -> Self {
         if !msg.is_null() {
             Self::clone_from_str(ffi::CStr::from_ptr(msg))
         } else {
@@ -191,7 +191,7 @@ impl error::Error for PosixLinkingError {}
 ///
 /// The thread-safety of `PosixHandle` depends on the `dlfcn` implementation.
 /// It is thread-safe only if the implementations of `dlopen`, `dlsym`, `dlclose`, and `dlerror` are thread-safe.
-pub struct PosixHandle(pub(super) *mut ffi::c_void);
+pub struct PosixHandle(pub(crate) *mut ffi::c_void);
 
 impl PosixHandle {
     /// Opens shared object file specified by `path` with default options and loads
@@ -312,6 +312,30 @@ impl PosixHandle {
             Err(PosixLinkingError::clone_from_ptr(err))
         }
     }
+
+    /// Closes the handle and decrements the reference count of the shared object file,
+    /// which is unloaded from the process address space when the count reaches zero.
+    ///
+    /// Unlike dropping the handle, this function reports a failure of `dlclose`.
+    ///
+    /// # Safety
+    ///
+    /// Shared object finalization routines that are executed when this
+    /// function is called may be UB.
+    pub unsafe fn close(self) -> Result<(), PosixLinkingError> {
+        let handle = self.0;
+        mem::forget(self);
+
+        #[cfg(target_os = "freebsd")]
+        let _ = libc::dlerror();
+
+        if libc::dlclose(handle) == 0 {
+            Ok(())
+        } else {
+            let err = libc::dlerror();
+            Err(PosixLinkingError::clone_from_ptr(err))
+        }
+    }
 }
 
 unsafe impl Send for PosixHandle {}
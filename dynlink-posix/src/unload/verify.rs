@@ -0,0 +1,381 @@
+use std::{error, ffi, fmt};
+
+use crate::{
+    elf::{DynamicInfo, LinkMap, Phdr, DF_1_NODELETE, SHN_UNDEF, STB_GNU_UNIQUE},
+    symtab::{PosixHandle, PosixLinkingError},
+};
+
+/// Represents a reason a shared object file remains resident after closing.
+///
+/// `PosixResidency::NoDelete` indicates that the shared object is marked with `DF_1_NODELETE`.
+/// `PosixResidency::UniqueSymbols` indicates that the shared object defines `STB_GNU_UNIQUE` symbols.
+/// `PosixResidency::Dependents(names)` contains names of loaded shared objects that need it.
+/// `PosixResidency::ThreadLocalStorage` indicates that the shared object has thread-local storage,
+/// whose destructors registered by running threads may keep it loaded.
+/// `PosixResidency::Referenced` indicates that no other reason was found, so other handles likely refer to it.
+pub enum PosixResidency {
+    NoDelete,
+    UniqueSymbols,
+    Dependents(Vec<ffi::CString>),
+    ThreadLocalStorage,
+    Referenced,
+}
+
+impl Clone for PosixResidency {
+    fn clone(&self) -> Self {
+        match self {
+            Self::NoDelete => Self::NoDelete,
+            Self::UniqueSymbols => Self::UniqueSymbols,
+            Self::Dependents(names) => Self::Dependents(names.clone()),
+            Self::ThreadLocalStorage => Self::ThreadLocalStorage,
+            Self::Referenced => Self::Referenced,
+        }
+    }
+}
+
+impl fmt::Debug for PosixResidency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoDelete => f.write_str("NoDelete"),
+            Self::UniqueSymbols => f.write_str("UniqueSymbols"),
+            Self::Dependents(names) => f.write_fmt(format_args!("Dependents({:?})", names)),
+            Self::ThreadLocalStorage => f.write_str("ThreadLocalStorage"),
+            Self::Referenced => f.write_str("Referenced"),
+        }
+    }
+}
+
+impl fmt::Display for PosixResidency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoDelete => f.write_str("marked as nodelete"),
+            Self::UniqueSymbols => f.write_str("defines unique symbols"),
+            Self::Dependents(names) => {
+                f.write_str("needed by ")?;
+
+                for (i, name) in names.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    f.write_str(&name.to_string_lossy())?;
+                }
+
+                Ok(())
+            }
+            Self::ThreadLocalStorage => f.write_str("has thread-local storage"),
+            Self::Referenced => f.write_str("referenced by other handles"),
+        }
+    }
+}
+
+/// Represents an error that occurred during closing and verifying unloading.
+///
+/// `PosixUnloadError::Linking(err)` contains a diagnostic message of a failed `dlclose`
+/// or of unavailable loader information.
+/// `PosixUnloadError::Resident(reasons)` indicates that the shared object remains loaded
+/// and contains the reasons found for it.
+pub enum PosixUnloadError {
+    Linking(PosixLinkingError),
+    Resident(Vec<PosixResidency>),
+}
+
+impl Clone for PosixUnloadError {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Linking(err) => Self::Linking(err.clone()),
+            Self::Resident(reasons) => Self::Resident(reasons.clone()),
+        }
+    }
+}
+
+impl fmt::Debug for PosixUnloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linking(err) => f.write_fmt(format_args!("Linking({:?})", err)),
+            Self::Resident(reasons) => f.write_fmt(format_args!("Resident({:?})", reasons)),
+        }
+    }
+}
+
+impl fmt::Display for PosixUnloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linking(err) => f.write_fmt(format_args!("Error occurred unloading: {}", err)),
+            Self::Resident(reasons) => {
+                f.write_str("Error occurred unloading: shared object remains loaded")?;
+
+                for (i, reason) in reasons.iter().enumerate() {
+                    f.write_str(if i == 0 { ", " } else { "; " })?;
+                    fmt::Display::fmt(reason, f)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl error::Error for PosixUnloadError {}
+
+/// Closes the `handle` and verifies that the shared object file is unloaded
+/// from the process address space.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink_posix::{
+///     symtab::{PosixHandle, RTLD_LAZY, RTLD_LOCAL},
+///     unload::{self, PosixUnloadError},
+/// };
+///
+/// fn main() {
+///     unsafe {
+///         let handle = PosixHandle::openc(c"libsum.so", RTLD_LOCAL | RTLD_LAZY)
+///             .expect("libsum handle was not opened");
+///
+///         match unload::close_and_verify(handle) {
+///             Ok(()) => println!("libsum was unloaded"),
+///             Err(PosixUnloadError::Resident(reasons)) => println!("libsum remains: {:?}", reasons),
+///             Err(err) => eprintln!("{}", err),
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Shared object finalization routines that are executed when this
+/// function is called may be UB.
+///
+/// # Notes
+///
+/// `dlclose` only decrements the reference count of the shared object, so it remains loaded
+/// while other handles or dependents refer to it, or when the loader pins it.
+/// Residency is checked with `RTLD_NOLOAD` and `dl_iterate_phdr`.
+///
+/// The reasons are inferred from the dynamic section, program headers and `DT_NEEDED` entries
+/// of other loaded shared objects, since the loader does not expose reference counts.
+/// Shared objects opened with the `RTLD_NODELETE` flag are reported as `Referenced`.
+pub unsafe fn close_and_verify(handle: PosixHandle) -> Result<(), PosixUnloadError> {
+    let (base, name) = match LinkMap::of(&handle) {
+        Ok(link_map) => (
+            link_map.l_addr,
+            ffi::CStr::from_ptr(link_map.l_name).to_owned(),
+        ),
+        Err(err) => {
+            drop(handle);
+            return Err(PosixUnloadError::Linking(err));
+        }
+    };
+
+    handle.close().map_err(PosixUnloadError::Linking)?;
+
+    let is_noload = {
+        let handle = libc::dlopen(name.as_ptr(), libc::RTLD_NOLOAD | libc::RTLD_LAZY);
+        if !handle.is_null() {
+            libc::dlclose(handle);
+        }
+
+        !handle.is_null()
+    };
+
+    match find_loaded(base, &name) {
+        Some(loaded) => Err(PosixUnloadError::Resident(loaded.reasons(&name))),
+        None if is_noload => Err(PosixUnloadError::Resident(vec![PosixResidency::Referenced])),
+        None => Ok(()),
+    }
+}
+
+/// Represents the residency-related properties of a loaded shared object.
+struct Loaded {
+    soname: Option<ffi::CString>,
+    is_nodelete: bool,
+    is_unique: bool,
+    is_tls: bool,
+}
+
+impl Loaded {
+    /// Returns the reasons the shared object `name` remains loaded.
+    unsafe fn reasons(&self, name: &ffi::CStr) -> Vec<PosixResidency> {
+        let mut reasons = Vec::new();
+
+        if self.is_nodelete {
+            reasons.push(PosixResidency::NoDelete);
+        }
+
+        if self.is_unique {
+            reasons.push(PosixResidency::UniqueSymbols);
+        }
+
+        let file_name = name
+            .to_bytes()
+            .rsplit(|it| *it == b'/')
+            .next()
+            .unwrap_or_default();
+
+        let dependents = find_dependents(self.soname.as_deref(), file_name);
+        if !dependents.is_empty() {
+            reasons.push(PosixResidency::Dependents(dependents));
+        }
+
+        if reasons.is_empty() {
+            if self.is_tls {
+                reasons.push(PosixResidency::ThreadLocalStorage);
+            }
+
+            reasons.push(PosixResidency::Referenced);
+        }
+
+        reasons
+    }
+}
+
+/// Returns the dynamic section of the shared object described by `info`.
+unsafe fn dynamic_of(info: &libc::dl_phdr_info) -> Option<DynamicInfo> {
+    let phdrs = phdrs_of(info);
+    let dynamic = phdrs.iter().find(|it| it.p_type == libc::PT_DYNAMIC)?;
+
+    let base = info.dlpi_addr as usize;
+    Some(DynamicInfo::read(
+        base,
+        (base + dynamic.p_vaddr as usize) as *const _,
+    ))
+}
+
+unsafe fn phdrs_of(info: &libc::dl_phdr_info) -> &[Phdr] {
+    if info.dlpi_phdr.is_null() {
+        &[]
+    } else {
+        std::slice::from_raw_parts(info.dlpi_phdr, info.dlpi_phnum as usize)
+    }
+}
+
+unsafe fn name_of(info: &libc::dl_phdr_info) -> &ffi::CStr {
+    if info.dlpi_name.is_null() {
+        c""
+    } else {
+        ffi::CStr::from_ptr(info.dlpi_name)
+    }
+}
+
+/// Finds the shared object `name` loaded at `base`.
+unsafe fn find_loaded(base: usize, name: &ffi::CStr) -> Option<Loaded> {
+    struct Search<'name> {
+        base: usize,
+        name: &'name ffi::CStr,
+        loaded: Option<Loaded>,
+    }
+
+    unsafe extern "C" fn callback(
+        info: *mut libc::dl_phdr_info,
+        _: libc::size_t,
+        data: *mut ffi::c_void,
+    ) -> ffi::c_int {
+        let search = &mut *data.cast::<Search>();
+        let info = &*info;
+
+        if info.dlpi_addr as usize != search.base || name_of(info) != search.name {
+            return 0;
+        }
+
+        let dynamic = dynamic_of(info);
+
+        search.loaded = Some(Loaded {
+            soname: dynamic
+                .as_ref()
+                .and_then(|it| it.soname())
+                .map(ffi::CStr::to_owned),
+            is_nodelete: dynamic
+                .as_ref()
+                .is_some_and(|it| it.flags_1 & DF_1_NODELETE != 0),
+            is_unique: dynamic.as_ref().is_some_and(|it| {
+                it.symbols()
+                    .iter()
+                    .any(|sym| sym.st_info >> 4 == STB_GNU_UNIQUE && sym.st_shndx != SHN_UNDEF)
+            }),
+            is_tls: phdrs_of(info).iter().any(|it| it.p_type == libc::PT_TLS),
+        });
+
+        1
+    }
+
+    let mut search = Search {
+        base,
+        name,
+        loaded: None,
+    };
+
+    libc::dl_iterate_phdr(Some(callback), (&mut search as *mut Search).cast());
+    search.loaded
+}
+
+/// Finds loaded shared objects that need the shared object by `soname` or `file_name`.
+unsafe fn find_dependents(soname: Option<&ffi::CStr>, file_name: &[u8]) -> Vec<ffi::CString> {
+    struct Search<'name> {
+        soname: Option<&'name ffi::CStr>,
+        file_name: &'name [u8],
+        dependents: Vec<ffi::CString>,
+    }
+
+    unsafe extern "C" fn callback(
+        info: *mut libc::dl_phdr_info,
+        _: libc::size_t,
+        data: *mut ffi::c_void,
+    ) -> ffi::c_int {
+        let search = &mut *data.cast::<Search>();
+        let info = &*info;
+
+        let Some(dynamic) = dynamic_of(info) else {
+            return 0;
+        };
+
+        let is_dependent = dynamic
+            .needed()
+            .iter()
+            .any(|needed| Some(*needed) == search.soname || needed.to_bytes() == search.file_name);
+
+        if is_dependent {
+            search.dependents.push(name_of(info).to_owned());
+        }
+
+        0
+    }
+
+    let mut search = Search {
+        soname,
+        file_name,
+        dependents: Vec::new(),
+    };
+
+    libc::dl_iterate_phdr(Some(callback), (&mut search as *mut Search).cast());
+    search.dependents
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::unload::{PosixResidency, PosixUnloadError};
+
+    pub fn assert_send<T: Send>() {}
+    pub fn assert_sync<T: Sync>() {}
+
+    #[test]
+    pub fn posix_residency_marked_as_send_test() {
+        assert_send::<PosixResidency>();
+    }
+
+    #[test]
+    pub fn posix_residency_marked_as_sync_test() {
+        assert_sync::<PosixResidency>();
+    }
+
+    #[test]
+    pub fn posix_unload_error_marked_as_send_test() {
+        assert_send::<PosixUnloadError>();
+    }
+
+    #[test]
+    pub fn posix_unload_error_marked_as_sync_test() {
+        assert_sync::<PosixUnloadError>();
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dynlink_posix::interpose::Interposed;
+
+static INTERPOSED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+dynlink_posix::interpose! {
+    unsafe extern "C" fn getppid() -> libc::pid_t as real {
+        INTERPOSED_CALLS.fetch_add(1, Ordering::SeqCst);
+
+        // nested call must go straight to the real implementation
+        assert_eq!(real(), libc::getppid());
+        real()
+    }
+}
+
+#[test]
+pub fn interposed_resolves_real_implementation_when_symbol_exists() {
+    static REAL_GETPID: Interposed<unsafe extern "C" fn() -> libc::pid_t> =
+        Interposed::new(c"getpid");
+
+    unsafe {
+        let real = REAL_GETPID.resolve().expect("Symbol was not resolved");
+        assert_eq!(std::process::id() as libc::pid_t, real.apply(|it| it()));
+    }
+}
+
+#[test]
+pub fn interposed_fails_to_resolve_when_symbol_does_not_exist() {
+    static REAL_UNKNOWN: Interposed<unsafe extern "C" fn()> = Interposed::new(c"unknown");
+
+    unsafe {
+        assert!(REAL_UNKNOWN.resolve().is_none());
+    }
+}
+
+#[test]
+pub fn interpose_routes_calls_to_replacement_and_real_implementation() {
+    unsafe {
+        let calls = INTERPOSED_CALLS.load(Ordering::SeqCst);
+        let ppid = libc::getppid();
+
+        assert_eq!(calls + 1, INTERPOSED_CALLS.load(Ordering::SeqCst));
+        assert_eq!(libc::syscall(libc::SYS_getppid) as libc::pid_t, ppid);
+    }
+}
+
+#[test]
+pub fn interposed_exposes_symbol_name() {
+    static REAL_GETPID: Interposed<unsafe extern "C" fn() -> libc::pid_t> =
+        Interposed::new(c"getpid");
+
+    assert_eq!(c"getpid", REAL_GETPID.symbol());
+}
@@ -0,0 +1,4 @@
+#[cfg(target_os = "linux")]
+mod interposed;
+#[cfg(target_os = "linux")]
+mod preload;
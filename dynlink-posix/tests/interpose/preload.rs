@@ -0,0 +1,36 @@
+use std::{env, path, process};
+
+/// Returns the path of the `preload` example, which `cargo test` builds into the `examples`
+/// directory next to the `deps` directory of the test binaries of any profile or target.
+///
+/// The example is not built when only this test target is selected (e.g. with `--test`),
+/// so it has to be built with `cargo build -p dynlink-posix --example preload` of the same profile.
+fn libpreload() -> path::PathBuf {
+    let exe = env::current_exe().expect("Test executable was not found");
+    let path = exe
+        .parent()
+        .and_then(|it| it.parent())
+        .expect("Test executable is not in a profile directory")
+        .join("examples")
+        .join("libpreload.so");
+
+    assert!(
+        path.exists(),
+        "Preload example was not built at {:?}, run `cargo test -p dynlink-posix`",
+        path
+    );
+
+    path
+}
+
+#[test]
+pub fn interpose_replaces_functions_of_preloaded_process() {
+    let output = process::Command::new("sh")
+        .args(["-c", "echo $PPID"])
+        .env("LD_PRELOAD", libpreload())
+        .output()
+        .expect("Process was not run");
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!("4242\n", String::from_utf8_lossy(&output.stdout));
+}
@@ -8,4 +8,5 @@
         any(target_arch = "aarch64", target_arch = "x86_64"),
     )
 ))]
+#[allow(clippy::redundant_static_lifetimes)]
 mod handle;
@@ -1,4 +1,4 @@
-use std::{error, ffi, fmt, os::windows::ffi::OsStrExt};
+use std::{error, ffi, fmt, mem, os::windows::ffi::OsStrExt};
 
 use pointersized::PointerSized;
 use smallvec;
@@ -348,6 +348,27 @@ impl Win32Handle {
             }
         }
     }
+
+    /// Closes the handle and decrements the reference count of the shared object file,
+    /// which is unloaded from the process address space when the count reaches zero.
+    ///
+    /// Unlike dropping the handle, this function reports a failure of `FreeLibrary`.
+    ///
+    /// # Safety
+    ///
+    /// Shared object finalization routines that are executed when this
+    /// function is called may be UB.
+    pub unsafe fn close(self) -> Result<(), Win32LinkingError> {
+        let handle = self.0;
+        mem::forget(self);
+
+        if Foundation::FreeLibrary(handle) != 0 {
+            Ok(())
+        } else {
+            let err = Foundation::GetLastError();
+            Err(Win32LinkingError::from_raw_code(err))
+        }
+    }
 }
 
 unsafe impl Send for Win32Handle {}
@@ -52,10 +52,27 @@ impl<'symtab, T: PointerSized> Win32Symbol<'symtab, T> {
     ///
     /// Type `T` must be ABI compatible with the type of symbol from a shared object.
     #[inline]
-    pub unsafe fn apply<R>(&self, f: impl Fn(T) -> R) -> R {
+    pub unsafe fn apply<R>(&self, f: impl FnOnce(T) -> R) -> R {
+        // fails to compile for types that are not pointer-sized
+        let () = T::ASSERT_POINTER_SIZED;
+
         f((&self.0 as *const *mut ffi::c_void).cast::<T>().read())
     }
 
+    /// Borrows as the type it represents.
+    ///
+    /// # Safety
+    ///
+    /// Value of the type `T` (which can be copied) must not outlive the handle that owns it.
+    /// Type `T` must be ABI compatible with the type of symbol from a shared object.
+    #[inline]
+    pub unsafe fn get(&self) -> &T {
+        // fails to compile for types that are not pointer-sized
+        let () = T::ASSERT_POINTER_SIZED;
+
+        &*(&self.0 as *const *mut ffi::c_void).cast::<T>()
+    }
+
     /// Leaks as the type it represents.
     ///
     /// # Safety
@@ -64,6 +81,9 @@ impl<'symtab, T: PointerSized> Win32Symbol<'symtab, T> {
     /// Type `T` must be ABI compatible with the type of symbol from a shared object.
     #[inline]
     pub unsafe fn leak(self) -> T {
+        // fails to compile for types that are not pointer-sized
+        let () = T::ASSERT_POINTER_SIZED;
+
         (&self.0 as *const *mut ffi::c_void).cast::<T>().read()
     }
 
@@ -76,6 +96,16 @@ impl<'symtab, T: PointerSized> Win32Symbol<'symtab, T> {
     pub unsafe fn leak_as_raw(self) -> *mut ffi::c_void {
         self.0
     }
+
+    /// Reinterprets as a symbol of the type `U`.
+    ///
+    /// # Safety
+    ///
+    /// Type `U` must be ABI compatible with the type of symbol from a shared object.
+    #[inline]
+    pub unsafe fn cast<U: PointerSized>(self) -> Win32Symbol<'symtab, U> {
+        Win32Symbol(self.0, marker::PhantomData)
+    }
 }
 
 unsafe impl<'symtab, T: PointerSized> Send for Win32Symbol<'symtab, T> {}
@@ -144,6 +174,26 @@ mod unittest {
         }
     }
 
+    #[test]
+    pub fn win32_symbol_borrows_as_type_it_represents_test() {
+        unsafe {
+            let symbol: Win32Symbol<'_, fn(i32, i32) -> i32> =
+                Win32Symbol(sum as *mut ffi::c_void, marker::PhantomData);
+
+            assert_eq!(2, symbol.get()(1, 1));
+        }
+    }
+
+    #[test]
+    pub fn win32_symbol_casts_to_another_type_test() {
+        unsafe {
+            let symbol: Win32Symbol<'_, *mut ffi::c_void> =
+                Win32Symbol(sum as *mut ffi::c_void, marker::PhantomData);
+
+            assert_eq!(2, symbol.cast::<fn(i32, i32) -> i32>().leak()(1, 1));
+        }
+    }
+
     #[test]
     pub fn win32_symbol_leaks_as_raw_ptr_test() {
         unsafe {
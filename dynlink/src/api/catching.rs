@@ -0,0 +1,218 @@
+use std::{any, error, fmt, panic, sync};
+
+use pointersized::{FnPtr, PointerSized};
+
+use crate::api::{OwnedSymbol, Symbol};
+
+/// Represents an error that occurred during a call of a symbol.
+///
+/// `CallError` contains the name of the symbol and the payload of the panic
+/// that unwound out of the call.
+pub struct CallError {
+    symbol: String,
+    // keeps the error `Sync` while the payload is only `Send`
+    payload: sync::Mutex<Box<dyn any::Any + Send>>,
+}
+
+impl CallError {
+    fn new(symbol: &str, payload: Box<dyn any::Any + Send>) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            payload: sync::Mutex::new(payload),
+        }
+    }
+
+    /// Returns the name of the symbol whose call unwound.
+    #[inline]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Returns the message of the panic, or `None` if the payload is not a string.
+    pub fn message(&self) -> Option<String> {
+        let payload = self
+            .payload
+            .lock()
+            .unwrap_or_else(sync::PoisonError::into_inner);
+
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            Some((*message).to_owned())
+        } else {
+            payload.downcast_ref::<String>().cloned()
+        }
+    }
+
+    /// Consumes the error and returns the payload of the panic,
+    /// which is usually passed to `std::panic::resume_unwind`.
+    pub fn into_payload(self) -> Box<dyn any::Any + Send> {
+        self.payload
+            .into_inner()
+            .unwrap_or_else(sync::PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "CallError({:?}, {:?})",
+            self.symbol,
+            self.message()
+        ))
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.message() {
+            Some(message) => f.write_fmt(format_args!(
+                "Error occurred calling symbol {}: panicked with '{}'",
+                self.symbol, message
+            )),
+            None => f.write_fmt(format_args!(
+                "Error occurred calling symbol {}: unwound with non-string payload",
+                self.symbol
+            )),
+        }
+    }
+}
+
+impl error::Error for CallError {}
+
+impl<'symtab, T: PointerSized> Symbol<'symtab, T> {
+    /// Applies as the type it represents, converting a panic into `CallError` named `symbol`.
+    ///
+    /// # Usage
+    ///
+    /// `apply_catching` is used where a panic of `f`, or of a host callback that unwinds
+    /// through the shared object, must not bring the process down.
+    ///
+    /// ```no_run
+    /// use dynlink::api::Handle;
+    ///
+    /// // plugin.c, compiled with -fexceptions
+    /// //
+    /// // int plugin_run(int (*cb)(int), int value) {
+    /// //     return cb(value);
+    /// // }
+    ///
+    /// type CallbackFn = extern "C-unwind" fn(i32) -> i32;
+    /// type RunFn = extern "C-unwind" fn(CallbackFn, i32) -> i32;
+    ///
+    /// extern "C-unwind" fn validate(value: i32) -> i32 {
+    ///     assert!(value >= 0, "negative value");
+    ///     value
+    /// }
+    ///
+    /// fn main() {
+    ///     unsafe {
+    ///         let handle = Handle::open("libplugin.so")
+    ///             .expect("libplugin handle was not opened");
+    ///
+    ///         let run = handle.lookup::<RunFn>("plugin_run")
+    ///             .expect("plugin_run symbol was not found");
+    ///
+    ///         let err = run.apply_catching("plugin_run", |run_fn| run_fn(validate, -1))
+    ///             .expect_err("panic was not caught");
+    ///
+    ///         assert_eq!("plugin_run", err.symbol());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Type `T` must be ABI compatible with the type of symbol from a shared object.
+    ///
+    /// # Notes
+    ///
+    /// Only the `C-unwind` and `system-unwind` ABIs allow unwinding out of a function pointer,
+    /// so functions that may throw or call back into panicking code must be typed with them,
+    /// and their shared objects must be compiled with unwind tables (e.g. `-fexceptions`).
+    /// A panic that unwinds out of a `C` function aborts the process before it is caught.
+    ///
+    /// A foreign exception (e.g. thrown by C++) is never converted into `CallError`,
+    /// since `std::panic::catch_unwind` aborts the process when one unwinds into it,
+    /// so C++ exceptions must be caught on the C++ side before they leave the shared object.
+    pub unsafe fn apply_catching<R>(
+        &self,
+        symbol: &str,
+        f: impl FnOnce(T) -> R,
+    ) -> Result<R, CallError> {
+        panic::catch_unwind(panic::AssertUnwindSafe(|| self.0.apply(f)))
+            .map_err(|payload| CallError::new(symbol, payload))
+    }
+
+    /// Calls the function it represents with the tuple of `args`,
+    /// converting a panic into `CallError` named `symbol`.
+    ///
+    /// # Safety
+    ///
+    /// Type `T` must be ABI compatible with the type of symbol from a shared object,
+    /// and the function must be safe to call with `args`.
+    ///
+    /// # Notes
+    ///
+    /// Unwinding is caught under the same conditions as with `Symbol::apply_catching`.
+    #[inline]
+    pub unsafe fn call_catching(&self, symbol: &str, args: T::Args) -> Result<T::Output, CallError>
+    where
+        T: FnPtr,
+    {
+        self.apply_catching(symbol, |it| it.call(args))
+    }
+}
+
+impl<T: PointerSized + 'static> OwnedSymbol<T> {
+    /// Calls the function it represents with the tuple of `args`,
+    /// converting a panic into `CallError` named by the name it was looked up by.
+    ///
+    /// # Safety
+    ///
+    /// The function must be safe to call with `args`, which is required for `unsafe` functions.
+    ///
+    /// # Notes
+    ///
+    /// Unwinding is caught under the same conditions as with `Symbol::apply_catching`.
+    #[inline]
+    pub unsafe fn call_catching(&self, args: T::Args) -> Result<T::Output, CallError>
+    where
+        T: FnPtr,
+    {
+        self.symbol().call_catching(self.name(), args)
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::api::CallError;
+
+    pub fn assert_send<T: Send>() {}
+    pub fn assert_sync<T: Sync>() {}
+
+    #[test]
+    pub fn call_error_marked_as_send_test() {
+        assert_send::<CallError>();
+    }
+
+    #[test]
+    pub fn call_error_marked_as_sync_test() {
+        assert_sync::<CallError>();
+    }
+
+    #[test]
+    pub fn call_error_reports_symbol_and_message_test() {
+        let err = CallError::new("plugin_run", Box::new("negative value"));
+
+        assert_eq!("plugin_run", err.symbol());
+        assert_eq!(Some("negative value".to_owned()), err.message());
+        assert_eq!(
+            "Error occurred calling symbol plugin_run: panicked with 'negative value'",
+            err.to_string()
+        );
+
+        let err = CallError::new("plugin_run", Box::new(42));
+
+        assert_eq!(None, err.message());
+        assert_eq!(Some(&42), err.into_payload().downcast_ref::<i32>());
+    }
+}
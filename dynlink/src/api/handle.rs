@@ -1,7 +1,8 @@
-use std::{error, ffi, fmt};
+use std::{error, ffi, fmt, mem, time};
 
 use crate::{
     api::Symbol,
+    loader,
     platform::{PlatformHandle, PlatformLinkingError, PlatformMessage},
 };
 
@@ -16,7 +17,7 @@ pub enum LinkingError {
 
 impl LinkingError {
     /// Creates owned error cloned from `PlatformLinkingError`.
-    pub(super) fn from(err: PlatformLinkingError) -> Self {
+    pub(crate) fn from(err: PlatformLinkingError) -> Self {
         match err {
             PlatformLinkingError::System(msg) => Self::System(msg),
             PlatformLinkingError::Unknown => Self::Unknown,
@@ -56,6 +57,50 @@ impl fmt::Display for LinkingError {
 
 impl error::Error for LinkingError {}
 
+/// Represents an error that occurred during lookup of the first resolving symbol of several names.
+///
+/// It contains every attempted name with the linking error of its lookup in the order of attempts.
+pub struct FallbackError(Vec<(String, LinkingError)>);
+
+impl FallbackError {
+    /// Returns the attempted names with the linking errors of their lookups.
+    #[inline]
+    pub fn attempts(&self) -> &[(String, LinkingError)] {
+        &self.0
+    }
+}
+
+impl Clone for FallbackError {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl fmt::Debug for FallbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("FallbackError({:?})", self.0))
+    }
+}
+
+impl fmt::Display for FallbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Error occurred dynamic linking: none of the symbols resolved")?;
+
+        for (i, (symbol, err)) in self.0.iter().enumerate() {
+            f.write_str(if i == 0 { ", " } else { "; " })?;
+
+            match err {
+                LinkingError::System(msg) => f.write_fmt(format_args!("{}: {}", symbol, msg))?,
+                LinkingError::Unknown => f.write_fmt(format_args!("{}: unknown", symbol))?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for FallbackError {}
+
 /// Represents an opaque handle of a shared object file's symbol table.
 ///
 /// # Usage
@@ -91,7 +136,16 @@ impl error::Error for LinkingError {}
 /// `Handle::open` is called may contain undefined behavior (UB).
 ///
 /// The thread-safety of `Handle` depends on the platform implementation.
-pub struct Handle(pub(super) PlatformHandle);
+///
+/// # Notes
+///
+/// Opening, lookup and closing are reported to the process-wide `loader::LoaderHooks`.
+/// The path is copied on opening for `Handle::path` and the reported events, while the elapsed
+/// time is measured only when hooks are installed or the `log` or `tracing` feature is enabled.
+pub struct Handle(
+    pub(crate) mem::ManuallyDrop<PlatformHandle>,
+    Box<ffi::OsStr>,
+);
 
 impl Handle {
     /// Opens shared object file specified by `path` with default options and loads
@@ -102,10 +156,19 @@ impl Handle {
     /// Shared object initialization routines that are executed when this
     /// function is called may be UB.
     pub unsafe fn open(path: impl AsRef<ffi::OsStr>) -> Result<Self, LinkingError> {
-        match PlatformHandle::open(path) {
-            Ok(handle) => Ok(Self(handle)),
+        let path = path.as_ref();
+        let started = Stopwatch::start();
+
+        let result = match PlatformHandle::open(path) {
+            Ok(handle) => Ok(Self(mem::ManuallyDrop::new(handle), Box::from(path))),
             Err(err) => Err(LinkingError::from(err)),
+        };
+
+        if let Some(elapsed) = started.elapsed() {
+            loader::notify_open(path, result.as_ref().map(|_| ()), elapsed);
         }
+
+        result
     }
 
     /// Looks up a symbol from the shared object file's symbol table by name.
@@ -117,16 +180,138 @@ impl Handle {
         &self,
         symbol: &str,
     ) -> Result<Symbol<'_, T>, LinkingError> {
-        match self.0.lookup(symbol) {
+        let started = Stopwatch::start();
+
+        let result = match self.0.lookup(symbol) {
             Ok(symbol) => Ok(Symbol(symbol)),
             Err(err) => Err(LinkingError::from(err)),
+        };
+
+        if let Some(elapsed) = started.elapsed() {
+            loader::notify_lookup(&self.1, symbol, result.as_ref().map(|_| ()), elapsed);
         }
+
+        result
+    }
+
+    /// Looks up the first symbol of `symbols` that is found in the shared object file's
+    /// symbol table and returns it together with the name it was found by.
+    ///
+    /// # Usage
+    ///
+    /// `lookup_first` is used when a symbol is renamed between releases of a shared object.
+    ///
+    /// ```no_run
+    /// use dynlink::api::Handle;
+    ///
+    /// fn main() {
+    ///     unsafe {
+    ///         let handle = Handle::open("libsum.so")
+    ///             .expect("libsum handle was not opened");
+    ///
+    ///         let (name, symbol) = handle
+    ///             .lookup_first::<extern "C" fn(i32, i32) -> i32>(&["sum_of_v2", "sum_of", "_sum_of"])
+    ///             .expect("none of sum_of symbols was found");
+    ///
+    ///         println!("{} returned {}", name, symbol.apply(|it| it(1, 1)));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Type `T` must be ABI compatible with the type of the symbol found from the shared object.
+    ///
+    /// # Notes
+    ///
+    /// Every attempt is reported to the loader hooks as a separate lookup.
+    ///
+    /// Symbols found with a null address (e.g. absolute symbols of disabled implementations)
+    /// are skipped and recorded as attempts failed with `LinkingError::Unknown`.
+    pub unsafe fn lookup_first<'name, T: pointersized::PointerSized>(
+        &self,
+        symbols: &[&'name str],
+    ) -> Result<(&'name str, Symbol<'_, T>), FallbackError> {
+        let mut attempts = Vec::with_capacity(symbols.len());
+
+        for symbol in symbols {
+            match self.lookup::<T>(symbol) {
+                Ok(found) if found.clone().leak_as_raw().is_null() => {
+                    attempts.push((String::from(*symbol), LinkingError::Unknown))
+                }
+                Ok(found) => return Ok((symbol, found)),
+                Err(err) => attempts.push((String::from(*symbol), err)),
+            }
+        }
+
+        Err(FallbackError(attempts))
+    }
+
+    /// Returns the path the shared object file was opened with.
+    #[inline]
+    pub fn path(&self) -> &ffi::OsStr {
+        &self.1
+    }
+
+    /// Closes the handle with `close` instead of dropping it and reports closing to
+    /// the loader hooks, where `failure` returns the linking error of a failed result.
+    pub(crate) unsafe fn close_with<R>(
+        self,
+        close: impl FnOnce(PlatformHandle) -> R,
+        failure: impl FnOnce(&R) -> Option<LinkingError>,
+    ) -> R {
+        let mut this = mem::ManuallyDrop::new(self);
+        let handle = mem::ManuallyDrop::take(&mut this.0);
+        let path = mem::take(&mut this.1);
+
+        let started = Stopwatch::start();
+        let result = close(handle);
+
+        if let Some(elapsed) = started.elapsed() {
+            let outcome = match failure(&result) {
+                Some(err) => Err(err),
+                None => Ok(()),
+            };
+
+            loader::notify_close(&path, outcome.as_ref().map(|_| ()), elapsed);
+        }
+
+        result
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        let started = Stopwatch::start();
+
+        // SAFETY: the platform handle is not used after it is taken.
+        let result = unsafe { mem::ManuallyDrop::take(&mut self.0).close() };
+
+        if let Some(elapsed) = started.elapsed() {
+            let result = result.map_err(LinkingError::from);
+            loader::notify_close(&self.1, result.as_ref().map(|_| ()), elapsed);
+        }
+    }
+}
+
+/// Measures the elapsed time only when loader hooks are active.
+struct Stopwatch(Option<time::Instant>);
+
+impl Stopwatch {
+    #[inline]
+    fn start() -> Self {
+        Self(loader::is_active().then(time::Instant::now))
+    }
+
+    #[inline]
+    fn elapsed(&self) -> Option<time::Duration> {
+        self.0.map(|started| started.elapsed())
     }
 }
 
 impl fmt::Debug for Handle {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("{:?}", self.0))
+        f.write_fmt(format_args!("{:?}", *self.0))
     }
 }
 
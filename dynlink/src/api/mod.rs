@@ -1,5 +1,9 @@
+mod catching;
 mod handle;
+mod owned;
 mod symbol;
 
-pub use handle::{Handle, LinkingError};
-pub use symbol::Symbol;
+pub use catching::CallError;
+pub use handle::{FallbackError, Handle, LinkingError};
+pub use owned::{OwnedSymbol, StaticSymbol};
+pub use symbol::{RawSymbol, Symbol};
@@ -0,0 +1,232 @@
+use std::{fmt, mem, ops, sync};
+
+use pointersized::{FnPtr, PointerSized};
+
+use crate::api::{Handle, LinkingError, Symbol};
+
+/// Represents a typed symbol that keeps the shared object file it was looked up from opened.
+///
+/// # Usage
+///
+/// `OwnedSymbol` is stored without the handle that owns it, so that function symbols
+/// are called after the handle is dropped.
+///
+/// ```no_run
+/// use dynlink::{api::OwnedSymbol, registry};
+///
+/// // sum.c
+/// //
+/// // int sum_of(int a, int b) {
+/// //    return a + b;
+/// // }
+///
+/// fn main() {
+///     unsafe {
+///         let handle = registry::open_shared("libsum.so")
+///             .expect("libsum handle was not opened");
+///
+///         let sum_of: OwnedSymbol<extern "C" fn(i32, i32) -> i32> = handle.lookup_owned("sum_of")
+///             .expect("sum_of symbol was not found");
+///
+///         drop(handle);
+///         assert_eq!(2, sum_of.call((1, 1)));
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// `OwnedSymbol` does not dereference to the type it represents, since a copied out value
+/// of the type `T` would outlive the handle it keeps. Symbols of handles that are never closed
+/// are converted into `StaticSymbol`, which does.
+pub struct OwnedSymbol<T: PointerSized + 'static> {
+    symbol: Symbol<'static, T>,
+    name: Box<str>,
+    // keeps the symbol valid
+    handle: sync::Arc<Handle>,
+}
+
+impl<T: PointerSized + 'static> OwnedSymbol<T> {
+    /// Calls the function it represents with the tuple of `args`.
+    ///
+    /// # Safety
+    ///
+    /// The function must be safe to call with `args`, which is required for `unsafe` functions.
+    #[inline]
+    pub unsafe fn call(&self, args: T::Args) -> T::Output
+    where
+        T: FnPtr,
+    {
+        self.symbol.call(args)
+    }
+
+    /// Returns the symbol it owns, borrowed for the lifetime of `self`.
+    #[inline]
+    pub fn symbol(&self) -> &Symbol<'_, T> {
+        &self.symbol
+    }
+
+    /// Returns the name it was looked up by.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the handle it keeps opened.
+    #[inline]
+    pub fn handle(&self) -> &sync::Arc<Handle> {
+        &self.handle
+    }
+}
+
+impl<T: PointerSized + 'static> Clone for OwnedSymbol<T> {
+    fn clone(&self) -> Self {
+        Self {
+            symbol: self.symbol.clone(),
+            name: self.name.clone(),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+impl<T: PointerSized + 'static> fmt::Debug for OwnedSymbol<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "OwnedSymbol({:?}, {:?}, {:?})",
+            self.name, self.symbol, self.handle
+        ))
+    }
+}
+
+/// Represents a typed symbol of a shared object file that is never closed.
+///
+/// # Usage
+///
+/// `StaticSymbol` is converted from the symbols of leaked handles (e.g. the ones resolved
+/// by `LazySymbol`) and dereferences to the type it represents, so that function symbols
+/// are called directly.
+///
+/// ```no_run
+/// use dynlink::api::{Handle, StaticSymbol};
+///
+/// fn main() {
+///     unsafe {
+///         let handle: &'static Handle = Box::leak(Box::new(
+///             Handle::open("libsum.so").expect("libsum handle was not opened"),
+///         ));
+///
+///         let sum_of = StaticSymbol::from(
+///             handle.lookup::<extern "C" fn(i32, i32) -> i32>("sum_of")
+///                 .expect("sum_of symbol was not found"),
+///         );
+///
+///         assert_eq!(2, sum_of(1, 1));
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// Values of the type `T` that are copied out of the dereferenced symbol stay valid,
+/// since the shared object file it was looked up from is never unloaded.
+pub struct StaticSymbol<T: PointerSized + 'static>(Symbol<'static, T>);
+
+impl<T: PointerSized + 'static> StaticSymbol<T> {
+    /// Calls the function it represents with the tuple of `args`.
+    ///
+    /// # Safety
+    ///
+    /// The function must be safe to call with `args`, which is required for `unsafe` functions.
+    #[inline]
+    pub unsafe fn call(&self, args: T::Args) -> T::Output
+    where
+        T: FnPtr,
+    {
+        self.0.call(args)
+    }
+
+    /// Returns the symbol it represents.
+    #[inline]
+    pub fn symbol(&self) -> &Symbol<'static, T> {
+        &self.0
+    }
+}
+
+impl<T: PointerSized + 'static> From<Symbol<'static, T>> for StaticSymbol<T> {
+    fn from(symbol: Symbol<'static, T>) -> Self {
+        Self(symbol)
+    }
+}
+
+impl<T: PointerSized + 'static> ops::Deref for StaticSymbol<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: ABI compatibility was asserted when the symbol was looked up,
+        // and the handle that owns it is never closed.
+        unsafe { self.0 .0.get() }
+    }
+}
+
+impl<T: PointerSized + 'static> Clone for StaticSymbol<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: PointerSized + 'static> fmt::Debug for StaticSymbol<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("StaticSymbol({:?})", self.0))
+    }
+}
+
+impl Handle {
+    /// Looks up a symbol from the shared object file's symbol table by name
+    /// and keeps the handle opened while the symbol lives.
+    ///
+    /// # Safety
+    ///
+    /// Type `T` must be ABI compatible with the type of symbol from the shared object.
+    pub unsafe fn lookup_owned<T: PointerSized + 'static>(
+        self: &sync::Arc<Self>,
+        symbol: &str,
+    ) -> Result<OwnedSymbol<T>, LinkingError> {
+        let found = self.lookup::<T>(symbol)?;
+
+        Ok(OwnedSymbol {
+            // SAFETY: symbol lives as long as the handle that is kept together with it.
+            symbol: mem::transmute::<Symbol<'_, T>, Symbol<'static, T>>(found),
+            name: symbol.into(),
+            handle: sync::Arc::clone(self),
+        })
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::api::{OwnedSymbol, StaticSymbol};
+
+    pub fn assert_send<T: Send>() {}
+    pub fn assert_sync<T: Sync>() {}
+
+    #[test]
+    pub fn owned_symbol_marked_as_send_test() {
+        assert_send::<OwnedSymbol<fn(i32, i32) -> i32>>();
+    }
+
+    #[test]
+    pub fn owned_symbol_marked_as_sync_test() {
+        assert_sync::<OwnedSymbol<fn(i32, i32) -> i32>>();
+    }
+
+    #[test]
+    pub fn static_symbol_marked_as_send_test() {
+        assert_send::<StaticSymbol<fn(i32, i32) -> i32>>();
+    }
+
+    #[test]
+    pub fn static_symbol_marked_as_sync_test() {
+        assert_sync::<StaticSymbol<fn(i32, i32) -> i32>>();
+    }
+}
@@ -1,6 +1,6 @@
 use std::{ffi, fmt};
 
-use pointersized::PointerSized;
+use pointersized::{FnPtr, PointerSized};
 
 use crate::platform::PlatformSymbol;
 
@@ -29,6 +29,9 @@ use crate::platform::PlatformSymbol;
 ///
 ///         let sum = symbol.apply(|sum_of_fn| sum_of_fn(1, 1));
 ///         assert_eq!(2, sum);
+///
+///         let sum = symbol.call((1, 1));
+///         assert_eq!(2, sum);
 ///     }
 /// }
 /// ```
@@ -46,10 +49,23 @@ impl<'symtab, T: PointerSized> Symbol<'symtab, T> {
     ///
     /// Type `T` must be ABI compatible with the type of symbol from a shared object.
     #[inline]
-    pub unsafe fn apply<R>(&self, f: impl Fn(T) -> R) -> R {
+    pub unsafe fn apply<R>(&self, f: impl FnOnce(T) -> R) -> R {
         self.0.apply(f)
     }
 
+    /// Calls the function it represents with the tuple of `args`.
+    ///
+    /// # Safety
+    ///
+    /// Type `T` must be ABI compatible with the type of symbol from a shared object.
+    #[inline]
+    pub unsafe fn call(&self, args: T::Args) -> T::Output
+    where
+        T: FnPtr,
+    {
+        self.0.apply(|it| it.call(args))
+    }
+
     /// Leaks as the type it represents.
     ///
     /// # Safety
@@ -70,8 +86,43 @@ impl<'symtab, T: PointerSized> Symbol<'symtab, T> {
     pub unsafe fn leak_as_raw(self) -> *mut ffi::c_void {
         self.0.leak_as_raw()
     }
+
+    /// Reinterprets as a symbol of the type `U`.
+    ///
+    /// # Safety
+    ///
+    /// Type `U` must be ABI compatible with the type of symbol from a shared object.
+    #[inline]
+    pub unsafe fn cast<U: PointerSized>(self) -> Symbol<'symtab, U> {
+        Symbol(self.0.cast())
+    }
 }
 
+/// Represents an untyped symbol from a shared object file's symbol table.
+///
+/// # Usage
+///
+/// `RawSymbol` is looked up when the type of symbol is not known yet,
+/// and is cast to its type with `Symbol::cast` once it is.
+///
+/// ```no_run
+/// use dynlink::api::{Handle, RawSymbol};
+///
+/// fn main() {
+///     unsafe {
+///         let handle = Handle::open("libsum.so")
+///             .expect("libsum handle was not opened");
+///
+///         let symbol: RawSymbol<'_> = handle.lookup("sum_of")
+///             .expect("sum_of symbol was not found");
+///
+///         let sum = symbol.cast::<extern "C" fn(i32, i32) -> i32>().call((1, 1));
+///         assert_eq!(2, sum);
+///     }
+/// }
+/// ```
+pub type RawSymbol<'symtab> = Symbol<'symtab, *mut ffi::c_void>;
+
 impl<'symtab, T: PointerSized> Clone for Symbol<'symtab, T> {
     fn clone(&self) -> Self {
         Self(self.0.clone())
@@ -0,0 +1,7 @@
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod thunk;
+mod trampoline;
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+pub use thunk::{Thunk, ThunkError};
+pub use trampoline::{Callback, CallbackFn};
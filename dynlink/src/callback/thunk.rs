@@ -0,0 +1,249 @@
+use std::{any, arch::naked_asm, ffi, fmt};
+
+use dynlink_posix::exec::{PosixExecError, PosixExecMemory};
+
+use crate::callback::{trampoline::CURRENT, Callback, CallbackFn};
+
+/// Represents an error that occurred during mapping of a thunk.
+pub type ThunkError = PosixExecError;
+
+/// Stores the state of the entered thunk for the function it jumps to.
+extern "C" fn enter(state: *mut ffi::c_void) {
+    CURRENT.set(state);
+}
+
+/// Saves the argument registers, stores the state from `r10` and jumps to the function in `r11`,
+/// so the function receives the arguments and the return address the thunk was called with.
+#[unsafe(naked)]
+unsafe extern "C" fn entry() {
+    naked_asm!(
+        "push rax",
+        "push rdi",
+        "push rsi",
+        "push rdx",
+        "push rcx",
+        "push r8",
+        "push r9",
+        "push r11",
+        "sub rsp, 136",
+        "movdqu xmmword ptr [rsp], xmm0",
+        "movdqu xmmword ptr [rsp + 16], xmm1",
+        "movdqu xmmword ptr [rsp + 32], xmm2",
+        "movdqu xmmword ptr [rsp + 48], xmm3",
+        "movdqu xmmword ptr [rsp + 64], xmm4",
+        "movdqu xmmword ptr [rsp + 80], xmm5",
+        "movdqu xmmword ptr [rsp + 96], xmm6",
+        "movdqu xmmword ptr [rsp + 112], xmm7",
+        "mov rdi, r10",
+        "call {enter}",
+        "movdqu xmm0, xmmword ptr [rsp]",
+        "movdqu xmm1, xmmword ptr [rsp + 16]",
+        "movdqu xmm2, xmmword ptr [rsp + 32]",
+        "movdqu xmm3, xmmword ptr [rsp + 48]",
+        "movdqu xmm4, xmmword ptr [rsp + 64]",
+        "movdqu xmm5, xmmword ptr [rsp + 80]",
+        "movdqu xmm6, xmmword ptr [rsp + 96]",
+        "movdqu xmm7, xmmword ptr [rsp + 112]",
+        "add rsp, 136",
+        "pop r11",
+        "pop r9",
+        "pop r8",
+        "pop rcx",
+        "pop rdx",
+        "pop rsi",
+        "pop rdi",
+        "pop rax",
+        "jmp r11",
+        enter = sym enter,
+    )
+}
+
+/// Returns the machine code of a thunk that enters `function` with `state`.
+fn code(state: *mut ffi::c_void, function: *const ffi::c_void) -> Vec<u8> {
+    let mut code = Vec::with_capacity(34);
+
+    // mov r10, state
+    code.extend([0x49, 0xba]);
+    code.extend((state as u64).to_le_bytes());
+
+    // mov r11, function
+    code.extend([0x49, 0xbb]);
+    code.extend((function as u64).to_le_bytes());
+
+    // jmp qword ptr [rip], followed by the address of the entry
+    code.extend([0xff, 0x25, 0x00, 0x00, 0x00, 0x00]);
+    code.extend((entry as *const () as u64).to_le_bytes());
+
+    code
+}
+
+/// Represents a closure passed to C as a function pointer without user data,
+/// which is valid until the thunk is dropped.
+///
+/// # Usage
+///
+/// `Thunk` is used for C functions that take a callback without a `void *` to pass back,
+/// by mapping a small piece of executable code for each closure.
+///
+/// ```no_run
+/// use dynlink::{api::Handle, callback::Thunk};
+///
+/// // callback.c
+/// //
+/// // int apply_bare(int (*cb)(int), int value) {
+/// //     return cb(value);
+/// // }
+///
+/// type ApplyFn = extern "C" fn(unsafe extern "C" fn(i32) -> i32, i32) -> i32;
+///
+/// fn main() {
+///     unsafe {
+///         let handle = Handle::open("libcallback.so")
+///             .expect("libcallback handle was not opened");
+///
+///         let apply = handle.lookup::<ApplyFn>("apply_bare")
+///             .expect("apply_bare symbol was not found");
+///
+///         let offset = 21;
+///         let thunk = Thunk::new(|value: i32| value + offset)
+///             .expect("thunk was not mapped");
+///
+///         assert_eq!(42, apply.call((thunk.as_fn(), 21)));
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// A panicking closure does not unwind into C. The panic is kept by the thunk
+/// and returned by `Thunk::take_panic`, while C receives the default of the result.
+///
+/// The closure is called through `&mut`, so C must not call it concurrently or reentrantly.
+pub struct Thunk<F> {
+    code: PosixExecMemory,
+    callback: Callback<F>,
+}
+
+impl<F> Thunk<F> {
+    /// Creates a thunk calling `closure`.
+    pub fn new<Args>(closure: F) -> Result<Self, ThunkError>
+    where
+        F: CallbackFn<Args>,
+    {
+        let callback = Callback::new(closure);
+
+        // SAFETY: code is generated for the running architecture and refers to the boxed state
+        // which lives as long as the thunk.
+        let code = unsafe { PosixExecMemory::new(&code(callback.user_data(), F::current()))? };
+
+        Ok(Self { code, callback })
+    }
+
+    /// Returns the function pointer that calls the closure.
+    #[inline]
+    pub fn as_fn<Args>(&self) -> F::Bare
+    where
+        F: CallbackFn<Args>,
+    {
+        // SAFETY: thunk code enters the function of the type `F::Bare`.
+        unsafe { F::bare(self.code.as_ptr()) }
+    }
+
+    /// Takes the payload of the first panic of the closure, if it panicked.
+    pub fn take_panic(&self) -> Option<Box<dyn any::Any + Send>> {
+        self.callback.take_panic()
+    }
+
+    /// Consumes the thunk and returns the closure.
+    pub fn into_inner(self) -> F {
+        self.callback.into_inner()
+    }
+}
+
+impl<F> fmt::Debug for Thunk<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Thunk({:p}, {:?})",
+            self.code.as_ptr(),
+            self.callback
+        ))
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use std::thread;
+
+    use crate::callback::Thunk;
+
+    pub extern "C" fn apply_many(
+        cb: unsafe extern "C" fn(i64, i64, i64, i64, i64, i64, i64, i64, f64, f32) -> f64,
+    ) -> f64 {
+        unsafe { cb(1, 2, 3, 4, 5, 6, 7, 8, 0.5, 0.25) }
+    }
+
+    #[test]
+    pub fn thunk_passes_register_and_stack_arguments_test() {
+        let thunk = Thunk::new(
+            |a: i64, b: i64, c: i64, d: i64, e: i64, f: i64, g: i64, h: i64, x: f64, y: f32| {
+                (a + b + c + d + e + f + g + h) as f64 + x + y as f64
+            },
+        )
+        .expect("thunk was not mapped");
+
+        assert_eq!(36.75, apply_many(thunk.as_fn()));
+    }
+
+    #[test]
+    pub fn thunk_distinguishes_closures_of_same_type_test() {
+        let thunks = (0..4)
+            .map(|offset| Thunk::new(move |value: i32| value + offset).unwrap())
+            .collect::<Vec<_>>();
+
+        let sums = thunks
+            .iter()
+            .map(|it| unsafe { it.as_fn()(10) })
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![10, 11, 12, 13], sums);
+    }
+
+    #[test]
+    pub fn thunk_reenters_other_thunks_and_threads_test() {
+        let inner = Thunk::new(|value: i32| value * 2).unwrap();
+        let inner_fn = inner.as_fn();
+
+        let outer = Thunk::new(move |value: i32| unsafe { inner_fn(value) } + 1).unwrap();
+        let outer_fn = outer.as_fn();
+
+        assert_eq!(43, unsafe { outer_fn(21) });
+
+        let outer_fn = outer_fn as usize;
+        let ret = thread::scope(|scope| {
+            scope
+                .spawn(move || unsafe {
+                    let outer_fn: unsafe extern "C" fn(i32) -> i32 = std::mem::transmute(outer_fn);
+                    outer_fn(1)
+                })
+                .join()
+                .unwrap()
+        });
+
+        assert_eq!(3, ret);
+    }
+
+    #[test]
+    pub fn thunk_keeps_panic_and_returns_default_test() {
+        let thunk = Thunk::new(|value: i32| -> i32 {
+            if value > 0 {
+                panic!("thunk panicked");
+            }
+
+            value
+        })
+        .unwrap();
+
+        assert_eq!(0, unsafe { thunk.as_fn()(1) });
+        assert!(thunk.take_panic().is_some());
+    }
+}
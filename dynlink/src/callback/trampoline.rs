@@ -0,0 +1,330 @@
+use std::{any, cell, ffi, fmt, panic, ptr, sync};
+
+/// Shared state that C function pointers receive as their user data.
+pub(super) struct State<F> {
+    closure: cell::UnsafeCell<F>,
+    panic: sync::Mutex<Option<Box<dyn any::Any + Send>>>,
+}
+
+thread_local! {
+    /// State of the thunk that is being entered on the current thread.
+    pub(super) static CURRENT: cell::Cell<*mut ffi::c_void> = const { cell::Cell::new(ptr::null_mut()) };
+}
+
+/// Calls the closure of the state `user_data` points to, catching a panic so it does not
+/// unwind into the C frame that called it.
+///
+/// # Safety
+///
+/// `user_data` must point to the state of a live `Callback<F>`.
+unsafe fn invoke<F, R: Default>(user_data: *mut ffi::c_void, call: impl FnOnce(&mut F) -> R) -> R {
+    let state = &*(user_data as *const State<F>);
+
+    match panic::catch_unwind(panic::AssertUnwindSafe(|| call(&mut *state.closure.get()))) {
+        Ok(ret) => ret,
+        Err(payload) => {
+            let mut panic = state
+                .panic
+                .lock()
+                .unwrap_or_else(sync::PoisonError::into_inner);
+
+            // the first panic is kept, since later ones are usually caused by it
+            if panic.is_none() {
+                *panic = Some(payload);
+            }
+
+            R::default()
+        }
+    }
+}
+
+/// Represents a closure that is called through C function pointers.
+///
+/// `CallbackFn` is implemented for `FnMut` closures with up to 12 arguments whose result
+/// implements `Default`, which is returned to C when the closure panics.
+/// Pointers are returned as `Option<NonNull<T>>`, which is `None` by default.
+pub trait CallbackFn<Args> {
+    /// `unsafe extern "C" fn(*mut c_void, A1, .., An) -> R` that takes user data first.
+    type Leading: Copy;
+    /// `unsafe extern "C" fn(A1, .., An, *mut c_void) -> R` that takes user data last.
+    type Trailing: Copy;
+    /// `unsafe extern "C" fn(A1, .., An) -> R` that takes no user data.
+    type Bare: Copy;
+
+    #[doc(hidden)]
+    fn leading() -> Self::Leading;
+
+    #[doc(hidden)]
+    fn trailing() -> Self::Trailing;
+
+    /// Returns the function that reads its state from `CURRENT`.
+    #[doc(hidden)]
+    fn current() -> *const ffi::c_void;
+
+    /// Returns `code` as the function that takes no user data.
+    ///
+    /// # Safety
+    ///
+    /// `code` must be a function of the type `Self::Bare`.
+    #[doc(hidden)]
+    unsafe fn bare(code: *const ffi::c_void) -> Self::Bare;
+}
+
+macro_rules! impl_callback_fn {
+    ($($arg:ident),*) => {
+        impl<F, R, $($arg),*> CallbackFn<($($arg,)*)> for F
+        where
+            F: FnMut($($arg),*) -> R,
+            R: Default,
+        {
+            type Leading = unsafe extern "C" fn(*mut ffi::c_void, $($arg),*) -> R;
+            type Trailing = unsafe extern "C" fn($($arg,)* *mut ffi::c_void) -> R;
+            type Bare = unsafe extern "C" fn($($arg),*) -> R;
+
+            fn leading() -> Self::Leading {
+                #[allow(non_snake_case)]
+                unsafe extern "C" fn leading<F, R, $($arg),*>(
+                    user_data: *mut ffi::c_void,
+                    $($arg: $arg),*
+                ) -> R
+                where
+                    F: FnMut($($arg),*) -> R,
+                    R: Default,
+                {
+                    invoke::<F, R>(user_data, move |f| f($($arg),*))
+                }
+
+                leading::<F, R, $($arg),*>
+            }
+
+            fn trailing() -> Self::Trailing {
+                #[allow(non_snake_case)]
+                unsafe extern "C" fn trailing<F, R, $($arg),*>(
+                    $($arg: $arg,)*
+                    user_data: *mut ffi::c_void
+                ) -> R
+                where
+                    F: FnMut($($arg),*) -> R,
+                    R: Default,
+                {
+                    invoke::<F, R>(user_data, move |f| f($($arg),*))
+                }
+
+                trailing::<F, R, $($arg),*>
+            }
+
+            fn current() -> *const ffi::c_void {
+                #[allow(non_snake_case)]
+                unsafe extern "C" fn current<F, R, $($arg),*>($($arg: $arg),*) -> R
+                where
+                    F: FnMut($($arg),*) -> R,
+                    R: Default,
+                {
+                    invoke::<F, R>(CURRENT.get(), move |f| f($($arg),*))
+                }
+
+                current::<F, R, $($arg),*> as *const ffi::c_void
+            }
+
+            unsafe fn bare(code: *const ffi::c_void) -> Self::Bare {
+                std::mem::transmute::<*const ffi::c_void, Self::Bare>(code)
+            }
+        }
+    };
+}
+
+impl_callback_fn!();
+impl_callback_fn!(A1);
+impl_callback_fn!(A1, A2);
+impl_callback_fn!(A1, A2, A3);
+impl_callback_fn!(A1, A2, A3, A4);
+impl_callback_fn!(A1, A2, A3, A4, A5);
+impl_callback_fn!(A1, A2, A3, A4, A5, A6);
+impl_callback_fn!(A1, A2, A3, A4, A5, A6, A7);
+impl_callback_fn!(A1, A2, A3, A4, A5, A6, A7, A8);
+impl_callback_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_callback_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+impl_callback_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+impl_callback_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
+
+/// Represents a closure passed to C as a trampoline function and its user data,
+/// which are valid until the callback is dropped.
+///
+/// # Usage
+///
+/// `Callback` is used for C functions that take a callback together with a `void *`
+/// they pass back to it.
+///
+/// ```no_run
+/// use std::ffi;
+///
+/// use dynlink::{api::Handle, callback::Callback};
+///
+/// // callback.c
+/// //
+/// // int apply_leading(int (*cb)(void *, int), void *data, int value) {
+/// //     return cb(data, value);
+/// // }
+///
+/// type ApplyFn = extern "C" fn(
+///     unsafe extern "C" fn(*mut ffi::c_void, i32) -> i32,
+///     *mut ffi::c_void,
+///     i32,
+/// ) -> i32;
+///
+/// fn main() {
+///     unsafe {
+///         let handle = Handle::open("libcallback.so")
+///             .expect("libcallback handle was not opened");
+///
+///         let apply = handle.lookup::<ApplyFn>("apply_leading")
+///             .expect("apply_leading symbol was not found");
+///
+///         let mut calls = 0;
+///         let callback = Callback::new(|value: i32| {
+///             calls += 1;
+///             value * 2
+///         });
+///
+///         let doubled = apply.call((callback.trampoline(), callback.user_data(), 21));
+///         assert_eq!(42, doubled);
+///
+///         drop(callback);
+///         assert_eq!(1, calls);
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// A panicking closure does not unwind into C. The panic is kept by the callback
+/// and returned by `Callback::take_panic`, while C receives the default of the result.
+///
+/// The closure is called through `&mut`, so C must not call it concurrently or reentrantly.
+pub struct Callback<F> {
+    state: Box<State<F>>,
+}
+
+impl<F> Callback<F> {
+    /// Creates a callback calling `closure`.
+    pub fn new(closure: F) -> Self {
+        Self {
+            state: Box::new(State {
+                closure: cell::UnsafeCell::new(closure),
+                panic: sync::Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Returns the user data that is passed to the trampolines.
+    #[inline]
+    pub fn user_data(&self) -> *mut ffi::c_void {
+        &*self.state as *const State<F> as *mut ffi::c_void
+    }
+
+    /// Returns the trampoline that takes the user data as its first argument.
+    #[inline]
+    pub fn trampoline<Args>(&self) -> F::Leading
+    where
+        F: CallbackFn<Args>,
+    {
+        F::leading()
+    }
+
+    /// Returns the trampoline that takes the user data as its last argument.
+    #[inline]
+    pub fn trailing_trampoline<Args>(&self) -> F::Trailing
+    where
+        F: CallbackFn<Args>,
+    {
+        F::trailing()
+    }
+
+    /// Takes the payload of the first panic of the closure, if it panicked.
+    ///
+    /// The payload is usually passed to `std::panic::resume_unwind` once C has returned.
+    pub fn take_panic(&self) -> Option<Box<dyn any::Any + Send>> {
+        self.state
+            .panic
+            .lock()
+            .unwrap_or_else(sync::PoisonError::into_inner)
+            .take()
+    }
+
+    /// Consumes the callback and returns the closure.
+    pub fn into_inner(self) -> F {
+        self.state.closure.into_inner()
+    }
+}
+
+impl<F> fmt::Debug for Callback<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("Callback({:p})", self.user_data()))
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use std::ffi;
+
+    use crate::callback::Callback;
+
+    pub extern "C" fn apply_leading(
+        cb: unsafe extern "C" fn(*mut ffi::c_void, i32, i32) -> i32,
+        user_data: *mut ffi::c_void,
+    ) -> i32 {
+        unsafe { cb(user_data, 20, 1) }
+    }
+
+    pub extern "C" fn apply_trailing(
+        cb: unsafe extern "C" fn(f64, *mut ffi::c_void),
+        user_data: *mut ffi::c_void,
+    ) {
+        unsafe {
+            cb(0.5, user_data);
+            cb(1.5, user_data);
+        }
+    }
+
+    #[test]
+    pub fn callback_calls_closure_through_leading_trampoline_test() {
+        let offset = 21;
+        let callback = Callback::new(|a: i32, b: i32| a + b + offset);
+
+        assert_eq!(
+            42,
+            apply_leading(callback.trampoline(), callback.user_data())
+        );
+    }
+
+    #[test]
+    pub fn callback_calls_closure_through_trailing_trampoline_test() {
+        let mut seen = Vec::new();
+        let callback = Callback::new(|value: f64| seen.push(value));
+
+        apply_trailing(callback.trailing_trampoline(), callback.user_data());
+
+        let _ = callback.into_inner();
+        assert_eq!(vec![0.5, 1.5], seen);
+    }
+
+    #[test]
+    pub fn callback_keeps_panic_and_returns_default_test() {
+        let callback = Callback::new(|a: i32, _: i32| -> i32 {
+            if a > 0 {
+                panic!("callback panicked");
+            }
+
+            a
+        });
+
+        assert_eq!(
+            0,
+            apply_leading(callback.trampoline(), callback.user_data())
+        );
+
+        let payload = callback.take_panic().expect("panic was not kept");
+        assert_eq!(Some(&"callback panicked"), payload.downcast_ref::<&str>());
+        assert!(callback.take_panic().is_none());
+    }
+}
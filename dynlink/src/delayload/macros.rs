@@ -0,0 +1,158 @@
+/// Declares a struct of functions where missing symbols are resolved to generated stubs.
+///
+/// Each declared function becomes a field of the declared function pointer type. When the
+/// symbol is present in the shared object file the field points to it, otherwise it points
+/// to a stub of the same signature that behaves according to the declared policy:
+///
+/// - `panic` panics with the shared object file and symbol names, which aborts the process
+///   for non-unwinding ABIs (see Notes);
+/// - `default(expr)` returns the value of `expr`;
+/// - `callback(path)` calls the function at `path` with a `MissingSymbol` and returns its result.
+///
+/// A function declared with `as ["name", ..]` is looked up by the first of these aliases
+/// that is present instead of its name, and `Capabilities::resolved_as` returns that alias.
+///
+/// The struct gets an unsafe `load` constructor and a `capabilities` method returning
+/// a `Capabilities` report where stubbed symbols are reported as missing.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink::{api::Handle, delayload::MissingSymbol};
+///
+/// // sum.c
+/// //
+/// // int sum_of(int a, int b) {
+/// //    return a + b;
+/// // }
+///
+/// fn on_missing(symbol: &MissingSymbol) -> i32 {
+///     eprintln!("{}", symbol);
+///     -1
+/// }
+///
+/// dynlink::delay_load! {
+///     /// libsum functions with stubs for missing symbols.
+///     pub struct LibSumFns: "libsum" {
+///         pub extern "C" fn sum_of(a: i32, b: i32) -> i32 => panic;
+///         pub extern "C" fn sum_of_three(a: i32, b: i32, c: i32) -> i32 => default(0);
+///         pub extern "C" fn product_of(a: i32, b: i32) -> i32 => callback(on_missing);
+///         pub extern "C" fn sum(a: i32, b: i32) -> i32 as ["sum_of_v2", "sum_of"] => default(0);
+///     }
+/// }
+///
+/// fn main() {
+///     unsafe {
+///         let handle = Handle::open("libsum.so")
+///             .expect("libsum handle was not opened");
+///
+///         let fns = LibSumFns::load(&handle);
+///         println!("{}", (fns.sum_of)(1, 1));
+///         println!("{}", (fns.product_of)(1, 1));
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// A panic can't unwind out of a stub with a non-unwinding ABI (e.g. `extern "C"`),
+/// so a panicking stub aborts the process after the panic message is printed.
+/// Functions declared with an unwinding ABI (e.g. `extern "C-unwind"` or `extern "Rust"`)
+/// get stubs that unwind to the caller, where the panic can be caught.
+///
+/// # Safety
+///
+/// The declared struct must not outlive the handle it is loaded from and the declared function
+/// signatures must be ABI compatible with the symbols from the shared object.
+#[macro_export]
+macro_rules! delay_load {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident: $library:literal {
+            $(
+                $(#[$fn_attr:meta])*
+                $fn_vis:vis extern $abi:literal fn $fn_name:ident($($arg:ident: $arg_ty:ty),* $(,)?) $(-> $ret:ty)?
+                    $(as [$($alias:literal),+ $(,)?])? => $policy:ident $(($($policy_arg:tt)*))?;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$fn_attr])*
+                $fn_vis $fn_name: extern $abi fn($($arg_ty),*) $(-> $ret)?,
+            )*
+            __dynlink_capabilities: $crate::optional::Capabilities,
+        }
+
+        impl $name {
+            /// Looks up all declared symbols from the shared object file's symbol table
+            /// and resolves missing ones to stubs.
+            ///
+            /// # Safety
+            ///
+            /// Returning value must not outlive the handle it is loaded from.
+            /// Declared function signatures must be ABI compatible with the symbols from the shared object.
+            #[allow(dead_code)]
+            $vis unsafe fn load(handle: &$crate::api::Handle) -> Self {
+                // declared functions are bound by their names, which may shadow `handle`
+                let __dynlink_handle = handle;
+
+                $(
+                    let $fn_name: ::core::option::Option<(&'static str, extern $abi fn($($arg_ty),*) $(-> $ret)?)> =
+                        $crate::delay_load!(@lookup __dynlink_handle, $fn_name $([$($alias),+])?);
+                )*
+
+                let __dynlink_capabilities = <$crate::optional::Capabilities as ::core::iter::FromIterator<_>>::from_iter([
+                    $((::core::stringify!($fn_name), $fn_name.map(|(symbol, _)| symbol))),*
+                ]);
+
+                Self {
+                    $(
+                        $fn_name: match $fn_name {
+                            ::core::option::Option::Some((_, f)) => f,
+                            ::core::option::Option::None => {
+                                #[allow(unused_variables, clippy::too_many_arguments)]
+                                extern $abi fn stub($($arg: $arg_ty),*) $(-> $ret)? {
+                                    const MISSING: $crate::delayload::MissingSymbol =
+                                        $crate::delayload::MissingSymbol::new($library, ::core::stringify!($fn_name));
+
+                                    $crate::delay_load!(@stub MISSING, $policy $(($($policy_arg)*))?)
+                                }
+
+                                stub
+                            }
+                        },
+                    )*
+                    __dynlink_capabilities,
+                }
+            }
+
+            /// Returns a report of present and stubbed symbols.
+            #[allow(dead_code)]
+            $vis fn capabilities(&self) -> $crate::optional::Capabilities {
+                self.__dynlink_capabilities.clone()
+            }
+        }
+    };
+
+    (@lookup $handle:ident, $fn_name:ident) => {
+        $crate::optional::lookup_first($handle, &[::core::stringify!($fn_name)])
+    };
+
+    (@lookup $handle:ident, $fn_name:ident [$($alias:literal),+]) => {
+        $crate::optional::lookup_first($handle, &[$($alias),+])
+    };
+
+    (@stub $missing:ident, panic) => {
+        $missing.panic()
+    };
+
+    (@stub $missing:ident, default($value:expr)) => {
+        $value
+    };
+
+    (@stub $missing:ident, callback($callback:path)) => {
+        $callback(&$missing)
+    };
+}
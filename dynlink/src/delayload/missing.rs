@@ -0,0 +1,67 @@
+use std::fmt;
+
+/// Represents a symbol that is missing in a shared object file and resolved to a stub.
+///
+/// `MissingSymbol` is passed to callbacks of stubs declared with the `delay_load!` macro
+/// and is used as the panic message of panicking stubs.
+pub struct MissingSymbol {
+    library: &'static str,
+    symbol: &'static str,
+}
+
+impl MissingSymbol {
+    /// Creates a missing symbol specified by `symbol` of the shared object file specified by `library`.
+    pub const fn new(library: &'static str, symbol: &'static str) -> Self {
+        Self { library, symbol }
+    }
+
+    /// Returns the name of the shared object file the symbol is missing in.
+    #[inline]
+    pub fn library(&self) -> &'static str {
+        self.library
+    }
+
+    /// Returns the name of the missing symbol.
+    #[inline]
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Panics with the names of the shared object file and the missing symbol.
+    ///
+    /// # Notes
+    ///
+    /// A panic can't unwind out of a stub with a non-unwinding ABI (e.g. `extern "C"`),
+    /// so the process is aborted after the panic message is printed.
+    #[cold]
+    #[track_caller]
+    pub fn panic(&self) -> ! {
+        panic!("{}", self)
+    }
+}
+
+impl Clone for MissingSymbol {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for MissingSymbol {}
+
+impl fmt::Debug for MissingSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "MissingSymbol({:?}, {:?})",
+            self.library, self.symbol
+        ))
+    }
+}
+
+impl fmt::Display for MissingSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Symbol {} is missing in {}",
+            self.symbol, self.library
+        ))
+    }
+}
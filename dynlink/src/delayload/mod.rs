@@ -0,0 +1,4 @@
+mod macros;
+mod missing;
+
+pub use missing::MissingSymbol;
@@ -0,0 +1,195 @@
+use std::{error, fmt};
+
+use pointersized::PointerSized;
+
+use crate::api::{Handle, LinkingError, Symbol};
+
+/// Represents an error that occurred during lookup by a demangled name.
+///
+/// `DemangleError::Linking(err)` contains a diagnostic message of unavailable exports
+/// or of a failed lookup of the matching symbol.
+/// `DemangleError::NotFound(name)` contains the demangled name no export matches.
+/// `DemangleError::Ambiguous(name, candidates)` contains the demangled name and
+/// the mangled names of all exports that match it.
+pub enum DemangleError {
+    Linking(LinkingError),
+    NotFound(String),
+    Ambiguous(String, Vec<String>),
+}
+
+impl Clone for DemangleError {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Linking(err) => Self::Linking(err.clone()),
+            Self::NotFound(name) => Self::NotFound(name.clone()),
+            Self::Ambiguous(name, candidates) => Self::Ambiguous(name.clone(), candidates.clone()),
+        }
+    }
+}
+
+impl fmt::Debug for DemangleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linking(err) => f.write_fmt(format_args!("Linking({:?})", err)),
+            Self::NotFound(name) => f.write_fmt(format_args!("NotFound({:?})", name)),
+            Self::Ambiguous(name, candidates) => {
+                f.write_fmt(format_args!("Ambiguous({:?}, {:?})", name, candidates))
+            }
+        }
+    }
+}
+
+impl fmt::Display for DemangleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linking(err) => fmt::Display::fmt(err, f),
+            Self::NotFound(name) => f.write_fmt(format_args!(
+                "Error occurred demangled lookup: no export matches {}",
+                name
+            )),
+            Self::Ambiguous(name, candidates) => {
+                f.write_fmt(format_args!(
+                    "Error occurred demangled lookup: {} is ambiguous, candidates are ",
+                    name
+                ))?;
+
+                for (i, candidate) in candidates.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    f.write_str(candidate)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl error::Error for DemangleError {}
+
+impl Handle {
+    /// Looks up a symbol from the shared object file's exports by its demangled Rust path.
+    ///
+    /// # Usage
+    ///
+    /// `lookup_demangled` is used for Rust shared objects whose functions are not `#[no_mangle]`.
+    ///
+    /// ```no_run
+    /// use dynlink::{api::Handle, demangle::DemangleError};
+    ///
+    /// fn main() {
+    ///     unsafe {
+    ///         let handle = Handle::open("libmycrate.so")
+    ///             .expect("libmycrate handle was not opened");
+    ///
+    ///         match handle.lookup_demangled::<fn(i32) -> i32>("mycrate::module::func") {
+    ///             Ok(func) => println!("{}", func.apply(|it| it(1))),
+    ///             Err(DemangleError::Ambiguous(_, candidates)) => println!("{:?}", candidates),
+    ///             Err(err) => eprintln!("{}", err),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Type `T` must be ABI compatible with the type of symbol from the shared object.
+    ///
+    /// # Notes
+    ///
+    /// Both v0 and legacy mangled names are demangled, and `path` is compared without
+    /// the hash and crate disambiguators, so it is written as in source, e.g. `mycrate::func::<i32>`.
+    pub unsafe fn lookup_demangled<T: PointerSized>(
+        &self,
+        path: &str,
+    ) -> Result<Symbol<'_, T>, DemangleError> {
+        self.lookup_unique(path, |name| match rustc_demangle::try_demangle(name) {
+            Ok(demangled) => format!("{:#}", demangled) == path,
+            Err(_) => false,
+        })
+    }
+
+    /// Looks up a symbol from the shared object file's exports by its demangled C++ name.
+    ///
+    /// # Usage
+    ///
+    /// `lookup_cpp` is used for C++ shared objects whose functions are not `extern "C"`.
+    ///
+    /// ```no_run
+    /// use dynlink::api::Handle;
+    ///
+    /// // shape.cpp
+    /// //
+    /// // namespace geometry {
+    /// //    int Shape::area(int side) { return side * side; }
+    /// // }
+    ///
+    /// fn main() {
+    ///     unsafe {
+    ///         let handle = Handle::open("libshape.so")
+    ///             .expect("libshape handle was not opened");
+    ///
+    ///         let area_fn = handle.lookup_cpp::<extern "C" fn(i32) -> i32>("geometry::Shape::area(int)")
+    ///             .expect("geometry::Shape::area symbol was not found");
+    ///
+    ///         assert_eq!(4, area_fn.apply(|it| it(2)));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Type `T` must be ABI compatible with the type of symbol from the shared object,
+    /// where member functions take the object pointer as their first argument.
+    ///
+    /// # Notes
+    ///
+    /// Itanium mangled names are demangled, and `signature` is compared ignoring whitespace.
+    /// Parameters are written as the demangler prints them, e.g. `char const*`, and
+    /// a `signature` without parameters matches every overload.
+    pub unsafe fn lookup_cpp<T: PointerSized>(
+        &self,
+        signature: &str,
+    ) -> Result<Symbol<'_, T>, DemangleError> {
+        let expected = without_whitespace(signature);
+
+        let options = if expected.contains('(') {
+            cpp_demangle::DemangleOptions::new()
+        } else {
+            cpp_demangle::DemangleOptions::new().no_params()
+        };
+
+        self.lookup_unique(signature, |name| {
+            match cpp_demangle::Symbol::new(name.as_bytes()) {
+                Ok(symbol) => symbol
+                    .demangle_with_options(&options)
+                    .is_ok_and(|demangled| without_whitespace(&demangled) == expected),
+                Err(_) => false,
+            }
+        })
+    }
+
+    /// Looks up the only export whose mangled name `matches` the demangled `name`.
+    unsafe fn lookup_unique<T: PointerSized>(
+        &self,
+        name: &str,
+        matches: impl Fn(&str) -> bool,
+    ) -> Result<Symbol<'_, T>, DemangleError> {
+        let mut candidates = self.export_names().map_err(DemangleError::Linking)?;
+        candidates.retain(|it| matches(it));
+
+        match candidates.len() {
+            0 => Err(DemangleError::NotFound(name.to_owned())),
+            1 => self
+                .lookup(&candidates.remove(0))
+                .map_err(DemangleError::Linking),
+            _ => Err(DemangleError::Ambiguous(name.to_owned(), candidates)),
+        }
+    }
+}
+
+fn without_whitespace(name: &str) -> String {
+    name.chars().filter(|it| !it.is_whitespace()).collect()
+}
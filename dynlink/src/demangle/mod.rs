@@ -0,0 +1,3 @@
+mod lookup;
+
+pub use lookup::DemangleError;
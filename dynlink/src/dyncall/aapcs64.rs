@@ -0,0 +1,172 @@
+use std::{arch::asm, ffi};
+
+use crate::dyncall::{layout, Signature, Type, Value};
+
+const GENERAL_REGISTERS: usize = 8;
+const VECTOR_REGISTERS: usize = 8;
+
+/// Returns the member type and count of a homogeneous floating-point aggregate.
+fn homogeneous(ty: &Type) -> Option<(Type, usize)> {
+    if !matches!(ty, Type::Struct(_)) {
+        return None;
+    }
+
+    let scalars = layout::scalars(ty);
+    let (_, member) = scalars.first()?;
+
+    if member.is_float() && scalars.len() <= 4 && scalars.iter().all(|(_, it)| it == member) {
+        Some((member.clone(), scalars.len()))
+    } else {
+        None
+    }
+}
+
+/// Returns the register words of the members of a homogeneous floating-point aggregate.
+fn members(bytes: &[u8], member: &Type, count: usize) -> impl Iterator<Item = u64> + '_ {
+    (0..count).map(move |i| layout::extend(&layout::decode(member, &bytes[i * member.size()..])))
+}
+
+/// Calls `function` with `args` following the AArch64 procedure call standard.
+///
+/// # Safety
+///
+/// `function` must have the C signature described by `signature`, which `args` match.
+pub(super) unsafe fn call(
+    function: *const ffi::c_void,
+    signature: &Signature,
+    args: &[Value],
+) -> Value {
+    let mut ints = Vec::with_capacity(GENERAL_REGISTERS);
+    let mut floats = Vec::with_capacity(VECTOR_REGISTERS);
+    let mut stack = Vec::new();
+
+    // composites larger than 16 bytes are passed as pointers to their copies
+    let mut copies = Vec::new();
+
+    for arg in args {
+        let ty = arg.ty();
+
+        if ty.is_float() {
+            if floats.len() < VECTOR_REGISTERS {
+                floats.push(layout::extend(arg));
+            } else {
+                stack.push(layout::extend(arg));
+            }
+        } else if let Some((member, count)) = homogeneous(&ty) {
+            let bytes = layout::encode(arg);
+
+            if floats.len() + count <= VECTOR_REGISTERS {
+                floats.extend(members(&bytes, &member, count));
+            } else {
+                floats.resize(VECTOR_REGISTERS, 0);
+                stack.extend(layout::to_words(&bytes));
+            }
+        } else if let Value::Struct(_) = arg {
+            let mut words = layout::to_words(&layout::encode(arg));
+
+            if ty.size() > 16 {
+                copies.push(words);
+                words = vec![copies[copies.len() - 1].as_ptr() as u64];
+            }
+
+            if ints.len() + words.len() <= GENERAL_REGISTERS {
+                ints.extend(words);
+            } else {
+                ints.resize(GENERAL_REGISTERS, 0);
+                stack.extend(words);
+            }
+        } else if ints.len() < GENERAL_REGISTERS {
+            ints.push(layout::extend(arg));
+        } else {
+            stack.push(layout::extend(arg));
+        }
+    }
+
+    // results larger than 16 bytes are written where x8 points
+    let ret = signature.ret();
+    let mut memory = vec![0_u64; ret.size().div_ceil(8)];
+
+    ints.resize(GENERAL_REGISTERS, 0);
+    floats.resize(VECTOR_REGISTERS, 0);
+
+    let (rets, float_rets) = invoke(function, &ints, &floats, memory.as_mut_ptr(), &stack);
+
+    if ret.is_float() {
+        layout::decode(ret, &layout::from_words(&float_rets[..1]))
+    } else if let Some((member, count)) = homogeneous(ret) {
+        let bytes = float_rets[..count]
+            .iter()
+            .flat_map(|it| it.to_ne_bytes().into_iter().take(member.size()))
+            .collect::<Vec<_>>();
+
+        layout::decode(ret, &bytes)
+    } else if ret.size() > 16 {
+        layout::decode(ret, &layout::from_words(&memory))
+    } else {
+        layout::decode(ret, &layout::from_words(&rets))
+    }
+}
+
+/// Loads the argument registers and the stack, calls `function` and returns
+/// `x0`, `x1` and the low words of `v0` to `v3`.
+unsafe fn invoke(
+    function: *const ffi::c_void,
+    ints: &[u64],
+    floats: &[u64],
+    indirect: *mut u64,
+    stack: &[u64],
+) -> ([u64; 2], [u64; 4]) {
+    let x0: u64;
+    let x1: u64;
+    let d0: f64;
+    let d1: f64;
+    let d2: f64;
+    let d3: f64;
+
+    // x20 to x22 are preserved by the callee, so the stack pointer is restored from x20
+    asm!(
+        "mov x20, sp",
+        "add x9, x22, #1",
+        "and x9, x9, #0xfffffffffffffffe",
+        "sub x9, x20, x9, lsl #3",
+        "mov sp, x9",
+        "mov x10, #0",
+        "2:",
+        "cmp x10, x22",
+        "b.eq 3f",
+        "ldr x11, [x21, x10, lsl #3]",
+        "str x11, [sp, x10, lsl #3]",
+        "add x10, x10, #1",
+        "b 2b",
+        "3:",
+        "blr x16",
+        "mov sp, x20",
+        inout("x0") ints[0] => x0,
+        inout("x1") ints[1] => x1,
+        in("x2") ints[2],
+        in("x3") ints[3],
+        in("x4") ints[4],
+        in("x5") ints[5],
+        in("x6") ints[6],
+        in("x7") ints[7],
+        in("x8") indirect,
+        in("x16") function,
+        out("x20") _,
+        in("x21") stack.as_ptr(),
+        in("x22") stack.len(),
+        inout("d0") f64::from_bits(floats[0]) => d0,
+        inout("d1") f64::from_bits(floats[1]) => d1,
+        inout("d2") f64::from_bits(floats[2]) => d2,
+        inout("d3") f64::from_bits(floats[3]) => d3,
+        in("d4") f64::from_bits(floats[4]),
+        in("d5") f64::from_bits(floats[5]),
+        in("d6") f64::from_bits(floats[6]),
+        in("d7") f64::from_bits(floats[7]),
+        clobber_abi("C"),
+    );
+
+    (
+        [x0, x1],
+        [d0.to_bits(), d1.to_bits(), d2.to_bits(), d3.to_bits()],
+    )
+}
@@ -0,0 +1,259 @@
+use std::{error, ffi, fmt};
+
+use crate::{
+    api::RawSymbol,
+    dyncall::{Signature, Type, Value},
+};
+
+/// Represents an error that occurred before a dynamic call was made.
+///
+/// `DyncallError::ArgumentCount(expected, given)` contains the number of arguments
+/// of the signature and the number of given values.
+/// `DyncallError::ArgumentType(index, expected, given)` contains the index of an argument,
+/// its type in the signature and the type of the given value.
+/// `DyncallError::Unsupported(ty)` contains a type that can not be passed or returned,
+/// which are `Type::Void` arguments and structs without fields.
+pub enum DyncallError {
+    ArgumentCount(usize, usize),
+    ArgumentType(usize, Type, Type),
+    Unsupported(Type),
+}
+
+impl Clone for DyncallError {
+    fn clone(&self) -> Self {
+        match self {
+            Self::ArgumentCount(expected, given) => Self::ArgumentCount(*expected, *given),
+            Self::ArgumentType(index, expected, given) => {
+                Self::ArgumentType(*index, expected.clone(), given.clone())
+            }
+            Self::Unsupported(ty) => Self::Unsupported(ty.clone()),
+        }
+    }
+}
+
+impl fmt::Debug for DyncallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ArgumentCount(expected, given) => {
+                f.write_fmt(format_args!("ArgumentCount({}, {})", expected, given))
+            }
+            Self::ArgumentType(index, expected, given) => f.write_fmt(format_args!(
+                "ArgumentType({}, {:?}, {:?})",
+                index, expected, given
+            )),
+            Self::Unsupported(ty) => f.write_fmt(format_args!("Unsupported({:?})", ty)),
+        }
+    }
+}
+
+impl fmt::Display for DyncallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ArgumentCount(expected, given) => f.write_fmt(format_args!(
+                "Error occurred dynamic call: expected {} arguments, given {}",
+                expected, given
+            )),
+            Self::ArgumentType(index, expected, given) => f.write_fmt(format_args!(
+                "Error occurred dynamic call: argument {} is {}, given {}",
+                index, expected, given
+            )),
+            Self::Unsupported(ty) => f.write_fmt(format_args!(
+                "Error occurred dynamic call: {} is not supported",
+                ty
+            )),
+        }
+    }
+}
+
+impl error::Error for DyncallError {}
+
+/// Returns `true` if `ty` can be an argument, where `Type::Void` is allowed only as a result.
+fn supported(ty: &Type) -> bool {
+    match ty {
+        Type::Void => false,
+        Type::Struct(fields) => !fields.is_empty() && fields.iter().all(supported),
+        _ => true,
+    }
+}
+
+/// Calls the function `function` points to with `args` following the platform C calling
+/// convention for `signature`.
+///
+/// # Usage
+///
+/// `call` is used for function pointers that are not looked up, e.g. the ones returned
+/// by other functions or read from tables.
+///
+/// ```no_run
+/// use std::ffi;
+///
+/// use dynlink::dyncall::{self, Signature, Type, Value};
+///
+/// extern "C" fn sum_of(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// fn main() {
+///     unsafe {
+///         let signature = Signature::new(vec![Type::I32, Type::I32], Type::I32);
+///         let function = sum_of as *const ffi::c_void;
+///
+///         let sum = dyncall::call(function, &signature, &[Value::I32(1), Value::I32(1)])
+///             .expect("sum_of was not called");
+///
+///         assert_eq!(Value::I32(2), sum);
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// `function` must point to a function whose C signature is described by `signature`.
+/// Pointers in `args` must be valid for the function being called.
+///
+/// # Notes
+///
+/// Arguments of C variadic functions are passed like fixed ones, so variable arguments
+/// must be given already promoted (e.g. `Type::F64` instead of `Type::F32`).
+pub unsafe fn call(
+    function: *const ffi::c_void,
+    signature: &Signature,
+    args: &[Value],
+) -> Result<Value, DyncallError> {
+    if signature.args().len() != args.len() {
+        return Err(DyncallError::ArgumentCount(
+            signature.args().len(),
+            args.len(),
+        ));
+    }
+
+    for (index, (expected, arg)) in signature.args().iter().zip(args).enumerate() {
+        if !supported(expected) {
+            return Err(DyncallError::Unsupported(expected.clone()));
+        }
+
+        let given = arg.ty();
+
+        if *expected != given {
+            return Err(DyncallError::ArgumentType(index, expected.clone(), given));
+        }
+    }
+
+    if *signature.ret() != Type::Void && !supported(signature.ret()) {
+        return Err(DyncallError::Unsupported(signature.ret().clone()));
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    return Ok(crate::dyncall::sysv64::call(function, signature, args));
+
+    #[cfg(target_arch = "aarch64")]
+    return Ok(crate::dyncall::aapcs64::call(function, signature, args));
+}
+
+impl RawSymbol<'_> {
+    /// Calls the function it represents with `args` following the platform C calling
+    /// convention for `signature`.
+    ///
+    /// # Usage
+    ///
+    /// `call_dynamic` is used for functions whose signatures are known only at runtime,
+    /// e.g. the ones described by scripts or command line arguments.
+    ///
+    /// ```no_run
+    /// use dynlink::{
+    ///     api::{Handle, RawSymbol},
+    ///     dyncall::{Signature, Type, Value},
+    /// };
+    ///
+    /// // sum.c
+    /// //
+    /// // int sum_of(int a, int b) {
+    /// //    return a + b;
+    /// // }
+    ///
+    /// fn main() {
+    ///     unsafe {
+    ///         let handle = Handle::open("libsum.so")
+    ///             .expect("libsum handle was not opened");
+    ///
+    ///         let symbol: RawSymbol<'_> = handle.lookup("sum_of")
+    ///             .expect("sum_of symbol was not found");
+    ///
+    ///         let signature = Signature::new(vec![Type::I32, Type::I32], Type::I32);
+    ///         let sum = symbol.call_dynamic(&signature, &[Value::I32(1), Value::I32(1)])
+    ///             .expect("sum_of was not called");
+    ///
+    ///         assert_eq!(Value::I32(2), sum);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The symbol must be a function whose C signature is described by `signature`.
+    /// Pointers in `args` must be valid for the function being called.
+    pub unsafe fn call_dynamic(
+        &self,
+        signature: &Signature,
+        args: &[Value],
+    ) -> Result<Value, DyncallError> {
+        call(self.apply(|it| it), signature, args)
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::dyncall::{self, DyncallError, Signature, Type, Value};
+
+    pub extern "C" fn scale(v: i64, k: f64) -> f64 {
+        v as f64 * k
+    }
+
+    #[test]
+    pub fn call_invokes_function_pointer_test() {
+        unsafe {
+            let signature = Signature::new(vec![Type::I64, Type::F64], Type::F64);
+
+            let scaled = dyncall::call(
+                scale as *const _,
+                &signature,
+                &[Value::I64(3), Value::F64(0.5)],
+            );
+
+            assert_eq!(Value::F64(1.5), scaled.unwrap());
+        }
+    }
+
+    #[test]
+    pub fn call_fails_when_arguments_do_not_match_signature_test() {
+        unsafe {
+            let signature = Signature::new(vec![Type::I64, Type::F64], Type::F64);
+
+            match dyncall::call(scale as *const _, &signature, &[Value::I64(3)]) {
+                Err(DyncallError::ArgumentCount(2, 1)) => {}
+                other => panic!("unexpected result {:?}", other),
+            }
+
+            match dyncall::call(
+                scale as *const _,
+                &signature,
+                &[Value::I64(3), Value::F32(0.5)],
+            ) {
+                Err(DyncallError::ArgumentType(1, Type::F64, Type::F32)) => {}
+                other => panic!("unexpected result {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    pub fn call_fails_when_type_is_not_supported_test() {
+        unsafe {
+            let signature = Signature::new(vec![Type::Struct(vec![])], Type::Void);
+
+            match dyncall::call(scale as *const _, &signature, &[Value::Struct(vec![])]) {
+                Err(DyncallError::Unsupported(Type::Struct(_))) => {}
+                other => panic!("unexpected result {:?}", other),
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::dyncall::{types::align_to, Type, Value};
+
+/// Returns the offsets and types of the scalars of `ty`, flattening nested structs.
+pub(super) fn scalars(ty: &Type) -> Vec<(usize, Type)> {
+    let mut scalars = Vec::new();
+    flatten(ty, 0, &mut scalars);
+    scalars
+}
+
+fn flatten(ty: &Type, base: usize, scalars: &mut Vec<(usize, Type)>) {
+    match ty {
+        Type::Struct(fields) => {
+            let mut offset = 0;
+
+            for field in fields {
+                offset = align_to(offset, field.align());
+                flatten(field, base + offset, scalars);
+                offset += field.size();
+            }
+        }
+        Type::Void => {}
+        scalar => scalars.push((base, scalar.clone())),
+    }
+}
+
+/// Returns the bytes of `value` laid out in memory, with zeroed padding.
+pub(super) fn encode(value: &Value) -> Vec<u8> {
+    let mut bytes = vec![0; value.ty().size()];
+    write(value, &mut bytes);
+    bytes
+}
+
+fn write(value: &Value, bytes: &mut [u8]) {
+    match value {
+        Value::Void => {}
+        Value::I8(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::U8(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::I16(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::U16(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::I32(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::U32(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::I64(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::U64(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::F32(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::F64(it) => bytes.copy_from_slice(&it.to_ne_bytes()),
+        Value::Pointer(it) => bytes.copy_from_slice(&(*it as usize).to_ne_bytes()),
+        Value::Struct(fields) => {
+            let mut offset = 0;
+
+            for field in fields {
+                let ty = field.ty();
+                offset = align_to(offset, ty.align());
+                write(field, &mut bytes[offset..offset + ty.size()]);
+                offset += ty.size();
+            }
+        }
+    }
+}
+
+/// Returns the value of the type `ty` read from `bytes` laid out in memory.
+pub(super) fn decode(ty: &Type, bytes: &[u8]) -> Value {
+    fn read<const N: usize>(bytes: &[u8]) -> [u8; N] {
+        let mut array = [0; N];
+        array.copy_from_slice(&bytes[..N]);
+        array
+    }
+
+    match ty {
+        Type::Void => Value::Void,
+        Type::I8 => Value::I8(i8::from_ne_bytes(read(bytes))),
+        Type::U8 => Value::U8(u8::from_ne_bytes(read(bytes))),
+        Type::I16 => Value::I16(i16::from_ne_bytes(read(bytes))),
+        Type::U16 => Value::U16(u16::from_ne_bytes(read(bytes))),
+        Type::I32 => Value::I32(i32::from_ne_bytes(read(bytes))),
+        Type::U32 => Value::U32(u32::from_ne_bytes(read(bytes))),
+        Type::I64 => Value::I64(i64::from_ne_bytes(read(bytes))),
+        Type::U64 => Value::U64(u64::from_ne_bytes(read(bytes))),
+        Type::F32 => Value::F32(f32::from_ne_bytes(read(bytes))),
+        Type::F64 => Value::F64(f64::from_ne_bytes(read(bytes))),
+        Type::Pointer => Value::Pointer(usize::from_ne_bytes(read(bytes)) as *mut _),
+        Type::Struct(fields) => {
+            let mut offset = 0;
+            let mut values = Vec::with_capacity(fields.len());
+
+            for field in fields {
+                offset = align_to(offset, field.align());
+                values.push(decode(field, &bytes[offset..]));
+                offset += field.size();
+            }
+
+            Value::Struct(values)
+        }
+    }
+}
+
+/// Returns the register word of a scalar `value`, sign-extending signed integers.
+pub(super) fn extend(value: &Value) -> u64 {
+    match value {
+        Value::I8(it) => *it as i64 as u64,
+        Value::I16(it) => *it as i64 as u64,
+        Value::I32(it) => *it as i64 as u64,
+        Value::I64(it) => *it as u64,
+        Value::U8(it) => u64::from(*it),
+        Value::U16(it) => u64::from(*it),
+        Value::U32(it) => u64::from(*it),
+        Value::U64(it) => *it,
+        Value::F32(it) => u64::from(it.to_bits()),
+        Value::F64(it) => it.to_bits(),
+        Value::Pointer(it) => *it as usize as u64,
+        Value::Void | Value::Struct(_) => 0,
+    }
+}
+
+/// Splits `bytes` into the words they occupy, zero-padding the last one.
+pub(super) fn to_words(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks(8)
+        .map(|chunk| {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_ne_bytes(word)
+        })
+        .collect()
+}
+
+/// Joins `words` into the bytes they hold.
+pub(super) fn from_words(words: &[u64]) -> Vec<u8> {
+    words.iter().flat_map(|it| it.to_ne_bytes()).collect()
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::dyncall::{
+        layout::{decode, encode, from_words, scalars, to_words},
+        Type, Value,
+    };
+
+    #[test]
+    pub fn layout_flattens_nested_struct_scalars_test() {
+        let ty = Type::Struct(vec![
+            Type::U8,
+            Type::Struct(vec![Type::F32, Type::F32]),
+            Type::I64,
+        ]);
+
+        assert_eq!(
+            vec![
+                (0, Type::U8),
+                (4, Type::F32),
+                (8, Type::F32),
+                (16, Type::I64)
+            ],
+            scalars(&ty)
+        );
+    }
+
+    #[test]
+    pub fn layout_decodes_what_it_encodes_test() {
+        let value = Value::Struct(vec![
+            Value::I8(-1),
+            Value::F64(2.5),
+            Value::Struct(vec![Value::U16(3), Value::I32(-4)]),
+        ]);
+
+        let bytes = encode(&value);
+        assert_eq!(24, bytes.len());
+
+        let words = to_words(&bytes);
+        assert_eq!(3, words.len());
+        assert_eq!(value, decode(&value.ty(), &from_words(&words)));
+    }
+}
@@ -0,0 +1,14 @@
+#[cfg(target_arch = "aarch64")]
+mod aapcs64;
+mod call;
+mod layout;
+mod signature;
+#[cfg(target_arch = "x86_64")]
+mod sysv64;
+mod types;
+mod value;
+
+pub use call::{call, DyncallError};
+pub use signature::Signature;
+pub use types::Type;
+pub use value::Value;
@@ -0,0 +1,80 @@
+use std::fmt;
+
+use crate::dyncall::Type;
+
+/// Represents the C signature of a function that is known only at runtime.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink::dyncall::{Signature, Type};
+///
+/// fn main() {
+///     // int32_t sum_of(int32_t a, int32_t b);
+///     let signature = Signature::new(vec![Type::I32, Type::I32], Type::I32);
+///
+///     assert_eq!("int32_t (int32_t, int32_t)", signature.to_string());
+/// }
+/// ```
+pub struct Signature {
+    args: Vec<Type>,
+    ret: Type,
+}
+
+impl Signature {
+    /// Creates a signature of a function taking `args` and returning `ret`.
+    pub fn new(args: Vec<Type>, ret: Type) -> Self {
+        Self { args, ret }
+    }
+
+    /// Returns the types of the arguments.
+    #[inline]
+    pub fn args(&self) -> &[Type] {
+        &self.args
+    }
+
+    /// Returns the type of the return value.
+    #[inline]
+    pub fn ret(&self) -> &Type {
+        &self.ret
+    }
+}
+
+impl Clone for Signature {
+    fn clone(&self) -> Self {
+        Self {
+            args: self.args.clone(),
+            ret: self.ret.clone(),
+        }
+    }
+}
+
+impl PartialEq for Signature {
+    fn eq(&self, other: &Self) -> bool {
+        self.args == other.args && self.ret == other.ret
+    }
+}
+
+impl Eq for Signature {}
+
+impl fmt::Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("Signature({:?}, {:?})", self.args, self.ret))
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{} (", self.ret))?;
+
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            fmt::Display::fmt(arg, f)?;
+        }
+
+        f.write_str(")")
+    }
+}
@@ -0,0 +1,199 @@
+use std::{arch::asm, ffi};
+
+use crate::dyncall::{layout, Signature, Type, Value};
+
+const INTEGER_REGISTERS: usize = 6;
+const SSE_REGISTERS: usize = 8;
+
+/// Represents the register class of an eightbyte of an argument or a return value.
+enum Class {
+    Integer,
+    Sse,
+}
+
+/// Returns the classes of the eightbytes of `ty`, or `None` if it is passed in memory.
+fn classify(ty: &Type) -> Option<Vec<Class>> {
+    if ty.size() > 16 {
+        return None;
+    }
+
+    let mut classes = (0..ty.size().div_ceil(8))
+        .map(|_| Class::Sse)
+        .collect::<Vec<_>>();
+
+    for (offset, scalar) in layout::scalars(ty) {
+        if !scalar.is_float() {
+            classes[offset / 8] = Class::Integer;
+        }
+    }
+
+    Some(classes)
+}
+
+/// Calls `function` with `args` following the System V AMD64 calling convention.
+///
+/// # Safety
+///
+/// `function` must have the C signature described by `signature`, which `args` match.
+pub(super) unsafe fn call(
+    function: *const ffi::c_void,
+    signature: &Signature,
+    args: &[Value],
+) -> Value {
+    let mut ints = Vec::with_capacity(INTEGER_REGISTERS);
+    let mut sses = Vec::with_capacity(SSE_REGISTERS);
+    let mut stack = Vec::new();
+
+    // memory class results are written where the hidden first argument points
+    let ret = classify(signature.ret());
+    let mut memory = vec![0_u64; signature.ret().size().div_ceil(8)];
+
+    if ret.is_none() {
+        ints.push(memory.as_mut_ptr() as u64);
+    }
+
+    for arg in args {
+        let words = match arg {
+            Value::Struct(_) => layout::to_words(&layout::encode(arg)),
+            scalar => vec![layout::extend(scalar)],
+        };
+
+        // arguments are passed in memory as a whole when any of their eightbytes do not fit
+        let classes = classify(&arg.ty());
+        let fits = classes.as_ref().is_some_and(|classes| {
+            let integers = classes
+                .iter()
+                .filter(|it| matches!(it, Class::Integer))
+                .count();
+
+            ints.len() + integers <= INTEGER_REGISTERS
+                && sses.len() + classes.len() - integers <= SSE_REGISTERS
+        });
+
+        match classes {
+            Some(classes) if fits => {
+                for (class, word) in classes.iter().zip(words) {
+                    match class {
+                        Class::Integer => ints.push(word),
+                        Class::Sse => sses.push(word),
+                    }
+                }
+            }
+            _ => stack.extend(words),
+        }
+    }
+
+    let sse_count = sses.len();
+    ints.resize(INTEGER_REGISTERS, 0);
+    sses.resize(SSE_REGISTERS, 0);
+
+    let (rets, sse_rets) = invoke(function, &ints, &sses, sse_count, &stack);
+
+    let words = match ret {
+        Some(classes) => {
+            let mut rets = rets.into_iter();
+            let mut sse_rets = sse_rets.into_iter();
+
+            classes
+                .iter()
+                .map(|class| match class {
+                    Class::Integer => rets.next().unwrap_or(0),
+                    Class::Sse => sse_rets.next().unwrap_or(0),
+                })
+                .collect()
+        }
+        None => memory,
+    };
+
+    layout::decode(signature.ret(), &layout::from_words(&words))
+}
+
+/// Loads the argument registers and the stack, calls `function` and returns
+/// `rax`, `rdx` and the low words of `xmm0`, `xmm1`.
+unsafe fn invoke(
+    function: *const ffi::c_void,
+    ints: &[u64],
+    sses: &[u64],
+    sse_count: usize,
+    stack: &[u64],
+) -> ([u64; 2], [u64; 2]) {
+    let rax: u64;
+    let rdx: u64;
+    let xmm0: f64;
+    let xmm1: f64;
+
+    // r12 to r15 are preserved by the callee, so the stack pointer is restored from r14
+    asm!(
+        "mov r14, rsp",
+        "lea rax, [r13 * 8 + 15]",
+        "and rax, -16",
+        "sub rsp, rax",
+        "and rsp, -16",
+        "xor eax, eax",
+        "2:",
+        "cmp rax, r13",
+        "je 3f",
+        "mov r10, qword ptr [r12 + rax * 8]",
+        "mov qword ptr [rsp + rax * 8], r10",
+        "inc rax",
+        "jmp 2b",
+        "3:",
+        // the number of vector registers used by variadic arguments
+        "mov rax, r15",
+        "call r11",
+        "mov rsp, r14",
+        in("rdi") ints[0],
+        in("rsi") ints[1],
+        inout("rdx") ints[2] => rdx,
+        in("rcx") ints[3],
+        in("r8") ints[4],
+        in("r9") ints[5],
+        in("r11") function,
+        in("r12") stack.as_ptr(),
+        in("r13") stack.len(),
+        out("r14") _,
+        in("r15") sse_count,
+        inout("xmm0") f64::from_bits(sses[0]) => xmm0,
+        inout("xmm1") f64::from_bits(sses[1]) => xmm1,
+        in("xmm2") f64::from_bits(sses[2]),
+        in("xmm3") f64::from_bits(sses[3]),
+        in("xmm4") f64::from_bits(sses[4]),
+        in("xmm5") f64::from_bits(sses[5]),
+        in("xmm6") f64::from_bits(sses[6]),
+        in("xmm7") f64::from_bits(sses[7]),
+        lateout("rax") rax,
+        clobber_abi("C"),
+    );
+
+    ([rax, rdx], [xmm0.to_bits(), xmm1.to_bits()])
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::dyncall::{
+        sysv64::{classify, Class},
+        Type,
+    };
+
+    pub fn classes(ty: &Type) -> Option<Vec<bool>> {
+        classify(ty).map(|it| it.iter().map(|it| matches!(it, Class::Sse)).collect())
+    }
+
+    #[test]
+    pub fn sysv64_classifies_eightbytes_of_structs_test() {
+        let tagged = Type::Struct(vec![Type::I32, Type::F64]);
+        assert_eq!(Some(vec![false, true]), classes(&tagged));
+
+        let rgb = Type::Struct(vec![Type::F32, Type::F32, Type::F32]);
+        assert_eq!(Some(vec![true, true]), classes(&rgb));
+
+        let mixed = Type::Struct(vec![Type::F32, Type::I32]);
+        assert_eq!(Some(vec![false]), classes(&mixed));
+    }
+
+    #[test]
+    pub fn sysv64_passes_large_structs_in_memory_test() {
+        let big = Type::Struct(vec![Type::I64, Type::I64, Type::I64]);
+        assert_eq!(None, classes(&big));
+    }
+}
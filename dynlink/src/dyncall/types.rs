@@ -0,0 +1,174 @@
+use std::{fmt, mem};
+
+/// Represents a C type of an argument or a return value of a dynamic call.
+///
+/// `Type::Void` is the type of functions that return nothing and is not an argument type.
+/// `Type::Pointer` is the type of any data or function pointer.
+/// `Type::Struct(fields)` contains the types of the fields of a struct passed by value,
+/// laid out the way a C compiler lays them out.
+pub enum Type {
+    Void,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+    Pointer,
+    Struct(Vec<Type>),
+}
+
+impl Type {
+    /// Returns the size of the type in bytes, including the trailing padding of structs.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Void => 0,
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::I64 | Self::U64 | Self::F64 => 8,
+            Self::Pointer => mem::size_of::<*const ()>(),
+            Self::Struct(fields) => {
+                let end = fields
+                    .iter()
+                    .fold(0, |offset, it| align_to(offset, it.align()) + it.size());
+
+                align_to(end, self.align())
+            }
+        }
+    }
+
+    /// Returns the alignment of the type in bytes.
+    pub fn align(&self) -> usize {
+        match self {
+            Self::Void => 1,
+            Self::Struct(fields) => fields.iter().map(Type::align).max().unwrap_or(1),
+            scalar => scalar.size(),
+        }
+    }
+
+    /// Returns `true` for `Type::F32` and `Type::F64`.
+    #[inline]
+    pub fn is_float(&self) -> bool {
+        matches!(self, Self::F32 | Self::F64)
+    }
+}
+
+/// Rounds `offset` up to the multiple of `align`.
+pub(super) fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+impl Clone for Type {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Void => Self::Void,
+            Self::I8 => Self::I8,
+            Self::U8 => Self::U8,
+            Self::I16 => Self::I16,
+            Self::U16 => Self::U16,
+            Self::I32 => Self::I32,
+            Self::U32 => Self::U32,
+            Self::I64 => Self::I64,
+            Self::U64 => Self::U64,
+            Self::F32 => Self::F32,
+            Self::F64 => Self::F64,
+            Self::Pointer => Self::Pointer,
+            Self::Struct(fields) => Self::Struct(fields.clone()),
+        }
+    }
+}
+
+impl PartialEq for Type {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Struct(fields), Self::Struct(other)) => fields == other,
+            (Self::Struct(_), _) | (_, Self::Struct(_)) => false,
+            (scalar, other) => mem::discriminant(scalar) == mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Type {}
+
+impl fmt::Debug for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Void => f.write_str("Void"),
+            Self::I8 => f.write_str("I8"),
+            Self::U8 => f.write_str("U8"),
+            Self::I16 => f.write_str("I16"),
+            Self::U16 => f.write_str("U16"),
+            Self::I32 => f.write_str("I32"),
+            Self::U32 => f.write_str("U32"),
+            Self::I64 => f.write_str("I64"),
+            Self::U64 => f.write_str("U64"),
+            Self::F32 => f.write_str("F32"),
+            Self::F64 => f.write_str("F64"),
+            Self::Pointer => f.write_str("Pointer"),
+            Self::Struct(fields) => f.write_fmt(format_args!("Struct({:?})", fields)),
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Void => f.write_str("void"),
+            Self::I8 => f.write_str("int8_t"),
+            Self::U8 => f.write_str("uint8_t"),
+            Self::I16 => f.write_str("int16_t"),
+            Self::U16 => f.write_str("uint16_t"),
+            Self::I32 => f.write_str("int32_t"),
+            Self::U32 => f.write_str("uint32_t"),
+            Self::I64 => f.write_str("int64_t"),
+            Self::U64 => f.write_str("uint64_t"),
+            Self::F32 => f.write_str("float"),
+            Self::F64 => f.write_str("double"),
+            Self::Pointer => f.write_str("void *"),
+            Self::Struct(fields) => {
+                f.write_str("struct {")?;
+
+                for field in fields {
+                    f.write_fmt(format_args!(" {};", field))?;
+                }
+
+                f.write_str(" }")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::dyncall::Type;
+
+    #[test]
+    pub fn type_has_size_and_align_of_c_scalars_test() {
+        assert_eq!((1, 1), (Type::I8.size(), Type::I8.align()));
+        assert_eq!((4, 4), (Type::F32.size(), Type::F32.align()));
+        assert_eq!((8, 8), (Type::Pointer.size(), Type::Pointer.align()));
+    }
+
+    #[test]
+    pub fn type_lays_out_struct_with_padding_test() {
+        let tagged = Type::Struct(vec![Type::I32, Type::F64]);
+        assert_eq!((16, 8), (tagged.size(), tagged.align()));
+
+        let rgb = Type::Struct(vec![Type::F32, Type::F32, Type::F32]);
+        assert_eq!((12, 4), (rgb.size(), rgb.align()));
+
+        let nested = Type::Struct(vec![Type::U8, rgb, Type::U16]);
+        assert_eq!((20, 4), (nested.size(), nested.align()));
+    }
+
+    #[test]
+    pub fn type_displays_as_c_type_test() {
+        let tagged = Type::Struct(vec![Type::I32, Type::Pointer]);
+        assert_eq!("struct { int32_t; void *; }", tagged.to_string());
+    }
+}
@@ -0,0 +1,106 @@
+use std::{ffi, fmt};
+
+use crate::dyncall::Type;
+
+/// Represents a C value of an argument or a return value of a dynamic call.
+///
+/// Each variant contains a value of the `Type` variant with the same name.
+/// `Value::Void` is returned by functions that return nothing.
+/// `Value::Struct(fields)` contains the values of the fields of a struct in declaration order.
+pub enum Value {
+    Void,
+    I8(i8),
+    U8(u8),
+    I16(i16),
+    U16(u16),
+    I32(i32),
+    U32(u32),
+    I64(i64),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    Pointer(*mut ffi::c_void),
+    Struct(Vec<Value>),
+}
+
+impl Value {
+    /// Returns the type of the value.
+    pub fn ty(&self) -> Type {
+        match self {
+            Self::Void => Type::Void,
+            Self::I8(_) => Type::I8,
+            Self::U8(_) => Type::U8,
+            Self::I16(_) => Type::I16,
+            Self::U16(_) => Type::U16,
+            Self::I32(_) => Type::I32,
+            Self::U32(_) => Type::U32,
+            Self::I64(_) => Type::I64,
+            Self::U64(_) => Type::U64,
+            Self::F32(_) => Type::F32,
+            Self::F64(_) => Type::F64,
+            Self::Pointer(_) => Type::Pointer,
+            Self::Struct(fields) => Type::Struct(fields.iter().map(Value::ty).collect()),
+        }
+    }
+}
+
+impl Clone for Value {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Void => Self::Void,
+            Self::I8(it) => Self::I8(*it),
+            Self::U8(it) => Self::U8(*it),
+            Self::I16(it) => Self::I16(*it),
+            Self::U16(it) => Self::U16(*it),
+            Self::I32(it) => Self::I32(*it),
+            Self::U32(it) => Self::U32(*it),
+            Self::I64(it) => Self::I64(*it),
+            Self::U64(it) => Self::U64(*it),
+            Self::F32(it) => Self::F32(*it),
+            Self::F64(it) => Self::F64(*it),
+            Self::Pointer(it) => Self::Pointer(*it),
+            Self::Struct(fields) => Self::Struct(fields.clone()),
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Void, Self::Void) => true,
+            (Self::I8(a), Self::I8(b)) => a == b,
+            (Self::U8(a), Self::U8(b)) => a == b,
+            (Self::I16(a), Self::I16(b)) => a == b,
+            (Self::U16(a), Self::U16(b)) => a == b,
+            (Self::I32(a), Self::I32(b)) => a == b,
+            (Self::U32(a), Self::U32(b)) => a == b,
+            (Self::I64(a), Self::I64(b)) => a == b,
+            (Self::U64(a), Self::U64(b)) => a == b,
+            (Self::F32(a), Self::F32(b)) => a == b,
+            (Self::F64(a), Self::F64(b)) => a == b,
+            (Self::Pointer(a), Self::Pointer(b)) => a == b,
+            (Self::Struct(a), Self::Struct(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Void => f.write_str("Void"),
+            Self::I8(it) => f.write_fmt(format_args!("I8({})", it)),
+            Self::U8(it) => f.write_fmt(format_args!("U8({})", it)),
+            Self::I16(it) => f.write_fmt(format_args!("I16({})", it)),
+            Self::U16(it) => f.write_fmt(format_args!("U16({})", it)),
+            Self::I32(it) => f.write_fmt(format_args!("I32({})", it)),
+            Self::U32(it) => f.write_fmt(format_args!("U32({})", it)),
+            Self::I64(it) => f.write_fmt(format_args!("I64({})", it)),
+            Self::U64(it) => f.write_fmt(format_args!("U64({})", it)),
+            Self::F32(it) => f.write_fmt(format_args!("F32({:?})", it)),
+            Self::F64(it) => f.write_fmt(format_args!("F64({:?})", it)),
+            Self::Pointer(it) => f.write_fmt(format_args!("Pointer({:p})", it)),
+            Self::Struct(fields) => f.write_fmt(format_args!("Struct({:?})", fields)),
+        }
+    }
+}
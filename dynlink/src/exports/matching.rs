@@ -0,0 +1,103 @@
+use std::{fmt, vec};
+
+use crate::{
+    api::{Handle, LinkingError, RawSymbol},
+    exports::Pattern,
+};
+
+/// Represents an iterator of the exports of a shared object file whose names match a pattern,
+/// yielding each name with its untyped symbol.
+///
+/// Exports that can no longer be looked up by name are skipped.
+pub struct Matches<'symtab> {
+    handle: &'symtab Handle,
+    names: vec::IntoIter<String>,
+}
+
+impl<'symtab> Iterator for Matches<'symtab> {
+    type Item = (String, RawSymbol<'symtab>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for name in self.names.by_ref() {
+            // SAFETY: untyped pointer is ABI compatible with any exported symbol.
+            if let Ok(symbol) = unsafe { self.handle.lookup(&name) } {
+                return Some((name, symbol));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.names.len()))
+    }
+}
+
+impl<'symtab> fmt::Debug for Matches<'symtab> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("Matches({:?})", self.names.as_slice()))
+    }
+}
+
+impl Handle {
+    /// Looks up the symbols from the shared object file's exports whose names match `pattern`.
+    ///
+    /// # Usage
+    ///
+    /// `lookup_matching` is used to discover entry points following a naming convention,
+    /// which are cast to their types after checking their names.
+    ///
+    /// ```no_run
+    /// use dynlink::{api::Handle, exports::Pattern};
+    ///
+    /// // plugin.c
+    /// //
+    /// // int plugin_cmd_start(void) { ... }
+    /// // int plugin_cmd_stop(void) { ... }
+    ///
+    /// fn main() {
+    ///     unsafe {
+    ///         let handle = Handle::open("libplugin.so")
+    ///             .expect("libplugin handle was not opened");
+    ///
+    ///         let commands = handle.lookup_matching(Pattern::glob("plugin_cmd_*"))
+    ///             .expect("libplugin exports were not read");
+    ///
+    ///         for (name, symbol) in commands {
+    ///             let command = symbol.cast::<extern "C" fn() -> i32>();
+    ///             println!("{} returned {}", name, command.call(()));
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Returning symbols must be cast to types that are ABI compatible with their exports.
+    ///
+    /// # Notes
+    ///
+    /// Names are matched against the dynamic symbol table of the loaded shared object
+    /// in its order, which is not the order of declaration, and symbols are looked up
+    /// by name while iterating.
+    pub unsafe fn lookup_matching(&self, pattern: Pattern) -> Result<Matches<'_>, LinkingError> {
+        let mut names = self.export_names()?;
+        names.retain(|it| pattern.matches(it));
+
+        Ok(Matches {
+            handle: self,
+            names: names.into_iter(),
+        })
+    }
+
+    /// Returns the names of the shared object file's exports, skipping names that are not UTF-8.
+    pub(crate) unsafe fn export_names(&self) -> Result<Vec<String>, LinkingError> {
+        match dynlink_posix::exports::exports(&self.0) {
+            Ok(names) => Ok(names
+                .into_iter()
+                .filter_map(|it| it.into_string().ok())
+                .collect()),
+            Err(err) => Err(LinkingError::from(err)),
+        }
+    }
+}
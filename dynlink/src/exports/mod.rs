@@ -0,0 +1,5 @@
+mod matching;
+mod pattern;
+
+pub use matching::Matches;
+pub use pattern::Pattern;
@@ -0,0 +1,161 @@
+use std::fmt;
+
+/// Represents a pattern of export names.
+///
+/// `Pattern::Prefix(prefix)` matches names that start with `prefix`.
+/// `Pattern::Glob(glob)` matches names as a shell glob, where `*` matches any characters,
+/// `?` matches one character and `[...]` matches one character of a class such as `[a-z]` or `[!0-9]`.
+/// `Pattern::Regex(regex)` matches names that the regular expression matches anywhere,
+/// so it is anchored with `^` and `$` to match whole names.
+pub enum Pattern {
+    Prefix(String),
+    Glob(String),
+    Regex(regex::Regex),
+}
+
+impl Pattern {
+    /// Creates a pattern of names that start with `prefix`.
+    #[inline]
+    pub fn prefix(prefix: impl Into<String>) -> Self {
+        Self::Prefix(prefix.into())
+    }
+
+    /// Creates a pattern of names matching the shell `glob`.
+    #[inline]
+    pub fn glob(glob: impl Into<String>) -> Self {
+        Self::Glob(glob.into())
+    }
+
+    /// Creates a pattern of names matching the regular expression `regex`.
+    #[inline]
+    pub fn regex(regex: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(regex).map(Self::Regex)
+    }
+
+    /// Returns whether `name` matches the pattern.
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Prefix(prefix) => name.starts_with(prefix.as_str()),
+            Self::Glob(glob) => glob_matches(
+                &glob.chars().collect::<Vec<_>>(),
+                &name.chars().collect::<Vec<_>>(),
+            ),
+            Self::Regex(regex) => regex.is_match(name),
+        }
+    }
+}
+
+/// Matches `name` against `glob`, backtracking to the last `*` on a mismatch.
+fn glob_matches(glob: &[char], name: &[char]) -> bool {
+    let (mut g, mut n) = (0, 0);
+    let mut star = None;
+
+    while n < name.len() {
+        match glob.get(g) {
+            Some('*') => {
+                star = Some((g, n));
+                g += 1;
+                continue;
+            }
+            Some('?') => {
+                g += 1;
+                n += 1;
+                continue;
+            }
+            Some('[') => {
+                if let Some((is_match, len)) = class_matches(&glob[g..], name[n]) {
+                    if is_match {
+                        g += len;
+                        n += 1;
+                        continue;
+                    }
+                } else if name[n] == '[' {
+                    // an unterminated class is a literal bracket
+                    g += 1;
+                    n += 1;
+                    continue;
+                }
+            }
+            Some(c) if *c == name[n] => {
+                g += 1;
+                n += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        match star {
+            Some((star_g, star_n)) => {
+                star = Some((star_g, star_n + 1));
+                g = star_g + 1;
+                n = star_n + 1;
+            }
+            None => return false,
+        }
+    }
+
+    glob[g..].iter().all(|it| *it == '*')
+}
+
+/// Matches `c` against the character class at the start of `glob`,
+/// returning whether it matches and the length of the class, or `None` if it is unterminated.
+fn class_matches(glob: &[char], c: char) -> Option<(bool, usize)> {
+    let mut i = 1;
+    let negated = matches!(glob.get(i), Some('!') | Some('^'));
+    if negated {
+        i += 1;
+    }
+
+    let mut is_match = false;
+    let mut first = true;
+
+    loop {
+        let start = *glob.get(i)?;
+        if start == ']' && !first {
+            return Some((is_match != negated, i + 1));
+        }
+
+        first = false;
+
+        match (glob.get(i + 1), glob.get(i + 2)) {
+            (Some('-'), Some(end)) if *end != ']' => {
+                is_match |= start <= c && c <= *end;
+                i += 3;
+            }
+            _ => {
+                is_match |= start == c;
+                i += 1;
+            }
+        }
+    }
+}
+
+impl Clone for Pattern {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Prefix(prefix) => Self::Prefix(prefix.clone()),
+            Self::Glob(glob) => Self::Glob(glob.clone()),
+            Self::Regex(regex) => Self::Regex(regex.clone()),
+        }
+    }
+}
+
+impl fmt::Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prefix(prefix) => f.write_fmt(format_args!("Prefix({:?})", prefix)),
+            Self::Glob(glob) => f.write_fmt(format_args!("Glob({:?})", glob)),
+            Self::Regex(regex) => f.write_fmt(format_args!("Regex({:?})", regex.as_str())),
+        }
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prefix(prefix) => f.write_fmt(format_args!("{}*", prefix)),
+            Self::Glob(glob) => f.write_str(glob),
+            Self::Regex(regex) => f.write_fmt(format_args!("/{}/", regex.as_str())),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use pointersized::PointerSized;
+
+use dynlink_posix::hook::{PosixHookError, PosixImportHook};
+
+use crate::api::Handle;
+
+/// Represents a redirected import of a shared object that is restored on drop.
+pub type ImportHook<'symtab, T> = PosixImportHook<'symtab, T>;
+
+/// Represents an error that occurred during import hooking.
+pub type HookError = PosixHookError;
+
+/// Redirects the import of `symbol` by the shared object owned by `handle` to `replacement`.
+///
+/// Only the GOT slots of the shared object owned by `handle` are overwritten, so other shared
+/// objects importing the same symbol are not affected. The original GOT values are restored
+/// when the returned hook is dropped. Hooking and restoring are serialized across threads.
+///
+/// # Usage
+///
+/// ```no_run
+/// use std::ffi;
+///
+/// use dynlink::{api::Handle, hook};
+///
+/// // import.c
+/// //
+/// // int parse_of(const char *s) {
+/// //    return atoi(s);
+/// // }
+///
+/// unsafe extern "C" fn fake_atoi(_: *const ffi::c_char) -> ffi::c_int {
+///     42
+/// }
+///
+/// fn main() {
+///     unsafe {
+///         let handle = Handle::open("libimport.so")
+///             .expect("libimport handle was not opened");
+///
+///         let parse_fn = handle.lookup::<extern "C" fn(*const ffi::c_char) -> ffi::c_int>("parse_of")
+///             .expect("parse_of symbol was not found");
+///
+///         let hook = hook::hook_import(&handle, "atoi", fake_atoi as unsafe extern "C" fn(_) -> _)
+///             .expect("atoi import was not hooked");
+///
+///         assert_eq!(42, parse_fn.apply(|it| it(c"1".as_ptr())));
+///
+///         drop(hook);
+///         assert_eq!(1, parse_fn.apply(|it| it(c"1".as_ptr())));
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Type `T` must be ABI compatible with the type of the imported symbol.
+/// The shared object must not call the import concurrently with hooking and restoring,
+/// and hooks of the same import must be dropped in the reverse order of creation.
+/// The protection of the GOT pages must not be changed by other code concurrently with hooking and restoring.
+pub unsafe fn hook_import<'symtab, T: PointerSized>(
+    handle: &'symtab Handle,
+    symbol: &str,
+    replacement: T,
+) -> Result<ImportHook<'symtab, T>, HookError> {
+    dynlink_posix::hook::hook_import(&handle.0, symbol, replacement)
+}
@@ -0,0 +1,3 @@
+mod import;
+
+pub use import::{hook_import, HookError, ImportHook};
@@ -0,0 +1,154 @@
+use std::{error, fmt, sync};
+
+use crate::api::{FallbackError, Handle, LinkingError};
+
+/// Represents an error that occurred during lazy dynamic linking processing.
+///
+/// `LazyError::Library(path, err)` indicates that the shared object file could not be opened.
+/// `LazyError::Symbol(symbol, err)` indicates that the shared object file was opened,
+/// but the symbol could not be found in its symbol table.
+/// `LazyError::Aliases(symbol, err)` indicates that the shared object file was opened,
+/// but none of the aliases of the symbol could be found in its symbol table.
+pub enum LazyError {
+    Library(&'static str, LinkingError),
+    Symbol(&'static str, LinkingError),
+    Aliases(&'static str, FallbackError),
+}
+
+impl Clone for LazyError {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Library(path, err) => Self::Library(path, err.clone()),
+            Self::Symbol(symbol, err) => Self::Symbol(symbol, err.clone()),
+            Self::Aliases(symbol, err) => Self::Aliases(symbol, err.clone()),
+        }
+    }
+}
+
+impl fmt::Debug for LazyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Library(path, err) => f.write_fmt(format_args!("Library({:?}, {:?})", path, err)),
+            Self::Symbol(symbol, err) => {
+                f.write_fmt(format_args!("Symbol({:?}, {:?})", symbol, err))
+            }
+            Self::Aliases(symbol, err) => {
+                f.write_fmt(format_args!("Aliases({:?}, {:?})", symbol, err))
+            }
+        }
+    }
+}
+
+impl fmt::Display for LazyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Library(path, err) => f.write_fmt(format_args!(
+                "Shared object {} is unavailable: {}",
+                path, err
+            )),
+
+            Self::Symbol(symbol, err) => {
+                f.write_fmt(format_args!("Symbol {} is unavailable: {}", symbol, err))
+            }
+
+            Self::Aliases(symbol, err) => {
+                f.write_fmt(format_args!("Symbol {} is unavailable: {}", symbol, err))
+            }
+        }
+    }
+}
+
+impl error::Error for LazyError {}
+
+/// Represents a shared object file that is opened on first use.
+///
+/// # Usage
+///
+/// `LazyLibrary` is intended to be used as a static, usually declared by the `lazy_library!` macro.
+///
+/// ```no_run
+/// use dynlink::lazy::{LazyLibrary, LazySymbol};
+///
+/// // sum.c
+/// //
+/// // int sum_of(int a, int b) {
+/// //    return a + b;
+/// // }
+///
+/// static LIBSUM: LazyLibrary = LazyLibrary::new("libsum.so");
+/// static SUM_OF: LazySymbol<extern "C" fn(i32, i32) -> i32> = LazySymbol::new("sum_of");
+///
+/// fn main() {
+///     unsafe {
+///         let symbol = SUM_OF.resolve(&LIBSUM)
+///             .expect("sum_of symbol is unavailable");
+///
+///         assert_eq!(2, symbol.apply(|sum_of_fn| sum_of_fn(1, 1)));
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Shared object initialization routines that are executed on first use may contain
+/// undefined behavior (UB).
+///
+/// The result of the first opening attempt is cached, so the shared object file is opened
+/// at most once even under concurrent first use, and a failed attempt is not retried.
+pub struct LazyLibrary {
+    path: &'static str,
+    handle: sync::OnceLock<Result<Handle, LinkingError>>,
+}
+
+impl LazyLibrary {
+    /// Creates a shared object file specified by `path` that is not opened yet.
+    pub const fn new(path: &'static str) -> Self {
+        Self {
+            path,
+            handle: sync::OnceLock::new(),
+        }
+    }
+
+    /// Returns the path the shared object file is opened with.
+    #[inline]
+    pub fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// Opens shared object file on first call and returns its handle.
+    ///
+    /// # Safety
+    ///
+    /// Shared object initialization routines that are executed when this
+    /// function is called for the first time may be UB.
+    pub unsafe fn handle(&'static self) -> Result<&'static Handle, LazyError> {
+        match self.handle.get_or_init(|| Handle::open(self.path)) {
+            Ok(handle) => Ok(handle),
+            Err(err) => Err(LazyError::Library(self.path, err.clone())),
+        }
+    }
+}
+
+impl fmt::Debug for LazyLibrary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("LazyLibrary({:?})", self.path))
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::lazy::LazyLibrary;
+
+    pub fn assert_send<T: Send>() {}
+    pub fn assert_sync<T: Sync>() {}
+
+    #[test]
+    pub fn lazy_library_marked_as_send_test() {
+        assert_send::<LazyLibrary>();
+    }
+
+    #[test]
+    pub fn lazy_library_marked_as_sync_test() {
+        assert_sync::<LazyLibrary>();
+    }
+}
@@ -0,0 +1,93 @@
+/// Declares a lazily opened shared object file static with functions resolved on first call.
+///
+/// The macro declares a struct type and a static of this type, where attributes and doc comments
+/// apply to the static only. Each declared function becomes
+/// an unsafe method that opens the shared object file on first use of any method, looks up
+/// the symbol on its own first call and returns a `Result` with `LazyError` that says
+/// whether the shared object file or the symbol is unavailable.
+///
+/// A function declared with `as ["name", ..]` is looked up by the first of these aliases
+/// found in the shared object file instead of its name, e.g. when the symbol is renamed
+/// between releases.
+///
+/// # Usage
+///
+/// ```no_run
+/// // sum.c
+/// //
+/// // int sum_of(int a, int b) {
+/// //    return a + b;
+/// // }
+///
+/// dynlink::lazy_library! {
+///     /// Lazily opened libsum.
+///     pub static LIBSUM: LibSum = "libsum.so";
+///
+///     pub extern "C" fn sum_of(a: i32, b: i32) -> i32;
+///     pub extern "C" fn sum(a: i32, b: i32) -> i32 as ["sum_of_v2", "sum_of"];
+/// }
+///
+/// fn main() {
+///     unsafe {
+///         match LIBSUM.sum_of(1, 1) {
+///             Ok(sum) => assert_eq!(2, sum),
+///             Err(err) => eprintln!("{}", err),
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Declared function signatures must be ABI compatible with the symbols from the shared object.
+#[macro_export]
+macro_rules! lazy_library {
+    (
+        $(#[$attr:meta])*
+        $vis:vis static $name:ident: $ty:ident = $path:expr;
+
+        $(
+            $(#[$fn_attr:meta])*
+            $fn_vis:vis extern $abi:literal fn $fn_name:ident($($arg:ident: $arg_ty:ty),* $(,)?) $(-> $ret:ty)?
+                $(as [$($alias:literal),+ $(,)?])?;
+        )*
+    ) => {
+        #[doc = ::core::concat!("Type of the lazily opened `", ::core::stringify!($name), "` shared object file.")]
+        $vis struct $ty {
+            __dynlink_library: $crate::lazy::LazyLibrary,
+            $($fn_name: $crate::lazy::LazySymbol<unsafe extern $abi fn($($arg_ty),*) $(-> $ret)?>,)*
+        }
+
+        impl $ty {
+            $(
+                $(#[$fn_attr])*
+                #[allow(clippy::too_many_arguments)]
+                $fn_vis unsafe fn $fn_name(
+                    &'static self,
+                    $($arg: $arg_ty),*
+                ) -> ::core::result::Result<$crate::lazy_library!(@ret $($ret)?), $crate::lazy::LazyError> {
+                    let symbol = self.$fn_name.resolve(&self.__dynlink_library)?;
+                    let f = symbol.apply(|f| f);
+
+                    Ok(f($($arg),*))
+                }
+            )*
+        }
+
+        $(#[$attr])*
+        $vis static $name: $ty = $ty {
+            __dynlink_library: $crate::lazy::LazyLibrary::new($path),
+            $($fn_name: $crate::lazy_library!(@symbol $fn_name $([$($alias),+])?),)*
+        };
+    };
+
+    (@symbol $fn_name:ident) => {
+        $crate::lazy::LazySymbol::new(::core::stringify!($fn_name))
+    };
+    (@symbol $fn_name:ident [$($alias:literal),+]) => {
+        $crate::lazy::LazySymbol::with_aliases(::core::stringify!($fn_name), &[$($alias),+])
+    };
+
+    (@ret) => { () };
+    (@ret $ret:ty) => { $ret };
+}
@@ -0,0 +1,6 @@
+mod library;
+mod macros;
+mod symbol;
+
+pub use library::{LazyError, LazyLibrary};
+pub use symbol::LazySymbol;
@@ -0,0 +1,142 @@
+use std::{fmt, sync};
+
+use pointersized::PointerSized;
+
+use crate::{
+    api::Symbol,
+    lazy::{LazyError, LazyLibrary},
+};
+
+/// Represents a typed symbol that is looked up on first use.
+///
+/// # Usage
+///
+/// `LazySymbol` is resolved from a `LazyLibrary` static, usually declared by the `lazy_library!` macro.
+///
+/// ```no_run
+/// use dynlink::lazy::{LazyLibrary, LazySymbol};
+///
+/// // sum.c
+/// //
+/// // int sum_of(int a, int b) {
+/// //    return a + b;
+/// // }
+///
+/// static LIBSUM: LazyLibrary = LazyLibrary::new("libsum.so");
+/// static SUM_OF: LazySymbol<extern "C" fn(i32, i32) -> i32> = LazySymbol::new("sum_of");
+///
+/// fn main() {
+///     unsafe {
+///         let sum = SUM_OF.resolve(&LIBSUM)
+///             .map(|symbol| symbol.apply(|sum_of_fn| sum_of_fn(1, 1)))
+///             .expect("sum_of symbol is unavailable");
+///
+///         assert_eq!(2, sum);
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Type `T` must be ABI compatible with the type of symbol from a shared object.
+///
+/// The result of the first lookup is cached, so the symbol is looked up at most once
+/// even under concurrent first use, and a failed lookup is not retried.
+pub struct LazySymbol<T: PointerSized + 'static> {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    symbol: sync::OnceLock<Result<Symbol<'static, T>, LazyError>>,
+}
+
+impl<T: PointerSized + 'static> LazySymbol<T> {
+    /// Creates a symbol specified by `name` that is not looked up yet.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            aliases: &[],
+            symbol: sync::OnceLock::new(),
+        }
+    }
+
+    /// Creates a symbol specified by `name` that is not looked up yet and is looked up
+    /// by the first of `aliases` found instead of its name.
+    pub const fn with_aliases(name: &'static str, aliases: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            aliases,
+            symbol: sync::OnceLock::new(),
+        }
+    }
+
+    /// Returns the name the symbol is declared with.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the names the symbol is looked up by in order,
+    /// which are empty when it is looked up by its name.
+    #[inline]
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Opens `library` and looks up the symbol on first call and returns it.
+    ///
+    /// # Safety
+    ///
+    /// Shared object initialization routines that are executed when this
+    /// function is called for the first time may be UB.
+    /// Type `T` must be ABI compatible with the type of symbol from the shared object.
+    pub unsafe fn resolve(
+        &'static self,
+        library: &'static LazyLibrary,
+    ) -> Result<&'static Symbol<'static, T>, LazyError> {
+        let handle = library.handle()?;
+
+        let symbol = self.symbol.get_or_init(|| {
+            if self.aliases.is_empty() {
+                handle
+                    .lookup(self.name)
+                    .map_err(|err| LazyError::Symbol(self.name, err))
+            } else {
+                handle
+                    .lookup_first(self.aliases)
+                    .map(|(_, symbol)| symbol)
+                    .map_err(|err| LazyError::Aliases(self.name, err))
+            }
+        });
+
+        match symbol {
+            Ok(symbol) => Ok(symbol),
+            Err(err) => Err(err.clone()),
+        }
+    }
+}
+
+impl<T: PointerSized + 'static> fmt::Debug for LazySymbol<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "LazySymbol({:?}, {:?})",
+            self.name, self.aliases
+        ))
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::lazy::LazySymbol;
+
+    pub fn assert_send<T: Send>() {}
+    pub fn assert_sync<T: Sync>() {}
+
+    #[test]
+    pub fn lazy_symbol_marked_as_send_test() {
+        assert_send::<LazySymbol<fn(i32, i32) -> i32>>();
+    }
+
+    #[test]
+    pub fn lazy_symbol_marked_as_sync_test() {
+        assert_sync::<LazySymbol<fn(i32, i32) -> i32>>();
+    }
+}
@@ -75,6 +75,78 @@
 //! }
 //! ```
 //!
+//! Optional shared objects can be opened on first use with the `lazy_library!` macro.
+//!
+//! ```no_run
+//! // sum.c
+//! //
+//! // int sum_of(int a, int b) {
+//! //    return a + b;
+//! // }
+//!
+//! dynlink::lazy_library! {
+//!     static LIBSUM: LibSum = "libsum.so";
+//!
+//!     extern "C" fn sum_of(a: i32, b: i32) -> i32;
+//! }
+//!
+//! fn main() {
+//!     unsafe {
+//!         match LIBSUM.sum_of(1, 1) {
+//!             Ok(sum) => println!("{}", sum),
+//!             Err(err) => eprintln!("{}", err),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Functions whose signatures are known only at runtime are called with the `dyncall` module.
+//!
+//! ```no_run
+//! #![cfg(target_os = "linux")]
+//!
+//! use dynlink::{
+//!     api::{Handle, RawSymbol},
+//!     dyncall::{Signature, Type, Value},
+//! };
+//!
+//! fn main() {
+//!     unsafe {
+//!         let handle = Handle::open("libsum.so").expect("libsum handle was not opened");
+//!         let symbol: RawSymbol<'_> = handle.lookup("sum_of").expect("sum_of symbol was not found");
+//!
+//!         let signature = Signature::new(vec![Type::I32, Type::I32], Type::I32);
+//!         let sum = symbol.call_dynamic(&signature, &[Value::I32(1), Value::I32(1)]);
+//!
+//!         println!("{:?}", sum);
+//!     }
+//! }
+//! ```
+//!
+//! Rust closures are passed to C as callbacks with the `callback` module.
+//!
+//! ```no_run
+//! use std::ffi;
+//!
+//! use dynlink::{api::Handle, callback::Callback};
+//!
+//! type ApplyFn = extern "C" fn(
+//!     unsafe extern "C" fn(*mut ffi::c_void, i32) -> i32,
+//!     *mut ffi::c_void,
+//!     i32,
+//! ) -> i32;
+//!
+//! fn main() {
+//!     unsafe {
+//!         let handle = Handle::open("libcallback.so").expect("libcallback handle was not opened");
+//!         let apply = handle.lookup::<ApplyFn>("apply_leading").expect("apply_leading symbol was not found");
+//!
+//!         let callback = Callback::new(|value: i32| value * 2);
+//!         println!("{}", apply.call((callback.trampoline(), callback.user_data(), 21)));
+//!     }
+//! }
+//! ```
+//!
 //! Platform-specific APIs are also available in the `platform` module.
 //!
 //! ```no_run
@@ -98,4 +170,61 @@
 //!
 
 pub mod api;
+pub mod callback;
+pub mod delayload;
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+))]
+pub mod demangle;
+#[cfg(any(
+    all(unix, target_arch = "x86_64"),
+    all(target_os = "linux", target_arch = "aarch64")
+))]
+pub mod dyncall;
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+))]
+pub mod exports;
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm"
+    )
+))]
+pub mod hook;
+pub mod lazy;
+pub mod loader;
+pub mod object;
+pub mod optional;
 pub mod platform;
+#[cfg(target_os = "linux")]
+pub mod preflight;
+pub mod registry;
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+))]
+pub mod unload;
@@ -0,0 +1,228 @@
+use std::{ffi, fmt, time};
+
+use crate::api::LinkingError;
+
+/// Represents an attempt to open a shared object file.
+pub struct OpenEvent<'event> {
+    pub(super) path: &'event ffi::OsStr,
+    pub(super) outcome: Result<(), &'event LinkingError>,
+    pub(super) elapsed: time::Duration,
+}
+
+impl<'event> OpenEvent<'event> {
+    /// Returns the path the shared object file was opened with.
+    #[inline]
+    pub fn path(&self) -> &'event ffi::OsStr {
+        self.path
+    }
+
+    /// Returns the outcome of the attempt.
+    #[inline]
+    pub fn outcome(&self) -> Result<(), &'event LinkingError> {
+        self.outcome
+    }
+
+    /// Returns the time the attempt took.
+    #[inline]
+    pub fn elapsed(&self) -> time::Duration {
+        self.elapsed
+    }
+}
+
+impl<'event> fmt::Debug for OpenEvent<'event> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "OpenEvent({:?}, {:?}, {:?})",
+            self.path, self.outcome, self.elapsed
+        ))
+    }
+}
+
+/// Represents an attempt to look up a symbol from a shared object file's symbol table.
+pub struct LookupEvent<'event> {
+    pub(super) path: &'event ffi::OsStr,
+    pub(super) symbol: &'event str,
+    pub(super) outcome: Result<(), &'event LinkingError>,
+    pub(super) elapsed: time::Duration,
+}
+
+impl<'event> LookupEvent<'event> {
+    /// Returns the path the shared object file was opened with.
+    #[inline]
+    pub fn path(&self) -> &'event ffi::OsStr {
+        self.path
+    }
+
+    /// Returns the name of the symbol.
+    #[inline]
+    pub fn symbol(&self) -> &'event str {
+        self.symbol
+    }
+
+    /// Returns the outcome of the attempt.
+    #[inline]
+    pub fn outcome(&self) -> Result<(), &'event LinkingError> {
+        self.outcome
+    }
+
+    /// Returns the time the attempt took.
+    #[inline]
+    pub fn elapsed(&self) -> time::Duration {
+        self.elapsed
+    }
+}
+
+impl<'event> fmt::Debug for LookupEvent<'event> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "LookupEvent({:?}, {:?}, {:?}, {:?})",
+            self.path, self.symbol, self.outcome, self.elapsed
+        ))
+    }
+}
+
+/// Represents closing of a handle of a shared object file.
+pub struct CloseEvent<'event> {
+    pub(super) path: &'event ffi::OsStr,
+    pub(super) outcome: Result<(), &'event LinkingError>,
+    pub(super) elapsed: time::Duration,
+}
+
+impl<'event> CloseEvent<'event> {
+    /// Returns the path the shared object file was opened with.
+    #[inline]
+    pub fn path(&self) -> &'event ffi::OsStr {
+        self.path
+    }
+
+    /// Returns the outcome of closing.
+    #[inline]
+    pub fn outcome(&self) -> Result<(), &'event LinkingError> {
+        self.outcome
+    }
+
+    /// Returns the time closing took.
+    #[inline]
+    pub fn elapsed(&self) -> time::Duration {
+        self.elapsed
+    }
+}
+
+impl<'event> fmt::Debug for CloseEvent<'event> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "CloseEvent({:?}, {:?}, {:?})",
+            self.path, self.outcome, self.elapsed
+        ))
+    }
+}
+
+/// Represents an owned loader event recorded by `RecordingHooks`.
+pub enum LoaderEvent {
+    Open {
+        path: ffi::OsString,
+        outcome: Result<(), LinkingError>,
+        elapsed: time::Duration,
+    },
+    Lookup {
+        path: ffi::OsString,
+        symbol: String,
+        outcome: Result<(), LinkingError>,
+        elapsed: time::Duration,
+    },
+    Close {
+        path: ffi::OsString,
+        outcome: Result<(), LinkingError>,
+        elapsed: time::Duration,
+    },
+}
+
+impl LoaderEvent {
+    /// Returns the path the shared object file was opened with.
+    pub fn path(&self) -> &ffi::OsStr {
+        match self {
+            Self::Open { path, .. } | Self::Lookup { path, .. } | Self::Close { path, .. } => path,
+        }
+    }
+
+    /// Checks whether the event has a successful outcome.
+    pub fn is_ok(&self) -> bool {
+        match self {
+            Self::Open { outcome, .. }
+            | Self::Lookup { outcome, .. }
+            | Self::Close { outcome, .. } => outcome.is_ok(),
+        }
+    }
+}
+
+impl Clone for LoaderEvent {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Open {
+                path,
+                outcome,
+                elapsed,
+            } => Self::Open {
+                path: path.clone(),
+                outcome: outcome.clone(),
+                elapsed: *elapsed,
+            },
+
+            Self::Lookup {
+                path,
+                symbol,
+                outcome,
+                elapsed,
+            } => Self::Lookup {
+                path: path.clone(),
+                symbol: symbol.clone(),
+                outcome: outcome.clone(),
+                elapsed: *elapsed,
+            },
+
+            Self::Close {
+                path,
+                outcome,
+                elapsed,
+            } => Self::Close {
+                path: path.clone(),
+                outcome: outcome.clone(),
+                elapsed: *elapsed,
+            },
+        }
+    }
+}
+
+impl fmt::Debug for LoaderEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Open {
+                path,
+                outcome,
+                elapsed,
+            } => f.write_fmt(format_args!(
+                "Open({:?}, {:?}, {:?})",
+                path, outcome, elapsed
+            )),
+
+            Self::Lookup {
+                path,
+                symbol,
+                outcome,
+                elapsed,
+            } => f.write_fmt(format_args!(
+                "Lookup({:?}, {:?}, {:?}, {:?})",
+                path, symbol, outcome, elapsed
+            )),
+
+            Self::Close {
+                path,
+                outcome,
+                elapsed,
+            } => f.write_fmt(format_args!(
+                "Close({:?}, {:?}, {:?})",
+                path, outcome, elapsed
+            )),
+        }
+    }
+}
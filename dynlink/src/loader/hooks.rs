@@ -0,0 +1,158 @@
+use std::{
+    ffi,
+    sync::{self, atomic},
+    time,
+};
+
+use crate::{
+    api::LinkingError,
+    loader::{CloseEvent, LookupEvent, OpenEvent},
+};
+
+/// Process-wide observer of loader events.
+///
+/// # Usage
+///
+/// `LoaderHooks` is invoked by `Handle::open`, `Handle::lookup` and `Handle` drop with the path
+/// or symbol, the outcome and the elapsed time. All methods have empty default implementations.
+///
+/// ```no_run
+/// use std::sync::Arc;
+///
+/// use dynlink::{
+///     api::Handle,
+///     loader::{self, LoaderHooks, OpenEvent},
+/// };
+///
+/// struct PrintHooks;
+///
+/// impl LoaderHooks for PrintHooks {
+///     fn on_open(&self, event: &OpenEvent<'_>) {
+///         println!("{:?} opened in {:?}", event.path(), event.elapsed());
+///     }
+/// }
+///
+/// fn main() {
+///     loader::set_hooks(Arc::new(PrintHooks));
+///
+///     unsafe {
+///         let _ = Handle::open("libsum.so");
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// Hooks are invoked on the thread that performs the operation,
+/// so they must not open, look up or close shared objects themselves.
+pub trait LoaderHooks: Send + Sync {
+    /// Invoked after an attempt to open a shared object file.
+    fn on_open(&self, event: &OpenEvent<'_>) {
+        let _ = event;
+    }
+
+    /// Invoked after an attempt to look up a symbol.
+    fn on_lookup(&self, event: &LookupEvent<'_>) {
+        let _ = event;
+    }
+
+    /// Invoked after a handle of a shared object file is closed.
+    fn on_close(&self, event: &CloseEvent<'_>) {
+        let _ = event;
+    }
+}
+
+static HOOKS: sync::RwLock<Option<sync::Arc<dyn LoaderHooks>>> = sync::RwLock::new(None);
+
+static IS_INSTALLED: atomic::AtomicBool = atomic::AtomicBool::new(false);
+
+/// Installs process-wide `hooks` replacing previously installed ones.
+pub fn set_hooks(hooks: sync::Arc<dyn LoaderHooks>) {
+    let mut installed = HOOKS.write().unwrap_or_else(sync::PoisonError::into_inner);
+    *installed = Some(hooks);
+
+    IS_INSTALLED.store(true, atomic::Ordering::Release);
+}
+
+/// Removes process-wide hooks and returns previously installed ones.
+///
+/// When the `log` or `tracing` feature is enabled, the default hooks are used afterwards.
+pub fn take_hooks() -> Option<sync::Arc<dyn LoaderHooks>> {
+    let mut installed = HOOKS.write().unwrap_or_else(sync::PoisonError::into_inner);
+    IS_INSTALLED.store(false, atomic::Ordering::Release);
+
+    installed.take()
+}
+
+/// Checks whether any hooks are invoked, so the elapsed time has to be measured.
+#[inline]
+pub(crate) fn is_active() -> bool {
+    cfg!(any(feature = "log", feature = "tracing")) || IS_INSTALLED.load(atomic::Ordering::Acquire)
+}
+
+/// Invokes installed hooks or the default ones.
+fn dispatch(f: impl FnOnce(&dyn LoaderHooks)) {
+    if IS_INSTALLED.load(atomic::Ordering::Acquire) {
+        let installed = HOOKS
+            .read()
+            .unwrap_or_else(sync::PoisonError::into_inner)
+            .clone();
+
+        if let Some(hooks) = installed {
+            return f(&*hooks);
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    return f(&crate::loader::TracingHooks);
+
+    #[cfg(all(feature = "log", not(feature = "tracing")))]
+    return f(&crate::loader::LogHooks);
+
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    let _ = f;
+}
+
+pub(crate) fn notify_open(
+    path: &ffi::OsStr,
+    outcome: Result<(), &LinkingError>,
+    elapsed: time::Duration,
+) {
+    dispatch(|hooks| {
+        hooks.on_open(&OpenEvent {
+            path,
+            outcome,
+            elapsed,
+        })
+    });
+}
+
+pub(crate) fn notify_lookup(
+    path: &ffi::OsStr,
+    symbol: &str,
+    outcome: Result<(), &LinkingError>,
+    elapsed: time::Duration,
+) {
+    dispatch(|hooks| {
+        hooks.on_lookup(&LookupEvent {
+            path,
+            symbol,
+            outcome,
+            elapsed,
+        })
+    });
+}
+
+pub(crate) fn notify_close(
+    path: &ffi::OsStr,
+    outcome: Result<(), &LinkingError>,
+    elapsed: time::Duration,
+) {
+    dispatch(|hooks| {
+        hooks.on_close(&CloseEvent {
+            path,
+            outcome,
+            elapsed,
+        })
+    });
+}
@@ -0,0 +1,68 @@
+use crate::loader::{CloseEvent, LoaderHooks, LookupEvent, OpenEvent};
+
+/// Loader hooks that emit `log` records.
+///
+/// Successful operations are logged at the `debug` level and failures at the `warn` level
+/// with the `dynlink::loader` target. These hooks are used by default when the `log` feature
+/// is enabled and no other hooks are installed.
+pub struct LogHooks;
+
+const TARGET: &str = "dynlink::loader";
+
+impl LoaderHooks for LogHooks {
+    fn on_open(&self, event: &OpenEvent<'_>) {
+        match event.outcome() {
+            Ok(()) => log::debug!(
+                target: TARGET,
+                "Opened {:?} in {:?}",
+                event.path(),
+                event.elapsed()
+            ),
+            Err(err) => log::warn!(
+                target: TARGET,
+                "Failed to open {:?} in {:?}: {}",
+                event.path(),
+                event.elapsed(),
+                err
+            ),
+        }
+    }
+
+    fn on_lookup(&self, event: &LookupEvent<'_>) {
+        match event.outcome() {
+            Ok(()) => log::debug!(
+                target: TARGET,
+                "Looked up {} in {:?} in {:?}",
+                event.symbol(),
+                event.path(),
+                event.elapsed()
+            ),
+            Err(err) => log::warn!(
+                target: TARGET,
+                "Failed to look up {} in {:?} in {:?}: {}",
+                event.symbol(),
+                event.path(),
+                event.elapsed(),
+                err
+            ),
+        }
+    }
+
+    fn on_close(&self, event: &CloseEvent<'_>) {
+        match event.outcome() {
+            Ok(()) => log::debug!(
+                target: TARGET,
+                "Closed {:?} in {:?}",
+                event.path(),
+                event.elapsed()
+            ),
+            Err(err) => log::warn!(
+                target: TARGET,
+                "Failed to close {:?} in {:?}: {}",
+                event.path(),
+                event.elapsed(),
+                err
+            ),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+mod event;
+mod hooks;
+#[cfg(feature = "log")]
+mod log;
+mod recording;
+#[cfg(feature = "tracing")]
+mod tracing;
+
+pub use event::{CloseEvent, LoaderEvent, LookupEvent, OpenEvent};
+pub(crate) use hooks::{is_active, notify_close, notify_lookup, notify_open};
+pub use hooks::{set_hooks, take_hooks, LoaderHooks};
+#[cfg(feature = "log")]
+pub use log::LogHooks;
+pub use recording::RecordingHooks;
+#[cfg(feature = "tracing")]
+pub use tracing::TracingHooks;
@@ -0,0 +1,88 @@
+use std::{fmt, sync};
+
+use crate::loader::{CloseEvent, LoaderEvent, LoaderHooks, LookupEvent, OpenEvent};
+
+/// Loader hooks that record events for assertions.
+///
+/// # Usage
+///
+/// ```no_run
+/// use std::sync::Arc;
+///
+/// use dynlink::{
+///     api::Handle,
+///     loader::{self, LoaderEvent, RecordingHooks},
+/// };
+///
+/// fn main() {
+///     let hooks = Arc::new(RecordingHooks::new());
+///     loader::set_hooks(hooks.clone());
+///
+///     unsafe {
+///         let _ = Handle::open("libsum.so");
+///     }
+///
+///     assert!(hooks.events().iter().any(|it| matches!(it, LoaderEvent::Open { .. })));
+/// }
+/// ```
+pub struct RecordingHooks(sync::Mutex<Vec<LoaderEvent>>);
+
+impl RecordingHooks {
+    /// Creates hooks with no recorded events.
+    pub const fn new() -> Self {
+        Self(sync::Mutex::new(Vec::new()))
+    }
+
+    /// Returns a snapshot of recorded events in the order they occurred.
+    pub fn events(&self) -> Vec<LoaderEvent> {
+        self.lock().clone()
+    }
+
+    /// Removes all recorded events and returns them.
+    pub fn take(&self) -> Vec<LoaderEvent> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    fn lock(&self) -> sync::MutexGuard<'_, Vec<LoaderEvent>> {
+        self.0.lock().unwrap_or_else(sync::PoisonError::into_inner)
+    }
+}
+
+impl Default for RecordingHooks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LoaderHooks for RecordingHooks {
+    fn on_open(&self, event: &OpenEvent<'_>) {
+        self.lock().push(LoaderEvent::Open {
+            path: event.path().to_owned(),
+            outcome: event.outcome().map_err(Clone::clone),
+            elapsed: event.elapsed(),
+        });
+    }
+
+    fn on_lookup(&self, event: &LookupEvent<'_>) {
+        self.lock().push(LoaderEvent::Lookup {
+            path: event.path().to_owned(),
+            symbol: event.symbol().to_owned(),
+            outcome: event.outcome().map_err(Clone::clone),
+            elapsed: event.elapsed(),
+        });
+    }
+
+    fn on_close(&self, event: &CloseEvent<'_>) {
+        self.lock().push(LoaderEvent::Close {
+            path: event.path().to_owned(),
+            outcome: event.outcome().map_err(Clone::clone),
+            elapsed: event.elapsed(),
+        });
+    }
+}
+
+impl fmt::Debug for RecordingHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("RecordingHooks({:?})", &*self.lock()))
+    }
+}
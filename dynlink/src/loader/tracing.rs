@@ -0,0 +1,67 @@
+use crate::loader::{CloseEvent, LoaderHooks, LookupEvent, OpenEvent};
+
+/// Loader hooks that emit `tracing` events.
+///
+/// Each operation is reported after it completes as a single event with the `dynlink::loader`
+/// target carrying the `path`, `symbol` and `elapsed` fields, at the `debug` level on success
+/// and at the `warn` level with the `error` field on failure. These hooks are used by default
+/// when the `tracing` feature is enabled and no other hooks are installed.
+pub struct TracingHooks;
+
+impl LoaderHooks for TracingHooks {
+    fn on_open(&self, event: &OpenEvent<'_>) {
+        match event.outcome() {
+            Ok(()) => tracing::debug!(
+                target: "dynlink::loader",
+                path = ?event.path(),
+                elapsed = ?event.elapsed(),
+                "opened"
+            ),
+            Err(err) => tracing::warn!(
+                target: "dynlink::loader",
+                path = ?event.path(),
+                elapsed = ?event.elapsed(),
+                error = %err,
+                "failed to open"
+            ),
+        }
+    }
+
+    fn on_lookup(&self, event: &LookupEvent<'_>) {
+        match event.outcome() {
+            Ok(()) => tracing::debug!(
+                target: "dynlink::loader",
+                path = ?event.path(),
+                symbol = event.symbol(),
+                elapsed = ?event.elapsed(),
+                "looked up"
+            ),
+            Err(err) => tracing::warn!(
+                target: "dynlink::loader",
+                path = ?event.path(),
+                symbol = event.symbol(),
+                elapsed = ?event.elapsed(),
+                error = %err,
+                "failed to look up"
+            ),
+        }
+    }
+
+    fn on_close(&self, event: &CloseEvent<'_>) {
+        match event.outcome() {
+            Ok(()) => tracing::debug!(
+                target: "dynlink::loader",
+                path = ?event.path(),
+                elapsed = ?event.elapsed(),
+                "closed"
+            ),
+            Err(err) => tracing::warn!(
+                target: "dynlink::loader",
+                path = ?event.path(),
+                elapsed = ?event.elapsed(),
+                error = %err,
+                "failed to close"
+            ),
+        }
+    }
+}
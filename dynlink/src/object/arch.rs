@@ -0,0 +1,206 @@
+use std::{fmt, mem};
+
+const EM_386: u16 = 3;
+const EM_MIPS: u16 = 8;
+const EM_PPC: u16 = 20;
+const EM_PPC64: u16 = 21;
+const EM_S390: u16 = 22;
+const EM_ARM: u16 = 40;
+const EM_X86_64: u16 = 62;
+const EM_AARCH64: u16 = 183;
+const EM_RISCV: u16 = 243;
+const EM_LOONGARCH: u16 = 258;
+
+const CPU_ARCH_ABI64: u32 = 0x0100_0000;
+const CPU_TYPE_X86: u32 = 7;
+const CPU_TYPE_ARM: u32 = 12;
+const CPU_TYPE_POWERPC: u32 = 18;
+
+const IMAGE_FILE_MACHINE_I386: u16 = 0x014c;
+const IMAGE_FILE_MACHINE_ARM: u16 = 0x01c0;
+const IMAGE_FILE_MACHINE_ARMNT: u16 = 0x01c4;
+const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
+const IMAGE_FILE_MACHINE_ARM64: u16 = 0xaa64;
+const IMAGE_FILE_MACHINE_RISCV32: u16 = 0x5032;
+const IMAGE_FILE_MACHINE_RISCV64: u16 = 0x5064;
+
+/// Represents the instruction set architecture an object file is built for.
+///
+/// Architectures are named after the `target_arch` values of Rust.
+/// `Architecture::Unknown(code)` contains the format-specific machine code of other architectures.
+pub enum Architecture {
+    X86,
+    X86_64,
+    Arm,
+    Aarch64,
+    RiscV32,
+    RiscV64,
+    PowerPc,
+    PowerPc64,
+    Mips,
+    Mips64,
+    S390x,
+    LoongArch64,
+    Unknown(u32),
+}
+
+impl Architecture {
+    /// Returns the architecture of the running process,
+    /// or `None` if it can not be represented (e.g. the x32 ABI of x86_64).
+    pub fn host() -> Option<Self> {
+        #[cfg(target_arch = "x86")]
+        return Some(Self::X86);
+
+        #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+        return Some(Self::X86_64);
+
+        #[cfg(target_arch = "arm")]
+        return Some(Self::Arm);
+
+        #[cfg(all(target_arch = "aarch64", target_pointer_width = "64"))]
+        return Some(Self::Aarch64);
+
+        #[cfg(target_arch = "riscv32")]
+        return Some(Self::RiscV32);
+
+        #[cfg(target_arch = "riscv64")]
+        return Some(Self::RiscV64);
+
+        #[cfg(target_arch = "powerpc")]
+        return Some(Self::PowerPc);
+
+        #[cfg(target_arch = "powerpc64")]
+        return Some(Self::PowerPc64);
+
+        #[cfg(target_arch = "mips")]
+        return Some(Self::Mips);
+
+        #[cfg(target_arch = "mips64")]
+        return Some(Self::Mips64);
+
+        #[cfg(target_arch = "s390x")]
+        return Some(Self::S390x);
+
+        #[cfg(target_arch = "loongarch64")]
+        return Some(Self::LoongArch64);
+
+        #[allow(unreachable_code)]
+        None
+    }
+
+    /// Returns the architecture of the ELF `e_machine` of a file of the 64-bit class or not.
+    ///
+    /// Machines combined with a class they are not defined for (e.g. the x32 ABI of `EM_X86_64`
+    /// in the 32-bit class) are reported as `Architecture::Unknown`.
+    pub(crate) fn from_elf(machine: u16, is_64: bool) -> Self {
+        match (machine, is_64) {
+            (EM_386, false) => Self::X86,
+            (EM_X86_64, true) => Self::X86_64,
+            (EM_ARM, false) => Self::Arm,
+            (EM_AARCH64, true) => Self::Aarch64,
+            (EM_RISCV, false) => Self::RiscV32,
+            (EM_RISCV, true) => Self::RiscV64,
+            (EM_PPC, false) => Self::PowerPc,
+            (EM_PPC64, true) => Self::PowerPc64,
+            (EM_MIPS, false) => Self::Mips,
+            (EM_MIPS, true) => Self::Mips64,
+            (EM_S390, true) => Self::S390x,
+            (EM_LOONGARCH, true) => Self::LoongArch64,
+            (machine, _) => Self::Unknown(u32::from(machine)),
+        }
+    }
+
+    /// Returns the architecture of the Mach-O `cputype`.
+    pub(crate) fn from_macho(cputype: u32) -> Self {
+        match cputype {
+            CPU_TYPE_X86 => Self::X86,
+            it if it == CPU_TYPE_X86 | CPU_ARCH_ABI64 => Self::X86_64,
+            CPU_TYPE_ARM => Self::Arm,
+            it if it == CPU_TYPE_ARM | CPU_ARCH_ABI64 => Self::Aarch64,
+            CPU_TYPE_POWERPC => Self::PowerPc,
+            it if it == CPU_TYPE_POWERPC | CPU_ARCH_ABI64 => Self::PowerPc64,
+            cputype => Self::Unknown(cputype),
+        }
+    }
+
+    /// Returns the architecture of the PE/COFF `Machine`.
+    pub(crate) fn from_pe(machine: u16) -> Self {
+        match machine {
+            IMAGE_FILE_MACHINE_I386 => Self::X86,
+            IMAGE_FILE_MACHINE_AMD64 => Self::X86_64,
+            IMAGE_FILE_MACHINE_ARM | IMAGE_FILE_MACHINE_ARMNT => Self::Arm,
+            IMAGE_FILE_MACHINE_ARM64 => Self::Aarch64,
+            IMAGE_FILE_MACHINE_RISCV32 => Self::RiscV32,
+            IMAGE_FILE_MACHINE_RISCV64 => Self::RiscV64,
+            machine => Self::Unknown(u32::from(machine)),
+        }
+    }
+
+    /// Returns the name of the architecture as the `target_arch` value.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::X86 => "x86",
+            Self::X86_64 => "x86_64",
+            Self::Arm => "arm",
+            Self::Aarch64 => "aarch64",
+            Self::RiscV32 => "riscv32",
+            Self::RiscV64 => "riscv64",
+            Self::PowerPc => "powerpc",
+            Self::PowerPc64 => "powerpc64",
+            Self::Mips => "mips",
+            Self::Mips64 => "mips64",
+            Self::S390x => "s390x",
+            Self::LoongArch64 => "loongarch64",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+}
+
+impl Clone for Architecture {
+    fn clone(&self) -> Self {
+        match self {
+            Self::X86 => Self::X86,
+            Self::X86_64 => Self::X86_64,
+            Self::Arm => Self::Arm,
+            Self::Aarch64 => Self::Aarch64,
+            Self::RiscV32 => Self::RiscV32,
+            Self::RiscV64 => Self::RiscV64,
+            Self::PowerPc => Self::PowerPc,
+            Self::PowerPc64 => Self::PowerPc64,
+            Self::Mips => Self::Mips,
+            Self::Mips64 => Self::Mips64,
+            Self::S390x => Self::S390x,
+            Self::LoongArch64 => Self::LoongArch64,
+            Self::Unknown(code) => Self::Unknown(*code),
+        }
+    }
+}
+
+impl PartialEq for Architecture {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unknown(code), Self::Unknown(other_code)) => code == other_code,
+            (this, other) => mem::discriminant(this) == mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Architecture {}
+
+impl fmt::Debug for Architecture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(code) => f.write_fmt(format_args!("Unknown({:#x})", code)),
+            known => f.write_str(known.name()),
+        }
+    }
+}
+
+impl fmt::Display for Architecture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(code) => f.write_fmt(format_args!("unknown ({:#x})", code)),
+            known => f.write_str(known.name()),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::{error, fmt, path};
+
+use crate::object::{Architecture, Format, Header, ObjectError, OsAbi};
+
+/// Represents an object file that can not be loaded into the running process.
+///
+/// `CompatibilityError::Object(err)` indicates that the header of the file could not be read.
+/// `CompatibilityError::Format(expected, found)` indicates an object file of another platform.
+/// `CompatibilityError::Architecture(expected, found)` indicates that no architecture of the file
+/// matches the running process.
+/// `CompatibilityError::Class(expected, found)` indicates an ELF file of another class,
+/// containing whether the 64-bit class is expected and found.
+/// `CompatibilityError::ByteOrder(expected, found)` indicates an ELF file of another byte order,
+/// containing whether the little-endian byte order is expected and found.
+/// `CompatibilityError::OsAbi(expected, found)` indicates an ELF file of another operating system.
+pub enum CompatibilityError {
+    Object(ObjectError),
+    Format(Format, Format),
+    Class(bool, bool),
+    ByteOrder(bool, bool),
+    Architecture(Architecture, Vec<Architecture>),
+    OsAbi(OsAbi, OsAbi),
+}
+
+impl Clone for CompatibilityError {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Object(err) => Self::Object(err.clone()),
+            Self::Format(expected, found) => Self::Format(expected.clone(), found.clone()),
+            Self::Class(expected, found) => Self::Class(*expected, *found),
+            Self::ByteOrder(expected, found) => Self::ByteOrder(*expected, *found),
+            Self::Architecture(expected, found) => {
+                Self::Architecture(expected.clone(), found.clone())
+            }
+            Self::OsAbi(expected, found) => Self::OsAbi(expected.clone(), found.clone()),
+        }
+    }
+}
+
+impl fmt::Debug for CompatibilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(err) => f.write_fmt(format_args!("Object({:?})", err)),
+            Self::Format(expected, found) => {
+                f.write_fmt(format_args!("Format({:?}, {:?})", expected, found))
+            }
+            Self::Class(expected, found) => {
+                f.write_fmt(format_args!("Class({:?}, {:?})", expected, found))
+            }
+            Self::ByteOrder(expected, found) => {
+                f.write_fmt(format_args!("ByteOrder({:?}, {:?})", expected, found))
+            }
+            Self::Architecture(expected, found) => {
+                f.write_fmt(format_args!("Architecture({:?}, {:?})", expected, found))
+            }
+            Self::OsAbi(expected, found) => {
+                f.write_fmt(format_args!("OsAbi({:?}, {:?})", expected, found))
+            }
+        }
+    }
+}
+
+impl fmt::Display for CompatibilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(err) => fmt::Display::fmt(err, f),
+            Self::Format(expected, found) => f.write_fmt(format_args!(
+                "Object file is incompatible: expected {} format, found {}",
+                expected, found
+            )),
+            Self::Class(expected, found) => f.write_fmt(format_args!(
+                "Object file is incompatible: expected {} class, found {}",
+                class_of(*expected),
+                class_of(*found)
+            )),
+            Self::ByteOrder(expected, found) => f.write_fmt(format_args!(
+                "Object file is incompatible: expected {} byte order, found {}",
+                byte_order_of(*expected),
+                byte_order_of(*found)
+            )),
+            Self::Architecture(expected, found) => {
+                f.write_fmt(format_args!(
+                    "Object file is incompatible: expected {} architecture, found ",
+                    expected
+                ))?;
+
+                for (i, architecture) in found.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    fmt::Display::fmt(architecture, f)?;
+                }
+
+                Ok(())
+            }
+            Self::OsAbi(expected, found) => f.write_fmt(format_args!(
+                "Object file is incompatible: expected {} OS ABI, found {}",
+                expected, found
+            )),
+        }
+    }
+}
+
+impl error::Error for CompatibilityError {}
+
+fn class_of(is_64: bool) -> &'static str {
+    if is_64 {
+        "64-bit"
+    } else {
+        "32-bit"
+    }
+}
+
+fn byte_order_of(is_little: bool) -> &'static str {
+    if is_little {
+        "little-endian"
+    } else {
+        "big-endian"
+    }
+}
+
+impl Header {
+    /// Checks whether the object file can be loaded into the running process
+    /// by comparing its format, architecture, ELF class and byte order and OS ABI
+    /// with the ones of the process.
+    pub fn check_host(&self) -> Result<(), CompatibilityError> {
+        let format = Format::host();
+        if *self.format() != format {
+            return Err(CompatibilityError::Format(format, self.format().clone()));
+        }
+
+        if let Some(architecture) = Architecture::host() {
+            if !self.architectures().contains(&architecture) {
+                return Err(CompatibilityError::Architecture(
+                    architecture,
+                    self.architectures().to_vec(),
+                ));
+            }
+        }
+
+        if let Some(found) = self.is_64() {
+            let expected = cfg!(target_pointer_width = "64");
+            if found != expected {
+                return Err(CompatibilityError::Class(expected, found));
+            }
+        }
+
+        if let Some(found) = self.is_little() {
+            let expected = cfg!(target_endian = "little");
+            if found != expected {
+                return Err(CompatibilityError::ByteOrder(expected, found));
+            }
+        }
+
+        if let (Some(expected), Some(found)) = (OsAbi::host(), self.os_abi()) {
+            if *found != OsAbi::SystemV && *found != expected {
+                return Err(CompatibilityError::OsAbi(expected, found.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the header of the object file specified by `path` and checks
+/// whether it can be loaded into the running process.
+///
+/// # Usage
+///
+/// `check_compatible` is used before `Handle::open` to report a structured error
+/// instead of an opaque platform message when a file of another platform is opened.
+///
+/// ```no_run
+/// use dynlink::{api::Handle, object};
+///
+/// fn main() {
+///     if let Err(err) = object::check_compatible("libsum.so") {
+///         eprintln!("{}", err);
+///         return;
+///     }
+///
+///     unsafe {
+///         let _ = Handle::open("libsum.so");
+///     }
+/// }
+/// ```
+pub fn check_compatible(path: impl AsRef<path::Path>) -> Result<Header, CompatibilityError> {
+    let header = Header::read(path).map_err(CompatibilityError::Object)?;
+    header.check_host()?;
+
+    Ok(header)
+}
@@ -0,0 +1,73 @@
+//! Constants of the ELF specification used by the accessors of this module.
+
+/// Shared object file type of `ElfFile::file_type`.
+pub const ET_DYN: u16 = 3;
+/// Executable file type of `ElfFile::file_type`.
+pub const ET_EXEC: u16 = 2;
+
+/// Loadable segment of `ProgramHeader::kind`.
+pub const PT_LOAD: u32 = 1;
+/// Dynamic linking information segment of `ProgramHeader::kind`.
+pub const PT_DYNAMIC: u32 = 2;
+/// Program interpreter segment of `ProgramHeader::kind`.
+pub const PT_INTERP: u32 = 3;
+/// Auxiliary information segment of `ProgramHeader::kind`.
+pub const PT_NOTE: u32 = 4;
+/// Program header table segment of `ProgramHeader::kind`.
+pub const PT_PHDR: u32 = 6;
+/// Thread-local storage segment of `ProgramHeader::kind`.
+pub const PT_TLS: u32 = 7;
+/// Exception handling frame header segment of `ProgramHeader::kind`.
+pub const PT_GNU_EH_FRAME: u32 = 0x6474_e550;
+/// Stack permissions segment of `ProgramHeader::kind`.
+pub const PT_GNU_STACK: u32 = 0x6474_e551;
+/// Read-only after relocation segment of `ProgramHeader::kind`.
+pub const PT_GNU_RELRO: u32 = 0x6474_e552;
+
+/// Executable segment flag of `ProgramHeader::flags`.
+pub const PF_X: u32 = 1;
+/// Writable segment flag of `ProgramHeader::flags`.
+pub const PF_W: u32 = 2;
+/// Readable segment flag of `ProgramHeader::flags`.
+pub const PF_R: u32 = 4;
+
+/// Local binding of `ElfSymbol::binding`.
+pub const STB_LOCAL: u8 = 0;
+/// Global binding of `ElfSymbol::binding`.
+pub const STB_GLOBAL: u8 = 1;
+/// Weak binding of `ElfSymbol::binding`.
+pub const STB_WEAK: u8 = 2;
+/// Unique binding of `ElfSymbol::binding`, a GNU extension.
+pub const STB_GNU_UNIQUE: u8 = 10;
+
+/// Unspecified type of `ElfSymbol::kind`.
+pub const STT_NOTYPE: u8 = 0;
+/// Data object type of `ElfSymbol::kind`.
+pub const STT_OBJECT: u8 = 1;
+/// Function type of `ElfSymbol::kind`.
+pub const STT_FUNC: u8 = 2;
+/// Section type of `ElfSymbol::kind`.
+pub const STT_SECTION: u8 = 3;
+/// Source file type of `ElfSymbol::kind`.
+pub const STT_FILE: u8 = 4;
+/// Thread-local storage type of `ElfSymbol::kind`.
+pub const STT_TLS: u8 = 6;
+/// Indirect function type of `ElfSymbol::kind`, a GNU extension.
+pub const STT_GNU_IFUNC: u8 = 10;
+
+/// Default visibility of `ElfSymbol::visibility`.
+pub const STV_DEFAULT: u8 = 0;
+/// Hidden visibility of `ElfSymbol::visibility`.
+pub const STV_HIDDEN: u8 = 2;
+/// Protected visibility of `ElfSymbol::visibility`.
+pub const STV_PROTECTED: u8 = 3;
+
+/// Undefined section index of `ElfSymbol::section`.
+pub const SHN_UNDEF: u16 = 0;
+/// Absolute section index of `ElfSymbol::section`.
+pub const SHN_ABS: u16 = 0xfff1;
+
+/// Base version flag of `VersionDefinition::flags`.
+pub const VER_FLG_BASE: u16 = 0x1;
+/// Weak version flag of `VersionDefinition::flags` and `NeededVersion::flags`.
+pub const VER_FLG_WEAK: u16 = 0x2;
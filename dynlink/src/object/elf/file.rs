@@ -0,0 +1,655 @@
+use std::{borrow, collections::HashMap, fmt, fs, path};
+
+use crate::object::{
+    elf::{
+        ElfSymbol, NeededVersion, ProgramHeader, VersionDefinition, VersionNeed, PT_DYNAMIC,
+        PT_LOAD, PT_NOTE, SHN_ABS, STB_GLOBAL, STB_GNU_UNIQUE, STB_WEAK, VER_FLG_BASE,
+    },
+    reader::{self, Reader},
+    Architecture, ObjectError, OsAbi,
+};
+
+const ELFMAG: &[u8] = b"\x7fELF";
+const ELFCLASS32: u8 = 1;
+const ELFCLASS64: u8 = 2;
+const ELFDATA2LSB: u8 = 1;
+const ELFDATA2MSB: u8 = 2;
+
+const SHT_DYNSYM: u32 = 11;
+
+const DT_NULL: u64 = 0;
+const DT_NEEDED: u64 = 1;
+const DT_HASH: u64 = 4;
+const DT_STRTAB: u64 = 5;
+const DT_SYMTAB: u64 = 6;
+const DT_STRSZ: u64 = 10;
+const DT_SONAME: u64 = 14;
+const DT_RPATH: u64 = 15;
+const DT_RUNPATH: u64 = 29;
+const DT_GNU_HASH: u64 = 0x6fff_fef5;
+const DT_VERSYM: u64 = 0x6fff_fff0;
+const DT_VERDEF: u64 = 0x6fff_fffc;
+const DT_VERDEFNUM: u64 = 0x6fff_fffd;
+const DT_VERNEED: u64 = 0x6fff_fffe;
+const DT_VERNEEDNUM: u64 = 0x6fff_ffff;
+
+const NT_GNU_BUILD_ID: u32 = 3;
+
+const VERSYM_HIDDEN: u16 = 0x8000;
+const VERSYM_INDEX: u16 = 0x7fff;
+
+/// Represents an ELF file parsed without loading it.
+///
+/// # Usage
+///
+/// `ElfFile` either borrows the bytes passed to `ElfFile::parse` or owns the bytes read by
+/// `ElfFile::open`. Accessors that read the dynamic section return `ObjectError::Malformed`
+/// when the tables they refer to are out of bounds.
+///
+/// ```no_run
+/// use dynlink::object::elf::ElfFile;
+///
+/// fn main() {
+///     let file = ElfFile::open("libsum.so").unwrap();
+///
+///     println!("{:?} {:?}", file.architecture(), file.soname().unwrap());
+///     for symbol in file.exports().unwrap() {
+///         println!("{} {:?}", symbol.name(), symbol.version());
+///     }
+/// }
+/// ```
+pub struct ElfFile<'data> {
+    data: borrow::Cow<'data, [u8]>,
+    is_64: bool,
+    is_little: bool,
+    os_abi: u8,
+    file_type: u16,
+    machine: u16,
+    program_headers: Vec<ProgramHeader>,
+    dynamic: Vec<(u64, u64)>,
+    dynsym_count: Option<u64>,
+}
+
+impl ElfFile<'static> {
+    /// Reads the ELF file specified by `path`.
+    pub fn open(path: impl AsRef<path::Path>) -> Result<Self, ObjectError> {
+        Self::new(borrow::Cow::Owned(fs::read(path)?))
+    }
+}
+
+impl<'data> ElfFile<'data> {
+    /// Parses the ELF headers and the dynamic section of `data`.
+    pub fn parse(data: &'data [u8]) -> Result<Self, ObjectError> {
+        Self::new(borrow::Cow::Borrowed(data))
+    }
+
+    fn new(data: borrow::Cow<'data, [u8]>) -> Result<Self, ObjectError> {
+        if !data.starts_with(ELFMAG) || data.len() < 16 {
+            return Err(ObjectError::UnknownFormat);
+        }
+
+        let is_64 = match data[4] {
+            ELFCLASS32 => false,
+            ELFCLASS64 => true,
+            _ => return Err(ObjectError::Malformed("ELF class")),
+        };
+
+        let is_little = match data[5] {
+            ELFDATA2LSB => true,
+            ELFDATA2MSB => false,
+            _ => return Err(ObjectError::Malformed("ELF byte order")),
+        };
+
+        let os_abi = data[7];
+
+        let mut file = Self {
+            data,
+            is_64,
+            is_little,
+            os_abi,
+            file_type: 0,
+            machine: 0,
+            program_headers: Vec::new(),
+            dynamic: Vec::new(),
+            dynsym_count: None,
+        };
+
+        file.read_headers()?;
+        Ok(file)
+    }
+
+    fn reader(&self) -> Reader<'_> {
+        Reader::new(&self.data, self.is_little)
+    }
+
+    fn read_headers(&mut self) -> Result<(), ObjectError> {
+        let reader = Reader::new(&self.data, self.is_little);
+        let is_64 = self.is_64;
+
+        let file_type = reader.u16(16, "ELF header")?;
+        let machine = reader.u16(18, "ELF header")?;
+
+        let (phoff, shoff, phentsize, phnum, shentsize, shnum) = if is_64 {
+            (
+                reader.u64(32, "ELF header")?,
+                reader.u64(40, "ELF header")?,
+                reader.u16(54, "ELF header")?,
+                reader.u16(56, "ELF header")?,
+                reader.u16(58, "ELF header")?,
+                reader.u16(60, "ELF header")?,
+            )
+        } else {
+            (
+                u64::from(reader.u32(28, "ELF header")?),
+                u64::from(reader.u32(32, "ELF header")?),
+                reader.u16(42, "ELF header")?,
+                reader.u16(44, "ELF header")?,
+                reader.u16(46, "ELF header")?,
+                reader.u16(48, "ELF header")?,
+            )
+        };
+
+        let mut program_headers = Vec::new();
+        for i in 0..u64::from(phnum) {
+            let phdr = reader::entry(phoff, i, u64::from(phentsize), "program header")?;
+
+            // fields are read at fixed offsets once the whole header is known to be in bounds
+            reader.bytes(phdr, if is_64 { 56 } else { 32 }, "program header")?;
+            let kind = reader.u32(phdr, "program header")?;
+
+            // p_flags follows p_type in 64-bit files but p_memsz in 32-bit ones
+            program_headers.push(if is_64 {
+                ProgramHeader {
+                    kind,
+                    flags: reader.u32(phdr + 4, "program header")?,
+                    offset: reader.u64(phdr + 8, "program header")?,
+                    vaddr: reader.u64(phdr + 16, "program header")?,
+                    filesz: reader.u64(phdr + 32, "program header")?,
+                    memsz: reader.u64(phdr + 40, "program header")?,
+                    align: reader.u64(phdr + 48, "program header")?,
+                }
+            } else {
+                ProgramHeader {
+                    kind,
+                    offset: u64::from(reader.u32(phdr + 4, "program header")?),
+                    vaddr: u64::from(reader.u32(phdr + 8, "program header")?),
+                    filesz: u64::from(reader.u32(phdr + 16, "program header")?),
+                    memsz: u64::from(reader.u32(phdr + 20, "program header")?),
+                    flags: reader.u32(phdr + 24, "program header")?,
+                    align: u64::from(reader.u32(phdr + 28, "program header")?),
+                }
+            });
+        }
+
+        let mut dynamic = Vec::new();
+        if let Some(header) = program_headers.iter().find(|it| it.kind == PT_DYNAMIC) {
+            let entsize = if is_64 { 16 } else { 8 };
+
+            for i in 0..header.filesz / entsize {
+                let entry = reader::entry(header.offset, i, entsize, "dynamic section")?;
+                reader.bytes(entry, entsize, "dynamic section")?;
+
+                let tag = reader.word(entry, is_64, "dynamic section")?;
+                let val = reader.word(entry + entsize / 2, is_64, "dynamic section")?;
+
+                if tag == DT_NULL {
+                    break;
+                }
+
+                dynamic.push((tag, val));
+            }
+        }
+
+        let mut dynsym_count = None;
+        for i in 0..u64::from(shnum) {
+            let shdr = reader::entry(shoff, i, u64::from(shentsize), "section header")?;
+            reader.bytes(shdr, if is_64 { 64 } else { 40 }, "section header")?;
+
+            if reader.u32(shdr + 4, "section header")? != SHT_DYNSYM {
+                continue;
+            }
+
+            let (size, entsize) = if is_64 {
+                (
+                    reader.u64(shdr + 32, "section header")?,
+                    reader.u64(shdr + 56, "section header")?,
+                )
+            } else {
+                (
+                    u64::from(reader.u32(shdr + 20, "section header")?),
+                    u64::from(reader.u32(shdr + 36, "section header")?),
+                )
+            };
+
+            dynsym_count = size.checked_div(entsize);
+        }
+
+        self.file_type = file_type;
+        self.machine = machine;
+        self.program_headers = program_headers;
+        self.dynamic = dynamic;
+        self.dynsym_count = dynsym_count;
+
+        Ok(())
+    }
+
+    /// Checks whether the file is of the 64-bit class.
+    #[inline]
+    pub fn is_64(&self) -> bool {
+        self.is_64
+    }
+
+    /// Checks whether the file is of the little-endian byte order.
+    #[inline]
+    pub fn is_little(&self) -> bool {
+        self.is_little
+    }
+
+    /// Returns the `e_type` of the file, e.g. `ET_DYN` for shared objects.
+    #[inline]
+    pub fn file_type(&self) -> u16 {
+        self.file_type
+    }
+
+    /// Returns the `e_machine` of the file.
+    #[inline]
+    pub fn machine(&self) -> u16 {
+        self.machine
+    }
+
+    /// Returns the architecture the file is built for.
+    pub fn architecture(&self) -> Architecture {
+        Architecture::from_elf(self.machine, self.is_64)
+    }
+
+    /// Returns the OS ABI of the file's `EI_OSABI`.
+    pub fn os_abi(&self) -> OsAbi {
+        OsAbi::from_code(self.os_abi)
+    }
+
+    /// Returns the program headers of the file.
+    #[inline]
+    pub fn program_headers(&self) -> &[ProgramHeader] {
+        &self.program_headers
+    }
+
+    /// Translates the virtual address `vaddr` to the file offset.
+    fn offset_of(&self, vaddr: u64) -> Option<u64> {
+        self.program_headers
+            .iter()
+            .filter(|it| it.kind == PT_LOAD)
+            .find_map(|it| it.offset_of(vaddr))
+    }
+
+    fn dynamic_values(&self, tag: u64) -> impl Iterator<Item = u64> + '_ {
+        self.dynamic
+            .iter()
+            .filter(move |it| it.0 == tag)
+            .map(|it| it.1)
+    }
+
+    fn dynamic_value(&self, tag: u64) -> Option<u64> {
+        self.dynamic_values(tag).next()
+    }
+
+    /// Returns the file offset of the table at the address of `tag`.
+    fn dynamic_table(&self, tag: u64) -> Option<u64> {
+        self.dynamic_value(tag).and_then(|it| self.offset_of(it))
+    }
+
+    /// Returns the string at `offset` of the dynamic string table.
+    fn dynamic_string(&self, offset: u64) -> Result<String, ObjectError> {
+        let strtab = self
+            .dynamic_table(DT_STRTAB)
+            .ok_or(ObjectError::Malformed("dynamic string table"))?;
+
+        if let Some(strsz) = self.dynamic_value(DT_STRSZ) {
+            if offset >= strsz {
+                return Err(ObjectError::Malformed("dynamic string table"));
+            }
+        }
+
+        let bytes = self.reader().string(
+            reader::offset(strtab, offset, "dynamic string table")?,
+            "dynamic string table",
+        )?;
+
+        Ok(String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    /// Returns the `DT_NEEDED` names in declaration order.
+    pub fn needed(&self) -> Result<Vec<String>, ObjectError> {
+        self.dynamic_values(DT_NEEDED)
+            .map(|it| self.dynamic_string(it))
+            .collect()
+    }
+
+    /// Returns the `DT_SONAME` of the file.
+    pub fn soname(&self) -> Result<Option<String>, ObjectError> {
+        self.dynamic_value(DT_SONAME)
+            .map(|it| self.dynamic_string(it))
+            .transpose()
+    }
+
+    /// Returns the `DT_RPATH` of the file.
+    pub fn rpath(&self) -> Result<Option<String>, ObjectError> {
+        self.dynamic_value(DT_RPATH)
+            .map(|it| self.dynamic_string(it))
+            .transpose()
+    }
+
+    /// Returns the `DT_RUNPATH` of the file.
+    pub fn runpath(&self) -> Result<Option<String>, ObjectError> {
+        self.dynamic_value(DT_RUNPATH)
+            .map(|it| self.dynamic_string(it))
+            .transpose()
+    }
+
+    /// Returns the GNU build ID of the file's `NT_GNU_BUILD_ID` note.
+    pub fn build_id(&self) -> Result<Option<&[u8]>, ObjectError> {
+        let reader = self.reader();
+
+        for header in self.program_headers.iter().filter(|it| it.kind == PT_NOTE) {
+            let end = reader::offset(header.offset, header.filesz, "note")?;
+            let mut note = header.offset;
+
+            // namesz, descsz and type, followed by the name and descriptor padded to 4 bytes
+            while reader::offset(note, 12, "note")? <= end {
+                let namesz = u64::from(reader.u32(note, "note")?);
+                let descsz = u64::from(reader.u32(note + 4, "note")?);
+                let kind = reader.u32(note + 8, "note")?;
+
+                let name = note + 12;
+                let desc = reader::offset(name, namesz.next_multiple_of(4), "note")?;
+
+                if kind == NT_GNU_BUILD_ID && reader.bytes(name, namesz, "note")? == b"GNU\0" {
+                    return reader.bytes(desc, descsz, "note").map(Some);
+                }
+
+                note = reader::offset(desc, descsz.next_multiple_of(4), "note")?;
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the number of dynamic symbols, which is taken from the `.dynsym`
+    /// section header or derived from the `DT_HASH` or `DT_GNU_HASH` table.
+    fn symbol_count(&self) -> Result<u64, ObjectError> {
+        if let Some(count) = self.dynsym_count {
+            return Ok(count);
+        }
+
+        let reader = self.reader();
+
+        if let Some(hash) = self.dynamic_table(DT_HASH) {
+            // nbucket, nchain, where nchain equals the number of symbols
+            return reader
+                .u32(reader::offset(hash, 4, "hash table")?, "hash table")
+                .map(u64::from);
+        }
+
+        let Some(gnu_hash) = self.dynamic_table(DT_GNU_HASH) else {
+            return Ok(0);
+        };
+
+        reader.bytes(gnu_hash, 16, "GNU hash table")?;
+
+        let nbuckets = u64::from(reader.u32(gnu_hash, "GNU hash table")?);
+        let symoffset = u64::from(reader.u32(gnu_hash + 4, "GNU hash table")?);
+        let bloom_size = u64::from(reader.u32(gnu_hash + 8, "GNU hash table")?);
+
+        let word = if self.is_64 { 8 } else { 4 };
+        let buckets = reader::entry(gnu_hash + 16, bloom_size, word, "GNU hash table")?;
+
+        let mut last = 0;
+        for i in 0..nbuckets {
+            let bucket = reader::entry(buckets, i, 4, "GNU hash table")?;
+            last = last.max(u64::from(reader.u32(bucket, "GNU hash table")?));
+        }
+
+        if last < symoffset {
+            return Ok(symoffset);
+        }
+
+        // the chain of the last bucket ends with a hash that has the lowest bit set
+        let chains = reader::entry(buckets, nbuckets, 4, "GNU hash table")?;
+        let mut index = last;
+        loop {
+            let chain = reader::entry(chains, index - symoffset, 4, "GNU hash table")?;
+            if reader.u32(chain, "GNU hash table")? & 1 != 0 {
+                break;
+            }
+
+            index += 1;
+        }
+
+        Ok(index + 1)
+    }
+
+    /// Returns the versions defined by the file in its `DT_VERDEF` table,
+    /// including the base version named after the file.
+    pub fn version_definitions(&self) -> Result<Vec<VersionDefinition>, ObjectError> {
+        let Some(mut verdef) = self.dynamic_table(DT_VERDEF) else {
+            return Ok(Vec::new());
+        };
+
+        let reader = self.reader();
+        let count = self.dynamic_value(DT_VERDEFNUM).unwrap_or(0);
+
+        let mut definitions = Vec::new();
+        for _ in 0..count {
+            reader.bytes(verdef, 20, "version definition")?;
+
+            let flags = reader.u16(verdef + 2, "version definition")?;
+            let index = reader.u16(verdef + 4, "version definition")?;
+            let cnt = reader.u16(verdef + 6, "version definition")?;
+            let aux = u64::from(reader.u32(verdef + 12, "version definition")?);
+            let next = u64::from(reader.u32(verdef + 16, "version definition")?);
+
+            // the first auxiliary entry names the version, the rest name its parents
+            let mut names = Vec::new();
+            let mut verdaux = reader::offset(verdef, aux, "version definition")?;
+            for _ in 0..cnt {
+                reader.bytes(verdaux, 8, "version definition")?;
+
+                let name = reader.u32(verdaux, "version definition")?;
+                names.push(self.dynamic_string(u64::from(name))?);
+
+                let next = u64::from(reader.u32(verdaux + 4, "version definition")?);
+                verdaux = reader::offset(verdaux, next, "version definition")?;
+            }
+
+            if names.is_empty() {
+                return Err(ObjectError::Malformed("version definition"));
+            }
+
+            let name = names.remove(0);
+            definitions.push(VersionDefinition {
+                index,
+                flags,
+                name,
+                parents: names,
+            });
+
+            if next == 0 {
+                break;
+            }
+
+            verdef = reader::offset(verdef, next, "version definition")?;
+        }
+
+        Ok(definitions)
+    }
+
+    /// Returns the versions the file requires from its dependencies in its `DT_VERNEED` table.
+    pub fn version_needs(&self) -> Result<Vec<VersionNeed>, ObjectError> {
+        let Some(mut verneed) = self.dynamic_table(DT_VERNEED) else {
+            return Ok(Vec::new());
+        };
+
+        let reader = self.reader();
+        let count = self.dynamic_value(DT_VERNEEDNUM).unwrap_or(0);
+
+        let mut needs = Vec::new();
+        for _ in 0..count {
+            reader.bytes(verneed, 16, "version requirement")?;
+
+            let cnt = reader.u16(verneed + 2, "version requirement")?;
+            let file = reader.u32(verneed + 4, "version requirement")?;
+            let aux = u64::from(reader.u32(verneed + 8, "version requirement")?);
+            let next = u64::from(reader.u32(verneed + 12, "version requirement")?);
+
+            let mut versions = Vec::new();
+            let mut vernaux = reader::offset(verneed, aux, "version requirement")?;
+            for _ in 0..cnt {
+                reader.bytes(vernaux, 16, "version requirement")?;
+
+                let flags = reader.u16(vernaux + 4, "version requirement")?;
+                let index = reader.u16(vernaux + 6, "version requirement")?;
+                let name = reader.u32(vernaux + 8, "version requirement")?;
+
+                versions.push(NeededVersion {
+                    index,
+                    flags,
+                    name: self.dynamic_string(u64::from(name))?,
+                });
+
+                let next = u64::from(reader.u32(vernaux + 12, "version requirement")?);
+                vernaux = reader::offset(vernaux, next, "version requirement")?;
+            }
+
+            needs.push(VersionNeed {
+                file: self.dynamic_string(u64::from(file))?,
+                versions,
+            });
+
+            if next == 0 {
+                break;
+            }
+
+            verneed = reader::offset(verneed, next, "version requirement")?;
+        }
+
+        Ok(needs)
+    }
+
+    /// Returns the names of versions by their index in the version symbol table.
+    fn version_names(&self) -> Result<HashMap<u16, String>, ObjectError> {
+        let mut names = HashMap::new();
+
+        // the base definition shares the global index with unversioned symbols
+        for definition in self.version_definitions()? {
+            if definition.flags & VER_FLG_BASE == 0 {
+                names.insert(definition.index, definition.name);
+            }
+        }
+
+        for need in self.version_needs()? {
+            for version in need.versions {
+                names.insert(version.index, version.name);
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// Returns the entries of the dynamic symbol table, except the null entry.
+    ///
+    /// Symbols of the local and global version indices have no version.
+    pub fn symbols(&self) -> Result<Vec<ElfSymbol>, ObjectError> {
+        let Some(symtab) = self.dynamic_table(DT_SYMTAB) else {
+            return Ok(Vec::new());
+        };
+
+        let reader = self.reader();
+        let entsize = if self.is_64 { 24 } else { 16 };
+
+        let versym = self.dynamic_table(DT_VERSYM);
+        let versions = match versym {
+            Some(_) => self.version_names()?,
+            None => HashMap::new(),
+        };
+
+        let mut symbols = Vec::new();
+        for i in 1..self.symbol_count()? {
+            let sym = reader::entry(symtab, i, entsize, "symbol table")?;
+            reader.bytes(sym, entsize, "symbol table")?;
+
+            let (name, info, other, section, value, size) = if self.is_64 {
+                (
+                    reader.u32(sym, "symbol table")?,
+                    reader.u8(sym + 4, "symbol table")?,
+                    reader.u8(sym + 5, "symbol table")?,
+                    reader.u16(sym + 6, "symbol table")?,
+                    reader.u64(sym + 8, "symbol table")?,
+                    reader.u64(sym + 16, "symbol table")?,
+                )
+            } else {
+                (
+                    reader.u32(sym, "symbol table")?,
+                    reader.u8(sym + 12, "symbol table")?,
+                    reader.u8(sym + 13, "symbol table")?,
+                    reader.u16(sym + 14, "symbol table")?,
+                    u64::from(reader.u32(sym + 4, "symbol table")?),
+                    u64::from(reader.u32(sym + 8, "symbol table")?),
+                )
+            };
+
+            let versym = match versym {
+                Some(versym) => reader.u16(
+                    reader::entry(versym, i, 2, "version symbol table")?,
+                    "version symbol table",
+                )?,
+                None => 0,
+            };
+
+            symbols.push(ElfSymbol {
+                name: self.dynamic_string(u64::from(name))?,
+                value,
+                size,
+                kind: info & 0xf,
+                binding: info >> 4,
+                visibility: other & 0x3,
+                section,
+                version: versions.get(&(versym & VERSYM_INDEX)).cloned(),
+                is_hidden: versym & VERSYM_HIDDEN != 0,
+            });
+        }
+
+        Ok(symbols)
+    }
+
+    /// Returns the symbols the file exports, which are defined global, weak and unique symbols
+    /// except the absolute symbols naming version definitions.
+    pub fn exports(&self) -> Result<Vec<ElfSymbol>, ObjectError> {
+        let mut symbols = self.symbols()?;
+
+        symbols.retain(|it| {
+            it.is_defined()
+                && matches!(it.binding, STB_GLOBAL | STB_WEAK | STB_GNU_UNIQUE)
+                && !(it.section == SHN_ABS && it.version() == Some(it.name()))
+        });
+
+        Ok(symbols)
+    }
+
+    /// Returns the named symbols the file imports from its dependencies.
+    pub fn imports(&self) -> Result<Vec<ElfSymbol>, ObjectError> {
+        let mut symbols = self.symbols()?;
+        symbols.retain(|it| !it.is_defined() && !it.name.is_empty());
+
+        Ok(symbols)
+    }
+}
+
+impl<'data> fmt::Debug for ElfFile<'data> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ElfFile({}, {:#x}, {:?})",
+            self.architecture(),
+            self.file_type,
+            self.program_headers
+        ))
+    }
+}
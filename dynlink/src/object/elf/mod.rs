@@ -0,0 +1,11 @@
+mod consts;
+mod file;
+mod segment;
+mod symbol;
+mod version;
+
+pub use consts::*;
+pub use file::ElfFile;
+pub use segment::ProgramHeader;
+pub use symbol::ElfSymbol;
+pub use version::{NeededVersion, VersionDefinition, VersionNeed};
@@ -0,0 +1,86 @@
+use std::fmt;
+
+/// Represents a program header of an ELF file.
+pub struct ProgramHeader {
+    pub(super) kind: u32,
+    pub(super) flags: u32,
+    pub(super) offset: u64,
+    pub(super) vaddr: u64,
+    pub(super) filesz: u64,
+    pub(super) memsz: u64,
+    pub(super) align: u64,
+}
+
+impl ProgramHeader {
+    /// Returns the `p_type` of the segment, e.g. `PT_LOAD`.
+    #[inline]
+    pub fn kind(&self) -> u32 {
+        self.kind
+    }
+
+    /// Returns the `p_flags` of the segment, a combination of `PF_R`, `PF_W` and `PF_X`.
+    #[inline]
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// Returns the file offset of the segment.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the virtual address of the segment relative to the load base.
+    #[inline]
+    pub fn vaddr(&self) -> u64 {
+        self.vaddr
+    }
+
+    /// Returns the size of the segment in the file.
+    #[inline]
+    pub fn filesz(&self) -> u64 {
+        self.filesz
+    }
+
+    /// Returns the size of the segment in memory.
+    #[inline]
+    pub fn memsz(&self) -> u64 {
+        self.memsz
+    }
+
+    /// Returns the alignment of the segment.
+    #[inline]
+    pub fn align(&self) -> u64 {
+        self.align
+    }
+
+    /// Translates the virtual address `vaddr` to the file offset if the segment contains it.
+    pub(super) fn offset_of(&self, vaddr: u64) -> Option<u64> {
+        (vaddr >= self.vaddr && vaddr - self.vaddr < self.filesz)
+            .then(|| self.offset.checked_add(vaddr - self.vaddr))
+            .flatten()
+    }
+}
+
+impl Clone for ProgramHeader {
+    fn clone(&self) -> Self {
+        Self {
+            kind: self.kind,
+            flags: self.flags,
+            offset: self.offset,
+            vaddr: self.vaddr,
+            filesz: self.filesz,
+            memsz: self.memsz,
+            align: self.align,
+        }
+    }
+}
+
+impl fmt::Debug for ProgramHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ProgramHeader({:#x}, {:#x}, {:#x}, {:#x}, {:#x}, {:#x}, {:#x})",
+            self.kind, self.flags, self.offset, self.vaddr, self.filesz, self.memsz, self.align
+        ))
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt;
+
+use crate::object::elf::{SHN_UNDEF, STB_WEAK};
+
+/// Represents an entry of the dynamic symbol table of an ELF file.
+pub struct ElfSymbol {
+    pub(super) name: String,
+    pub(super) value: u64,
+    pub(super) size: u64,
+    pub(super) kind: u8,
+    pub(super) binding: u8,
+    pub(super) visibility: u8,
+    pub(super) section: u16,
+    pub(super) version: Option<String>,
+    pub(super) is_hidden: bool,
+}
+
+impl ElfSymbol {
+    /// Returns the name of the symbol.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value of the symbol, usually its address relative to the load base.
+    #[inline]
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Returns the size of the symbol.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the type of the symbol, e.g. `STT_FUNC`.
+    #[inline]
+    pub fn kind(&self) -> u8 {
+        self.kind
+    }
+
+    /// Returns the binding of the symbol, e.g. `STB_GLOBAL`.
+    #[inline]
+    pub fn binding(&self) -> u8 {
+        self.binding
+    }
+
+    /// Returns the visibility of the symbol, e.g. `STV_DEFAULT`.
+    #[inline]
+    pub fn visibility(&self) -> u8 {
+        self.visibility
+    }
+
+    /// Returns the index of the section the symbol is defined in.
+    #[inline]
+    pub fn section(&self) -> u16 {
+        self.section
+    }
+
+    /// Returns the name of the version the symbol is defined with or required in.
+    #[inline]
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Checks whether the version of the symbol is hidden, so it is not the default
+    /// version used by the static linker (`name@version` rather than `name@@version`).
+    #[inline]
+    pub fn is_hidden(&self) -> bool {
+        self.is_hidden
+    }
+
+    /// Checks whether the symbol is defined by the file.
+    #[inline]
+    pub fn is_defined(&self) -> bool {
+        self.section != SHN_UNDEF
+    }
+
+    /// Checks whether the symbol is weak, so it may remain unresolved.
+    #[inline]
+    pub fn is_weak(&self) -> bool {
+        self.binding == STB_WEAK
+    }
+}
+
+impl Clone for ElfSymbol {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            value: self.value,
+            size: self.size,
+            kind: self.kind,
+            binding: self.binding,
+            visibility: self.visibility,
+            section: self.section,
+            version: self.version.clone(),
+            is_hidden: self.is_hidden,
+        }
+    }
+}
+
+impl fmt::Debug for ElfSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.version, self.is_hidden) {
+            (Some(version), true) => f.write_fmt(format_args!(
+                "ElfSymbol({}@{}, {:#x})",
+                self.name, version, self.value
+            )),
+            (Some(version), false) => f.write_fmt(format_args!(
+                "ElfSymbol({}@@{}, {:#x})",
+                self.name, version, self.value
+            )),
+            (None, _) => f.write_fmt(format_args!("ElfSymbol({}, {:#x})", self.name, self.value)),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+
+/// Represents a version defined by an ELF file in its `DT_VERDEF` table.
+pub struct VersionDefinition {
+    pub(super) index: u16,
+    pub(super) flags: u16,
+    pub(super) name: String,
+    pub(super) parents: Vec<String>,
+}
+
+impl VersionDefinition {
+    /// Returns the index of the version referenced by the version symbol table.
+    #[inline]
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the flags of the version, e.g. `VER_FLG_BASE` for the file's own name.
+    #[inline]
+    pub fn flags(&self) -> u16 {
+        self.flags
+    }
+
+    /// Returns the name of the version.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the names of the versions this version inherits from.
+    #[inline]
+    pub fn parents(&self) -> &[String] {
+        &self.parents
+    }
+}
+
+impl Clone for VersionDefinition {
+    fn clone(&self) -> Self {
+        Self {
+            index: self.index,
+            flags: self.flags,
+            name: self.name.clone(),
+            parents: self.parents.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for VersionDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "VersionDefinition({}, {:?}, {:?})",
+            self.index, self.name, self.parents
+        ))
+    }
+}
+
+/// Represents a version required from a dependency.
+pub struct NeededVersion {
+    pub(super) index: u16,
+    pub(super) flags: u16,
+    pub(super) name: String,
+}
+
+impl NeededVersion {
+    /// Returns the index of the version referenced by the version symbol table.
+    #[inline]
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the flags of the version, e.g. `VER_FLG_WEAK`.
+    #[inline]
+    pub fn flags(&self) -> u16 {
+        self.flags
+    }
+
+    /// Returns the name of the version.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Clone for NeededVersion {
+    fn clone(&self) -> Self {
+        Self {
+            index: self.index,
+            flags: self.flags,
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for NeededVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "NeededVersion({}, {:?})",
+            self.index, self.name
+        ))
+    }
+}
+
+/// Represents the versions an ELF file requires from a dependency in its `DT_VERNEED` table.
+pub struct VersionNeed {
+    pub(super) file: String,
+    pub(super) versions: Vec<NeededVersion>,
+}
+
+impl VersionNeed {
+    /// Returns the name of the dependency as listed in `DT_NEEDED`.
+    #[inline]
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Returns the versions required from the dependency.
+    #[inline]
+    pub fn versions(&self) -> &[NeededVersion] {
+        &self.versions
+    }
+}
+
+impl Clone for VersionNeed {
+    fn clone(&self) -> Self {
+        Self {
+            file: self.file.clone(),
+            versions: self.versions.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for VersionNeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "VersionNeed({:?}, {:?})",
+            self.file, self.versions
+        ))
+    }
+}
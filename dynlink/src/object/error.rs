@@ -0,0 +1,56 @@
+use std::{error, fmt, io};
+
+/// Represents an error that occurred during reading of an object file.
+///
+/// `ObjectError::Io(kind)` indicates that the file could not be read.
+/// `ObjectError::UnknownFormat` indicates that the file is not an object file of a supported format.
+/// `ObjectError::Malformed(reason)` indicates that the file is truncated or has inconsistent headers.
+pub enum ObjectError {
+    Io(io::ErrorKind),
+    UnknownFormat,
+    Malformed(&'static str),
+}
+
+impl Clone for ObjectError {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Io(kind) => Self::Io(*kind),
+            Self::UnknownFormat => Self::UnknownFormat,
+            Self::Malformed(reason) => Self::Malformed(reason),
+        }
+    }
+}
+
+impl fmt::Debug for ObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(kind) => f.write_fmt(format_args!("Io({:?})", kind)),
+            Self::UnknownFormat => f.write_str("UnknownFormat"),
+            Self::Malformed(reason) => f.write_fmt(format_args!("Malformed({:?})", reason)),
+        }
+    }
+}
+
+impl fmt::Display for ObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(kind) => f.write_fmt(format_args!(
+                "Error occurred reading object: {}",
+                io::Error::from(*kind)
+            )),
+            Self::UnknownFormat => f.write_str("Error occurred reading object: unknown format"),
+            Self::Malformed(reason) => f.write_fmt(format_args!(
+                "Error occurred reading object: malformed {}",
+                reason
+            )),
+        }
+    }
+}
+
+impl error::Error for ObjectError {}
+
+impl From<io::Error> for ObjectError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err.kind())
+    }
+}
@@ -0,0 +1,101 @@
+use std::{fmt, mem};
+
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+const PE_MAGIC: &[u8] = b"MZ";
+
+const MH_MAGIC: u32 = 0xfeed_face;
+const MH_MAGIC_64: u32 = 0xfeed_facf;
+const FAT_MAGIC: u32 = 0xcafe_babe;
+const FAT_MAGIC_64: u32 = 0xcafe_babf;
+
+/// Represents the format of an object file.
+///
+/// `Format::Elf` is used by Linux and other unix platforms, `Format::MachO` by macOS and iOS,
+/// including fat (universal) files, and `Format::Pe` by Windows.
+pub enum Format {
+    Elf,
+    MachO,
+    Pe,
+}
+
+impl Format {
+    /// Returns the format of shared object files loaded by the running process.
+    pub fn host() -> Self {
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        return Self::MachO;
+
+        #[cfg(target_os = "windows")]
+        return Self::Pe;
+
+        #[cfg(not(any(target_os = "macos", target_os = "ios", target_os = "windows")))]
+        return Self::Elf;
+    }
+
+    /// Detects the format of an object file by the magic bytes of its `data`.
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        if data.starts_with(ELF_MAGIC) {
+            return Some(Self::Elf);
+        }
+
+        if data.starts_with(PE_MAGIC) {
+            return Some(Self::Pe);
+        }
+
+        let magic = u32::from_be_bytes(data.get(..4)?.try_into().unwrap());
+        match magic {
+            MH_MAGIC | MH_MAGIC_64 => Some(Self::MachO),
+            it if it.swap_bytes() == MH_MAGIC || it.swap_bytes() == MH_MAGIC_64 => {
+                Some(Self::MachO)
+            }
+            // Java class files share the fat magic, but have a version instead of a small count
+            FAT_MAGIC | FAT_MAGIC_64 => {
+                let nfat_arch = u32::from_be_bytes(data.get(4..8)?.try_into().unwrap());
+                (nfat_arch < 45).then_some(Self::MachO)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the format.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Elf => "ELF",
+            Self::MachO => "Mach-O",
+            Self::Pe => "PE",
+        }
+    }
+}
+
+impl Clone for Format {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Elf => Self::Elf,
+            Self::MachO => Self::MachO,
+            Self::Pe => Self::Pe,
+        }
+    }
+}
+
+impl PartialEq for Format {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+
+impl Eq for Format {}
+
+impl fmt::Debug for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Elf => f.write_str("Elf"),
+            Self::MachO => f.write_str("MachO"),
+            Self::Pe => f.write_str("Pe"),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
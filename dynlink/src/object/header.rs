@@ -0,0 +1,292 @@
+use std::{fmt, fs, io::Read, path};
+
+use crate::object::{reader::Reader, Architecture, Format, ObjectError};
+
+/// Size of the leading part of a file that is enough for reading its header.
+const HEADER_READ_SIZE: u64 = 64 * 1024;
+
+const ELFCLASS32: u8 = 1;
+const ELFCLASS64: u8 = 2;
+const ELFDATA2LSB: u8 = 1;
+const ELFDATA2MSB: u8 = 2;
+
+const MH_MAGIC: u32 = 0xfeed_face;
+const MH_MAGIC_64: u32 = 0xfeed_facf;
+const FAT_MAGIC_64: u32 = 0xcafe_babf;
+
+/// Represents the ELF OS ABI an object file is built for.
+///
+/// `OsAbi::SystemV` is the generic ABI that is loadable on any ELF platform.
+/// `OsAbi::Other(code)` contains the `EI_OSABI` value of other ABIs.
+pub enum OsAbi {
+    SystemV,
+    NetBsd,
+    Linux,
+    Solaris,
+    FreeBsd,
+    OpenBsd,
+    Other(u8),
+}
+
+impl OsAbi {
+    /// Returns the OS ABI of the running process, or `None` if it does not use ELF.
+    pub fn host() -> Option<Self> {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        return Some(Self::Linux);
+
+        #[cfg(target_os = "netbsd")]
+        return Some(Self::NetBsd);
+
+        #[cfg(any(target_os = "solaris", target_os = "illumos"))]
+        return Some(Self::Solaris);
+
+        #[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+        return Some(Self::FreeBsd);
+
+        #[cfg(target_os = "openbsd")]
+        return Some(Self::OpenBsd);
+
+        #[allow(unreachable_code)]
+        None
+    }
+
+    pub(crate) fn from_code(code: u8) -> Self {
+        match code {
+            0 => Self::SystemV,
+            2 => Self::NetBsd,
+            3 => Self::Linux,
+            6 => Self::Solaris,
+            9 => Self::FreeBsd,
+            12 => Self::OpenBsd,
+            code => Self::Other(code),
+        }
+    }
+
+    /// Returns the `EI_OSABI` value of the OS ABI.
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::SystemV => 0,
+            Self::NetBsd => 2,
+            Self::Linux => 3,
+            Self::Solaris => 6,
+            Self::FreeBsd => 9,
+            Self::OpenBsd => 12,
+            Self::Other(code) => *code,
+        }
+    }
+}
+
+impl Clone for OsAbi {
+    fn clone(&self) -> Self {
+        Self::from_code(self.code())
+    }
+}
+
+impl PartialEq for OsAbi {
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for OsAbi {}
+
+impl fmt::Debug for OsAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SystemV => f.write_str("SystemV"),
+            Self::NetBsd => f.write_str("NetBsd"),
+            Self::Linux => f.write_str("Linux"),
+            Self::Solaris => f.write_str("Solaris"),
+            Self::FreeBsd => f.write_str("FreeBsd"),
+            Self::OpenBsd => f.write_str("OpenBsd"),
+            Self::Other(code) => f.write_fmt(format_args!("Other({})", code)),
+        }
+    }
+}
+
+impl fmt::Display for OsAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SystemV => f.write_str("System V"),
+            Self::NetBsd => f.write_str("NetBSD"),
+            Self::Linux => f.write_str("Linux"),
+            Self::Solaris => f.write_str("Solaris"),
+            Self::FreeBsd => f.write_str("FreeBSD"),
+            Self::OpenBsd => f.write_str("OpenBSD"),
+            Self::Other(code) => f.write_fmt(format_args!("unknown ({})", code)),
+        }
+    }
+}
+
+/// Represents the format and target of an object file read from its header.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink::object::{Architecture, Format, Header};
+///
+/// fn main() {
+///     let header = Header::read("libsum.so").expect("libsum header was not read");
+///
+///     assert_eq!(Format::Elf, *header.format());
+///     assert_eq!(&[Architecture::X86_64], header.architectures());
+/// }
+/// ```
+pub struct Header {
+    format: Format,
+    architectures: Vec<Architecture>,
+    os_abi: Option<OsAbi>,
+    is_64: Option<bool>,
+    is_little: Option<bool>,
+}
+
+impl Header {
+    /// Reads the header of the object file specified by `path`.
+    pub fn read(path: impl AsRef<path::Path>) -> Result<Self, ObjectError> {
+        let mut data = Vec::new();
+        fs::File::open(path)?
+            .take(HEADER_READ_SIZE)
+            .read_to_end(&mut data)?;
+
+        Self::parse(&data)
+    }
+
+    /// Parses the header of an object file from the leading bytes of its `data`.
+    pub fn parse(data: &[u8]) -> Result<Self, ObjectError> {
+        match Format::detect(data) {
+            Some(Format::Elf) => Self::parse_elf(data),
+            Some(Format::MachO) => Self::parse_macho(data),
+            Some(Format::Pe) => Self::parse_pe(data),
+            None => Err(ObjectError::UnknownFormat),
+        }
+    }
+
+    fn parse_elf(data: &[u8]) -> Result<Self, ObjectError> {
+        let ident = data.get(..16).ok_or(ObjectError::Malformed("ELF header"))?;
+
+        let is_64 = match ident[4] {
+            ELFCLASS32 => false,
+            ELFCLASS64 => true,
+            _ => return Err(ObjectError::Malformed("ELF class")),
+        };
+
+        let is_little = match ident[5] {
+            ELFDATA2LSB => true,
+            ELFDATA2MSB => false,
+            _ => return Err(ObjectError::Malformed("ELF byte order")),
+        };
+
+        let machine = Reader::new(data, is_little).u16(18, "ELF header")?;
+
+        Ok(Self {
+            format: Format::Elf,
+            architectures: vec![Architecture::from_elf(machine, is_64)],
+            os_abi: Some(OsAbi::from_code(ident[7])),
+            is_64: Some(is_64),
+            is_little: Some(is_little),
+        })
+    }
+
+    fn parse_macho(data: &[u8]) -> Result<Self, ObjectError> {
+        let magic = Reader::new(data, false).u32(0, "Mach-O header")?;
+
+        let architectures = if magic == MH_MAGIC || magic == MH_MAGIC_64 {
+            vec![Architecture::from_macho(
+                Reader::new(data, false).u32(4, "Mach-O header")?,
+            )]
+        } else if magic.swap_bytes() == MH_MAGIC || magic.swap_bytes() == MH_MAGIC_64 {
+            vec![Architecture::from_macho(
+                Reader::new(data, true).u32(4, "Mach-O header")?,
+            )]
+        } else {
+            // fat headers are always big-endian
+            let reader = Reader::new(data, false);
+            let nfat_arch = u64::from(reader.u32(4, "fat header")?);
+            let entsize = if magic == FAT_MAGIC_64 { 32 } else { 20 };
+
+            (0..nfat_arch)
+                .map(|i| reader.u32(8 + i * entsize, "fat header"))
+                .map(|it| it.map(Architecture::from_macho))
+                .collect::<Result<_, _>>()?
+        };
+
+        Ok(Self {
+            format: Format::MachO,
+            architectures,
+            os_abi: None,
+            is_64: None,
+            is_little: None,
+        })
+    }
+
+    fn parse_pe(data: &[u8]) -> Result<Self, ObjectError> {
+        let reader = Reader::new(data, true);
+        let pe = u64::from(reader.u32(0x3c, "DOS header")?);
+
+        if reader.bytes(pe, 4, "PE header")? != b"PE\0\0" {
+            return Err(ObjectError::Malformed("PE header"));
+        }
+
+        let machine = reader.u16(pe + 4, "PE header")?;
+
+        Ok(Self {
+            format: Format::Pe,
+            architectures: vec![Architecture::from_pe(machine)],
+            os_abi: None,
+            is_64: None,
+            is_little: None,
+        })
+    }
+
+    /// Returns the format of the object file.
+    #[inline]
+    pub fn format(&self) -> &Format {
+        &self.format
+    }
+
+    /// Returns the architectures of the object file,
+    /// where fat Mach-O files contain more than one.
+    #[inline]
+    pub fn architectures(&self) -> &[Architecture] {
+        &self.architectures
+    }
+
+    /// Returns the OS ABI of an ELF object file.
+    #[inline]
+    pub fn os_abi(&self) -> Option<&OsAbi> {
+        self.os_abi.as_ref()
+    }
+
+    /// Checks whether an ELF object file is of the 64-bit class.
+    #[inline]
+    pub fn is_64(&self) -> Option<bool> {
+        self.is_64
+    }
+
+    /// Checks whether an ELF object file is of the little-endian byte order.
+    #[inline]
+    pub fn is_little(&self) -> Option<bool> {
+        self.is_little
+    }
+}
+
+impl Clone for Header {
+    fn clone(&self) -> Self {
+        Self {
+            format: self.format.clone(),
+            architectures: self.architectures.clone(),
+            os_abi: self.os_abi.clone(),
+            is_64: self.is_64,
+            is_little: self.is_little,
+        }
+    }
+}
+
+impl fmt::Debug for Header {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Header({:?}, {:?}, {:?}, {:?}, {:?})",
+            self.format, self.architectures, self.os_abi, self.is_64, self.is_little
+        ))
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+
+/// Represents the identifier a toolchain stamps into an object file to match it with its
+/// debug information.
+///
+/// `BuildId::Gnu(bytes)` contains the `NT_GNU_BUILD_ID` note of an ELF file.
+/// `BuildId::Uuid(uuid)` contains the `LC_UUID` of a Mach-O file.
+/// `BuildId::Pdb(guid, age)` contains the GUID and age of the PDB file of a PE file.
+///
+/// `Display` prints the identifier the way debug symbol servers key it, i.e. lowercase hex for
+/// GNU build IDs, a hyphenated uppercase UUID and the uppercase GUID followed by the age.
+pub enum BuildId {
+    Gnu(Vec<u8>),
+    Uuid([u8; 16]),
+    Pdb([u8; 16], u32),
+}
+
+impl Clone for BuildId {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Gnu(bytes) => Self::Gnu(bytes.clone()),
+            Self::Uuid(uuid) => Self::Uuid(*uuid),
+            Self::Pdb(guid, age) => Self::Pdb(*guid, *age),
+        }
+    }
+}
+
+impl PartialEq for BuildId {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Gnu(bytes), Self::Gnu(other)) => bytes == other,
+            (Self::Uuid(uuid), Self::Uuid(other)) => uuid == other,
+            (Self::Pdb(guid, age), Self::Pdb(other_guid, other_age)) => {
+                guid == other_guid && age == other_age
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for BuildId {}
+
+impl fmt::Debug for BuildId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gnu(_) => f.write_fmt(format_args!("Gnu({})", self)),
+            Self::Uuid(_) => f.write_fmt(format_args!("Uuid({})", self)),
+            Self::Pdb(..) => f.write_fmt(format_args!("Pdb({})", self)),
+        }
+    }
+}
+
+impl fmt::Display for BuildId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gnu(bytes) => bytes
+                .iter()
+                .try_for_each(|it| f.write_fmt(format_args!("{:02x}", it))),
+
+            Self::Uuid(uuid) => uuid.iter().enumerate().try_for_each(|(i, it)| {
+                if matches!(i, 4 | 6 | 8 | 10) {
+                    f.write_str("-")?;
+                }
+
+                f.write_fmt(format_args!("{:02X}", it))
+            }),
+
+            // the first three fields of the GUID are stored little-endian
+            Self::Pdb(guid, age) => {
+                let data1 = u32::from_le_bytes(guid[..4].try_into().unwrap());
+                let data2 = u16::from_le_bytes(guid[4..6].try_into().unwrap());
+                let data3 = u16::from_le_bytes(guid[6..8].try_into().unwrap());
+
+                f.write_fmt(format_args!("{:08X}{:04X}{:04X}", data1, data2, data3))?;
+                guid[8..]
+                    .iter()
+                    .try_for_each(|it| f.write_fmt(format_args!("{:02X}", it)))?;
+                f.write_fmt(format_args!("{:X}", age))
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::{fmt, fs, path};
+
+use crate::object::{
+    elf::ElfFile,
+    macho::{FatFile, MachOFile},
+    pe::PeFile,
+    Architecture, BuildId, Format, ObjectError,
+};
+
+/// Represents format-neutral information about a shared object file of any supported format.
+///
+/// # Usage
+///
+/// `ObjectInfo` detects the format by the magic bytes and answers what the file exports,
+/// what it depends on, what architecture it is built for and what identifies it,
+/// regardless of the platform the process runs on.
+///
+/// ```no_run
+/// use dynlink::object::ObjectInfo;
+///
+/// fn main() {
+///     for path in ["libsum.so", "libsum.dylib", "sum.dll"] {
+///         let info = ObjectInfo::open(path).unwrap();
+///
+///         println!("{} {} {:?}", info.format(), info.architecture(), info.name());
+///         println!("{:?} {:?}", info.exports(), info.dependencies());
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// The identity name is the `DT_SONAME` of ELF files, the install name of Mach-O files
+/// and the export directory name of PE files.
+///
+/// Mach-O export names have the leading underscore of C symbols removed, so the same symbol
+/// has the same name in every format.
+///
+/// Fat Mach-O files are described by the file matching the running process,
+/// or by their first file if none matches, while `ObjectInfo::architectures` lists all of them.
+pub struct ObjectInfo {
+    format: Format,
+    architecture: Architecture,
+    architectures: Vec<Architecture>,
+    name: Option<String>,
+    build_id: Option<BuildId>,
+    exports: Vec<String>,
+    dependencies: Vec<String>,
+}
+
+impl ObjectInfo {
+    /// Reads the object file specified by `path`.
+    pub fn open(path: impl AsRef<path::Path>) -> Result<Self, ObjectError> {
+        Self::parse(&fs::read(path)?)
+    }
+
+    /// Parses the object file of `data`, detecting its format.
+    pub fn parse(data: &[u8]) -> Result<Self, ObjectError> {
+        match Format::detect(data) {
+            Some(Format::Elf) => Self::from_elf(&ElfFile::parse(data)?),
+            Some(Format::Pe) => Self::from_pe(&PeFile::parse(data)?),
+            Some(Format::MachO) => match MachOFile::parse(data) {
+                Err(ObjectError::UnknownFormat) => Self::from_fat(&FatFile::parse(data)?),
+                file => Self::from_macho(&file?),
+            },
+            None => Err(ObjectError::UnknownFormat),
+        }
+    }
+
+    fn from_elf(file: &ElfFile<'_>) -> Result<Self, ObjectError> {
+        let exports = file
+            .exports()?
+            .iter()
+            .map(|it| it.name().to_owned())
+            .collect();
+
+        Ok(Self {
+            format: Format::Elf,
+            architecture: file.architecture(),
+            architectures: vec![file.architecture()],
+            name: file.soname()?,
+            build_id: file.build_id()?.map(|it| BuildId::Gnu(it.to_vec())),
+            exports,
+            dependencies: file.needed()?,
+        })
+    }
+
+    fn from_pe(file: &PeFile<'_>) -> Result<Self, ObjectError> {
+        let exports = file
+            .exports()?
+            .into_iter()
+            .filter_map(|it| it.name().map(str::to_owned))
+            .collect();
+
+        let dependencies = file
+            .imports()?
+            .iter()
+            .map(|it| it.dll().to_owned())
+            .collect();
+
+        Ok(Self {
+            format: Format::Pe,
+            architecture: file.architecture(),
+            architectures: vec![file.architecture()],
+            name: file.dll_name()?,
+            build_id: file
+                .codeview()?
+                .map(|it| BuildId::Pdb(*it.guid(), it.age())),
+            exports,
+            dependencies,
+        })
+    }
+
+    fn from_macho(file: &MachOFile<'_>) -> Result<Self, ObjectError> {
+        let exports = file
+            .exports()?
+            .iter()
+            .map(|it| it.name().strip_prefix('_').unwrap_or(it.name()).to_owned())
+            .collect();
+
+        let dependencies = file
+            .dependencies()
+            .iter()
+            .map(|it| it.name().to_owned())
+            .collect();
+
+        Ok(Self {
+            format: Format::MachO,
+            architecture: file.architecture(),
+            architectures: vec![file.architecture()],
+            name: file.install_name().map(|it| it.name().to_owned()),
+            build_id: file.uuid().map(|it| BuildId::Uuid(*it)),
+            exports,
+            dependencies,
+        })
+    }
+
+    fn from_fat(fat: &FatFile<'_>) -> Result<Self, ObjectError> {
+        let file = match Architecture::host().and_then(|it| fat.file(&it)) {
+            Some(file) => file?,
+            None => fat
+                .files()?
+                .into_iter()
+                .next()
+                .ok_or(ObjectError::Malformed("fat header"))?,
+        };
+
+        let mut info = Self::from_macho(&file)?;
+        info.architectures = fat.arches().iter().map(|it| it.architecture()).collect();
+
+        Ok(info)
+    }
+
+    /// Returns the format of the object file.
+    #[inline]
+    pub fn format(&self) -> &Format {
+        &self.format
+    }
+
+    /// Returns the architecture the described object file is built for.
+    #[inline]
+    pub fn architecture(&self) -> &Architecture {
+        &self.architecture
+    }
+
+    /// Returns the architectures of the object file, which are several for fat Mach-O files.
+    #[inline]
+    pub fn architectures(&self) -> &[Architecture] {
+        &self.architectures
+    }
+
+    /// Returns the name the object file identifies itself with.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the build ID, UUID or PDB identifier of the object file.
+    #[inline]
+    pub fn build_id(&self) -> Option<&BuildId> {
+        self.build_id.as_ref()
+    }
+
+    /// Returns the names of the symbols the object file exports.
+    #[inline]
+    pub fn exports(&self) -> &[String] {
+        &self.exports
+    }
+
+    /// Returns the names of the shared object files the object file depends on.
+    #[inline]
+    pub fn dependencies(&self) -> &[String] {
+        &self.dependencies
+    }
+}
+
+impl Clone for ObjectInfo {
+    fn clone(&self) -> Self {
+        Self {
+            format: self.format.clone(),
+            architecture: self.architecture.clone(),
+            architectures: self.architectures.clone(),
+            name: self.name.clone(),
+            build_id: self.build_id.clone(),
+            exports: self.exports.clone(),
+            dependencies: self.dependencies.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for ObjectInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ObjectInfo({}, {}, {:?}, {:?}, {:?}, {:?})",
+            self.format,
+            self.architecture,
+            self.name,
+            self.build_id,
+            self.exports,
+            self.dependencies
+        ))
+    }
+}
@@ -0,0 +1,41 @@
+//! Constants of the Mach-O format used by the accessors of this module.
+
+/// Executable file type of `MachOFile::file_type`.
+pub const MH_EXECUTE: u32 = 0x2;
+/// Dynamic library file type of `MachOFile::file_type`.
+pub const MH_DYLIB: u32 = 0x6;
+/// Bundle file type of `MachOFile::file_type`.
+pub const MH_BUNDLE: u32 = 0x8;
+
+/// Two-level namespace flag of `MachOFile::flags`.
+pub const MH_TWOLEVEL: u32 = 0x80;
+/// Application extension safe flag of `MachOFile::flags`.
+pub const MH_APP_EXTENSION_SAFE: u32 = 0x0200_0000;
+
+/// Dependency load command of `Dylib::command`.
+pub const LC_LOAD_DYLIB: u32 = 0xc;
+/// Identification load command of `MachOFile::install_name`.
+pub const LC_ID_DYLIB: u32 = 0xd;
+/// Lazily loaded dependency load command of `Dylib::command`.
+pub const LC_LAZY_LOAD_DYLIB: u32 = 0x20;
+/// Weak dependency load command of `Dylib::command`, which may be missing at runtime.
+pub const LC_LOAD_WEAK_DYLIB: u32 = 0x8000_0018;
+/// Re-exported dependency load command of `Dylib::command`.
+pub const LC_REEXPORT_DYLIB: u32 = 0x8000_001f;
+/// Upward dependency load command of `Dylib::command`.
+pub const LC_LOAD_UPWARD_DYLIB: u32 = 0x8000_0023;
+
+/// Kind mask of `MachOExport::flags`.
+pub const EXPORT_SYMBOL_FLAGS_KIND_MASK: u64 = 0x03;
+/// Regular kind of `MachOExport::flags`.
+pub const EXPORT_SYMBOL_FLAGS_KIND_REGULAR: u64 = 0x00;
+/// Thread-local kind of `MachOExport::flags`.
+pub const EXPORT_SYMBOL_FLAGS_KIND_THREAD_LOCAL: u64 = 0x01;
+/// Absolute kind of `MachOExport::flags`.
+pub const EXPORT_SYMBOL_FLAGS_KIND_ABSOLUTE: u64 = 0x02;
+/// Weak definition flag of `MachOExport::flags`.
+pub const EXPORT_SYMBOL_FLAGS_WEAK_DEFINITION: u64 = 0x04;
+/// Re-export flag of `MachOExport::flags`.
+pub const EXPORT_SYMBOL_FLAGS_REEXPORT: u64 = 0x08;
+/// Stub and resolver flag of `MachOExport::flags`.
+pub const EXPORT_SYMBOL_FLAGS_STUB_AND_RESOLVER: u64 = 0x10;
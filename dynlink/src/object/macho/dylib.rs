@@ -0,0 +1,117 @@
+use std::fmt;
+
+use crate::object::macho::LC_LOAD_WEAK_DYLIB;
+
+/// Represents a dylib version encoded as `xxxx.yy.zz` in 32 bits.
+pub struct DylibVersion(pub u32);
+
+impl DylibVersion {
+    /// Returns the major version.
+    #[inline]
+    pub fn major(&self) -> u16 {
+        (self.0 >> 16) as u16
+    }
+
+    /// Returns the minor version.
+    #[inline]
+    pub fn minor(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Returns the patch version.
+    #[inline]
+    pub fn patch(&self) -> u8 {
+        self.0 as u8
+    }
+}
+
+impl Clone for DylibVersion {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl PartialEq for DylibVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for DylibVersion {}
+
+impl fmt::Debug for DylibVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("DylibVersion({})", self))
+    }
+}
+
+impl fmt::Display for DylibVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "{}.{}.{}",
+            self.major(),
+            self.minor(),
+            self.patch()
+        ))
+    }
+}
+
+/// Represents a dylib load command, which either identifies the file or names a dependency.
+pub struct Dylib {
+    pub(super) command: u32,
+    pub(super) name: String,
+    pub(super) current_version: DylibVersion,
+    pub(super) compatibility_version: DylibVersion,
+}
+
+impl Dylib {
+    /// Returns the load command, e.g. `LC_LOAD_DYLIB`.
+    #[inline]
+    pub fn command(&self) -> u32 {
+        self.command
+    }
+
+    /// Returns the install name of the dylib.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the current version of the dylib.
+    #[inline]
+    pub fn current_version(&self) -> &DylibVersion {
+        &self.current_version
+    }
+
+    /// Returns the compatibility version of the dylib.
+    #[inline]
+    pub fn compatibility_version(&self) -> &DylibVersion {
+        &self.compatibility_version
+    }
+
+    /// Checks whether the dependency may be missing at runtime.
+    #[inline]
+    pub fn is_weak(&self) -> bool {
+        self.command == LC_LOAD_WEAK_DYLIB
+    }
+}
+
+impl Clone for Dylib {
+    fn clone(&self) -> Self {
+        Self {
+            command: self.command,
+            name: self.name.clone(),
+            current_version: self.current_version.clone(),
+            compatibility_version: self.compatibility_version.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for Dylib {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Dylib({:#x}, {:?}, {}, {})",
+            self.command, self.name, self.current_version, self.compatibility_version
+        ))
+    }
+}
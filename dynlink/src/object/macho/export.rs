@@ -0,0 +1,73 @@
+use std::fmt;
+
+use crate::object::macho::EXPORT_SYMBOL_FLAGS_REEXPORT;
+
+/// Represents a terminal node of the exports trie of a Mach-O file.
+///
+/// Names carry the leading underscore of C symbols, e.g. `_sum_of`.
+pub struct MachOExport {
+    pub(super) name: String,
+    pub(super) flags: u64,
+    pub(super) address: u64,
+    pub(super) reexport: Option<(u64, String)>,
+}
+
+impl MachOExport {
+    /// Returns the name of the export.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the flags of the export, a combination of `EXPORT_SYMBOL_FLAGS_*` values.
+    #[inline]
+    pub fn flags(&self) -> u64 {
+        self.flags
+    }
+
+    /// Returns the address of the export relative to the image base, or zero for re-exports.
+    #[inline]
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// Checks whether the export is re-exported from a dependency.
+    #[inline]
+    pub fn is_reexport(&self) -> bool {
+        self.flags & EXPORT_SYMBOL_FLAGS_REEXPORT != 0
+    }
+
+    /// Returns the 1-based ordinal of the dependency and the name the export is re-exported
+    /// from, which is empty when the name is the same.
+    pub fn reexport(&self) -> Option<(u64, &str)> {
+        self.reexport
+            .as_ref()
+            .map(|(ordinal, name)| (*ordinal, name.as_str()))
+    }
+}
+
+impl Clone for MachOExport {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            flags: self.flags,
+            address: self.address,
+            reexport: self.reexport.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for MachOExport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.reexport {
+            Some((ordinal, name)) => f.write_fmt(format_args!(
+                "MachOExport({:?}, {:#x}, {}, {:?})",
+                self.name, self.flags, ordinal, name
+            )),
+            None => f.write_fmt(format_args!(
+                "MachOExport({:?}, {:#x}, {:#x})",
+                self.name, self.flags, self.address
+            )),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::{borrow, fmt, fs, path};
+
+use crate::object::{macho::MachOFile, reader::Reader, Architecture, ObjectError};
+
+const FAT_MAGIC: u32 = 0xcafe_babe;
+const FAT_MAGIC_64: u32 = 0xcafe_babf;
+
+/// Represents an entry of a fat header, locating the Mach-O file of one architecture.
+pub struct FatArch {
+    pub(super) cputype: u32,
+    pub(super) cpusubtype: u32,
+    pub(super) offset: u64,
+    pub(super) size: u64,
+    pub(super) align: u32,
+}
+
+impl FatArch {
+    /// Returns the `cputype` of the entry.
+    #[inline]
+    pub fn cputype(&self) -> u32 {
+        self.cputype
+    }
+
+    /// Returns the `cpusubtype` of the entry.
+    #[inline]
+    pub fn cpusubtype(&self) -> u32 {
+        self.cpusubtype
+    }
+
+    /// Returns the architecture of the entry.
+    pub fn architecture(&self) -> Architecture {
+        Architecture::from_macho(self.cputype)
+    }
+
+    /// Returns the file offset of the Mach-O file.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the size of the Mach-O file.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the alignment of the Mach-O file as a power of two.
+    #[inline]
+    pub fn align(&self) -> u32 {
+        self.align
+    }
+}
+
+impl Clone for FatArch {
+    fn clone(&self) -> Self {
+        Self {
+            cputype: self.cputype,
+            cpusubtype: self.cpusubtype,
+            offset: self.offset,
+            size: self.size,
+            align: self.align,
+        }
+    }
+}
+
+impl fmt::Debug for FatArch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "FatArch({}, {:#x}, {:#x})",
+            self.architecture(),
+            self.offset,
+            self.size
+        ))
+    }
+}
+
+/// Represents a fat (universal) file holding a Mach-O file per architecture.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink::object::{macho::FatFile, Architecture};
+///
+/// fn main() {
+///     let fat = FatFile::open("libsum.dylib").unwrap();
+///
+///     if let Some(file) = fat.file(&Architecture::Aarch64) {
+///         println!("{:?}", file.unwrap().install_name());
+///     }
+/// }
+/// ```
+pub struct FatFile<'data> {
+    data: borrow::Cow<'data, [u8]>,
+    arches: Vec<FatArch>,
+}
+
+impl FatFile<'static> {
+    /// Reads the fat file specified by `path`.
+    pub fn open(path: impl AsRef<path::Path>) -> Result<Self, ObjectError> {
+        Self::new(borrow::Cow::Owned(fs::read(path)?))
+    }
+}
+
+impl<'data> FatFile<'data> {
+    /// Parses the fat header of `data`.
+    pub fn parse(data: &'data [u8]) -> Result<Self, ObjectError> {
+        Self::new(borrow::Cow::Borrowed(data))
+    }
+
+    fn new(data: borrow::Cow<'data, [u8]>) -> Result<Self, ObjectError> {
+        // fat headers are always big-endian
+        let reader = Reader::new(&data, false);
+
+        let is_64 = match reader.u32(0, "fat header") {
+            Ok(FAT_MAGIC) => false,
+            Ok(FAT_MAGIC_64) => true,
+            _ => return Err(ObjectError::UnknownFormat),
+        };
+
+        let nfat_arch = u64::from(reader.u32(4, "fat header")?);
+
+        let mut arches = Vec::new();
+        for i in 0..nfat_arch {
+            // fat_arch_64 widens the offset and size and ends with a reserved field
+            arches.push(if is_64 {
+                let arch = 8 + i * 32;
+
+                FatArch {
+                    cputype: reader.u32(arch, "fat header")?,
+                    cpusubtype: reader.u32(arch + 4, "fat header")?,
+                    offset: reader.u64(arch + 8, "fat header")?,
+                    size: reader.u64(arch + 16, "fat header")?,
+                    align: reader.u32(arch + 24, "fat header")?,
+                }
+            } else {
+                let arch = 8 + i * 20;
+
+                FatArch {
+                    cputype: reader.u32(arch, "fat header")?,
+                    cpusubtype: reader.u32(arch + 4, "fat header")?,
+                    offset: u64::from(reader.u32(arch + 8, "fat header")?),
+                    size: u64::from(reader.u32(arch + 12, "fat header")?),
+                    align: reader.u32(arch + 16, "fat header")?,
+                }
+            });
+        }
+
+        Ok(Self { data, arches })
+    }
+
+    /// Returns the entries of the fat header.
+    #[inline]
+    pub fn arches(&self) -> &[FatArch] {
+        &self.arches
+    }
+
+    /// Parses the Mach-O file of every entry in fat header order.
+    pub fn files(&self) -> Result<Vec<MachOFile<'_>>, ObjectError> {
+        self.arches.iter().map(|it| self.parse_arch(it)).collect()
+    }
+
+    /// Parses the Mach-O file of `architecture` if the fat file holds one.
+    pub fn file(&self, architecture: &Architecture) -> Option<Result<MachOFile<'_>, ObjectError>> {
+        self.arches
+            .iter()
+            .find(|it| it.architecture() == *architecture)
+            .map(|it| self.parse_arch(it))
+    }
+
+    fn parse_arch(&self, arch: &FatArch) -> Result<MachOFile<'_>, ObjectError> {
+        let data = Reader::new(&self.data, false).bytes(arch.offset, arch.size, "fat header")?;
+
+        MachOFile::parse(data)
+    }
+}
+
+impl<'data> fmt::Debug for FatFile<'data> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("FatFile({:?})", self.arches))
+    }
+}
@@ -0,0 +1,394 @@
+use std::{borrow, collections::HashSet, fmt, fs, path};
+
+use crate::object::{
+    macho::{
+        Dylib, DylibVersion, MachOExport, EXPORT_SYMBOL_FLAGS_REEXPORT,
+        EXPORT_SYMBOL_FLAGS_STUB_AND_RESOLVER, LC_ID_DYLIB, LC_LAZY_LOAD_DYLIB, LC_LOAD_DYLIB,
+        LC_LOAD_UPWARD_DYLIB, LC_LOAD_WEAK_DYLIB, LC_REEXPORT_DYLIB,
+    },
+    reader::Reader,
+    Architecture, ObjectError,
+};
+
+const MH_MAGIC: u32 = 0xfeed_face;
+const MH_MAGIC_64: u32 = 0xfeed_facf;
+
+const LC_UUID: u32 = 0x1b;
+const LC_DYLD_INFO: u32 = 0x22;
+const LC_RPATH: u32 = 0x8000_001c;
+const LC_DYLD_INFO_ONLY: u32 = 0x8000_0022;
+const LC_DYLD_EXPORTS_TRIE: u32 = 0x8000_0033;
+
+/// Represents a thin Mach-O file parsed without loading it.
+///
+/// # Usage
+///
+/// `MachOFile` either borrows the bytes passed to `MachOFile::parse` or owns the bytes read by
+/// `MachOFile::open`, so dylibs can be inspected on any platform.
+///
+/// ```no_run
+/// use dynlink::object::macho::MachOFile;
+///
+/// fn main() {
+///     let file = MachOFile::open("libsum.dylib").unwrap();
+///
+///     println!("{:?} {:?}", file.install_name(), file.dependencies());
+///     for export in file.exports().unwrap() {
+///         println!("{} {:#x}", export.name(), export.address());
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// Fat (universal) files hold a Mach-O file per architecture and are read with `FatFile`.
+pub struct MachOFile<'data> {
+    data: borrow::Cow<'data, [u8]>,
+    is_64: bool,
+    is_little: bool,
+    cputype: u32,
+    cpusubtype: u32,
+    file_type: u32,
+    flags: u32,
+    install_name: Option<Dylib>,
+    dependencies: Vec<Dylib>,
+    rpaths: Vec<String>,
+    uuid: Option<[u8; 16]>,
+    exports_trie: Option<(u32, u32)>,
+}
+
+impl MachOFile<'static> {
+    /// Reads the Mach-O file specified by `path`.
+    pub fn open(path: impl AsRef<path::Path>) -> Result<Self, ObjectError> {
+        Self::new(borrow::Cow::Owned(fs::read(path)?))
+    }
+}
+
+impl<'data> MachOFile<'data> {
+    /// Parses the Mach-O header and the load commands of `data`.
+    pub fn parse(data: &'data [u8]) -> Result<Self, ObjectError> {
+        Self::new(borrow::Cow::Borrowed(data))
+    }
+
+    fn new(data: borrow::Cow<'data, [u8]>) -> Result<Self, ObjectError> {
+        let magic = Reader::new(&data, true)
+            .u32(0, "Mach-O header")
+            .map_err(|_| ObjectError::UnknownFormat)?;
+
+        let (is_64, is_little) = match magic {
+            MH_MAGIC => (false, true),
+            MH_MAGIC_64 => (true, true),
+            _ if magic.swap_bytes() == MH_MAGIC => (false, false),
+            _ if magic.swap_bytes() == MH_MAGIC_64 => (true, false),
+            _ => return Err(ObjectError::UnknownFormat),
+        };
+
+        let mut file = Self {
+            data,
+            is_64,
+            is_little,
+            cputype: 0,
+            cpusubtype: 0,
+            file_type: 0,
+            flags: 0,
+            install_name: None,
+            dependencies: Vec::new(),
+            rpaths: Vec::new(),
+            uuid: None,
+            exports_trie: None,
+        };
+
+        file.read_commands()?;
+        Ok(file)
+    }
+
+    fn reader(&self) -> Reader<'_> {
+        Reader::new(&self.data, self.is_little)
+    }
+
+    fn read_commands(&mut self) -> Result<(), ObjectError> {
+        let reader = Reader::new(&self.data, self.is_little);
+
+        let cputype = reader.u32(4, "Mach-O header")?;
+        let cpusubtype = reader.u32(8, "Mach-O header")?;
+        let file_type = reader.u32(12, "Mach-O header")?;
+        let ncmds = reader.u32(16, "Mach-O header")?;
+        let flags = reader.u32(24, "Mach-O header")?;
+
+        // mach_header_64 has a reserved field after the flags
+        let mut command = if self.is_64 { 32 } else { 28 };
+
+        for _ in 0..ncmds {
+            let cmd = reader.u32(command, "load command")?;
+            let cmdsize = u64::from(reader.u32(command + 4, "load command")?);
+
+            if cmdsize < 8 {
+                return Err(ObjectError::Malformed("load command"));
+            }
+
+            // strings of load commands are referenced by their offset from the command start
+            let string = |offset: u64| -> Result<String, ObjectError> {
+                let offset = u64::from(reader.u32(command + offset, "load command")?);
+                let bytes = reader.bytes(command, cmdsize, "load command")?;
+                let bytes = bytes
+                    .get(offset as usize..)
+                    .ok_or(ObjectError::Malformed("load command"))?;
+                let bytes = bytes.split(|it| *it == 0).next().unwrap_or_default();
+
+                Ok(String::from_utf8_lossy(bytes).into_owned())
+            };
+
+            match cmd {
+                LC_ID_DYLIB | LC_LOAD_DYLIB | LC_LOAD_WEAK_DYLIB | LC_REEXPORT_DYLIB
+                | LC_LAZY_LOAD_DYLIB | LC_LOAD_UPWARD_DYLIB => {
+                    // name offset, timestamp, current and compatibility versions
+                    let dylib = Dylib {
+                        command: cmd,
+                        name: string(8)?,
+                        current_version: DylibVersion(reader.u32(command + 16, "load command")?),
+                        compatibility_version: DylibVersion(
+                            reader.u32(command + 20, "load command")?,
+                        ),
+                    };
+
+                    if cmd == LC_ID_DYLIB {
+                        self.install_name = Some(dylib);
+                    } else {
+                        self.dependencies.push(dylib);
+                    }
+                }
+
+                LC_RPATH => self.rpaths.push(string(8)?),
+
+                LC_UUID => {
+                    let uuid = reader.bytes(command + 8, 16, "load command")?;
+                    self.uuid = Some(uuid.try_into().unwrap());
+                }
+
+                // rebase, bind, weak bind and lazy bind offsets and sizes precede the exports trie
+                LC_DYLD_INFO | LC_DYLD_INFO_ONLY => {
+                    let offset = reader.u32(command + 40, "load command")?;
+                    let size = reader.u32(command + 44, "load command")?;
+
+                    if size != 0 {
+                        self.exports_trie = Some((offset, size));
+                    }
+                }
+
+                LC_DYLD_EXPORTS_TRIE => {
+                    let offset = reader.u32(command + 8, "load command")?;
+                    let size = reader.u32(command + 12, "load command")?;
+
+                    self.exports_trie = Some((offset, size));
+                }
+
+                _ => {}
+            }
+
+            command += cmdsize;
+        }
+
+        self.cputype = cputype;
+        self.cpusubtype = cpusubtype;
+        self.file_type = file_type;
+        self.flags = flags;
+
+        Ok(())
+    }
+
+    /// Checks whether the file is of the 64-bit format.
+    #[inline]
+    pub fn is_64(&self) -> bool {
+        self.is_64
+    }
+
+    /// Checks whether the file is of the little-endian byte order.
+    #[inline]
+    pub fn is_little(&self) -> bool {
+        self.is_little
+    }
+
+    /// Returns the `cputype` of the file.
+    #[inline]
+    pub fn cputype(&self) -> u32 {
+        self.cputype
+    }
+
+    /// Returns the `cpusubtype` of the file.
+    #[inline]
+    pub fn cpusubtype(&self) -> u32 {
+        self.cpusubtype
+    }
+
+    /// Returns the architecture the file is built for.
+    pub fn architecture(&self) -> Architecture {
+        Architecture::from_macho(self.cputype)
+    }
+
+    /// Returns the `filetype` of the file, e.g. `MH_DYLIB`.
+    #[inline]
+    pub fn file_type(&self) -> u32 {
+        self.file_type
+    }
+
+    /// Returns the `flags` of the file, a combination of `MH_*` values.
+    #[inline]
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// Returns the `LC_ID_DYLIB` of the file, which holds its install name and versions.
+    #[inline]
+    pub fn install_name(&self) -> Option<&Dylib> {
+        self.install_name.as_ref()
+    }
+
+    /// Returns the dylibs the file depends on in load command order,
+    /// which is also the order of the ordinals used by re-exports.
+    #[inline]
+    pub fn dependencies(&self) -> &[Dylib] {
+        &self.dependencies
+    }
+
+    /// Returns the `LC_RPATH` entries of the file.
+    #[inline]
+    pub fn rpaths(&self) -> &[String] {
+        &self.rpaths
+    }
+
+    /// Returns the `LC_UUID` of the file.
+    #[inline]
+    pub fn uuid(&self) -> Option<&[u8; 16]> {
+        self.uuid.as_ref()
+    }
+
+    /// Returns the exports of the file's exports trie in trie order.
+    pub fn exports(&self) -> Result<Vec<MachOExport>, ObjectError> {
+        let Some((offset, size)) = self.exports_trie else {
+            return Ok(Vec::new());
+        };
+
+        let trie = self
+            .reader()
+            .bytes(u64::from(offset), u64::from(size), "exports trie")?;
+
+        let mut exports = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![(0_usize, Vec::new())];
+
+        while let Some((node, prefix)) = pending.pop() {
+            // children pointing back into visited nodes would loop forever
+            if !visited.insert(node) {
+                return Err(ObjectError::Malformed("exports trie"));
+            }
+
+            let mut cursor = node;
+            let terminal_size = uleb128(trie, &mut cursor)?;
+            let children = usize::try_from(terminal_size)
+                .ok()
+                .and_then(|it| cursor.checked_add(it))
+                .ok_or(ObjectError::Malformed("exports trie"))?;
+
+            if terminal_size != 0 {
+                let flags = uleb128(trie, &mut cursor)?;
+                let name = String::from_utf8_lossy(&prefix).into_owned();
+
+                let (address, reexport) = if flags & EXPORT_SYMBOL_FLAGS_REEXPORT != 0 {
+                    let ordinal = uleb128(trie, &mut cursor)?;
+                    let imported = string(trie, &mut cursor)?;
+
+                    (
+                        0,
+                        Some((ordinal, String::from_utf8_lossy(imported).into_owned())),
+                    )
+                } else {
+                    // stubs are followed by the resolver address, which is not reported
+                    let address = uleb128(trie, &mut cursor)?;
+                    if flags & EXPORT_SYMBOL_FLAGS_STUB_AND_RESOLVER != 0 {
+                        uleb128(trie, &mut cursor)?;
+                    }
+
+                    (address, None)
+                };
+
+                exports.push(MachOExport {
+                    name,
+                    flags,
+                    address,
+                    reexport,
+                });
+            }
+
+            let mut cursor = children;
+            let count = *trie
+                .get(cursor)
+                .ok_or(ObjectError::Malformed("exports trie"))?;
+            cursor += 1;
+
+            let mut edges = Vec::new();
+            for _ in 0..count {
+                let edge = string(trie, &mut cursor)?;
+                let child = uleb128(trie, &mut cursor)? as usize;
+
+                let mut name = prefix.clone();
+                name.extend_from_slice(edge);
+                edges.push((child, name));
+            }
+
+            // children are pushed in reverse so they are visited in trie order
+            pending.extend(edges.into_iter().rev());
+        }
+
+        Ok(exports)
+    }
+}
+
+/// Reads an unsigned LEB128 number of the exports trie at `cursor` and advances it.
+fn uleb128(trie: &[u8], cursor: &mut usize) -> Result<u64, ObjectError> {
+    let mut value = 0_u64;
+    let mut shift = 0;
+
+    loop {
+        let byte = *trie
+            .get(*cursor)
+            .ok_or(ObjectError::Malformed("exports trie"))?;
+        *cursor += 1;
+
+        if shift >= 64 {
+            return Err(ObjectError::Malformed("exports trie"));
+        }
+
+        value |= u64::from(byte & 0x7f) << shift;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+/// Reads a null-terminated string of the exports trie at `cursor` and advances it.
+fn string<'trie>(trie: &'trie [u8], cursor: &mut usize) -> Result<&'trie [u8], ObjectError> {
+    let tail = trie
+        .get(*cursor..)
+        .ok_or(ObjectError::Malformed("exports trie"))?;
+    let end = tail
+        .iter()
+        .position(|it| *it == 0)
+        .ok_or(ObjectError::Malformed("exports trie"))?;
+
+    *cursor += end + 1;
+    Ok(&tail[..end])
+}
+
+impl<'data> fmt::Debug for MachOFile<'data> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "MachOFile({}, {:#x}, {:?}, {:?})",
+            self.architecture(),
+            self.file_type,
+            self.install_name,
+            self.dependencies
+        ))
+    }
+}
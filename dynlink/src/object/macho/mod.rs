@@ -0,0 +1,11 @@
+mod consts;
+mod dylib;
+mod export;
+mod fat;
+mod file;
+
+pub use consts::*;
+pub use dylib::{Dylib, DylibVersion};
+pub use export::MachOExport;
+pub use fat::{FatArch, FatFile};
+pub use file::MachOFile;
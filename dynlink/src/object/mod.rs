@@ -0,0 +1,19 @@
+mod arch;
+mod compat;
+pub mod elf;
+mod error;
+mod format;
+mod header;
+mod identity;
+mod info;
+pub mod macho;
+pub mod pe;
+mod reader;
+
+pub use arch::Architecture;
+pub use compat::{check_compatible, CompatibilityError};
+pub use error::ObjectError;
+pub use format::Format;
+pub use header::{Header, OsAbi};
+pub use identity::BuildId;
+pub use info::ObjectInfo;
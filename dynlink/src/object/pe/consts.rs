@@ -0,0 +1,21 @@
+//! Constants of the PE/COFF specification used by the accessors of this module.
+
+/// Executable image flag of `PeFile::characteristics`.
+pub const IMAGE_FILE_EXECUTABLE_IMAGE: u16 = 0x0002;
+/// Large address aware flag of `PeFile::characteristics`.
+pub const IMAGE_FILE_LARGE_ADDRESS_AWARE: u16 = 0x0020;
+/// 32-bit word machine flag of `PeFile::characteristics`.
+pub const IMAGE_FILE_32BIT_MACHINE: u16 = 0x0100;
+/// Dynamic-link library flag of `PeFile::characteristics`.
+pub const IMAGE_FILE_DLL: u16 = 0x2000;
+
+/// Code section flag of `SectionHeader::characteristics`.
+pub const IMAGE_SCN_CNT_CODE: u32 = 0x0000_0020;
+/// Initialized data section flag of `SectionHeader::characteristics`.
+pub const IMAGE_SCN_CNT_INITIALIZED_DATA: u32 = 0x0000_0040;
+/// Executable section flag of `SectionHeader::characteristics`.
+pub const IMAGE_SCN_MEM_EXECUTE: u32 = 0x2000_0000;
+/// Readable section flag of `SectionHeader::characteristics`.
+pub const IMAGE_SCN_MEM_READ: u32 = 0x4000_0000;
+/// Writable section flag of `SectionHeader::characteristics`.
+pub const IMAGE_SCN_MEM_WRITE: u32 = 0x8000_0000;
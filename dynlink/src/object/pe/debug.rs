@@ -0,0 +1,48 @@
+use std::fmt;
+
+/// Represents the CodeView record of a PE file's debug directory,
+/// which identifies the PDB file matching the image.
+pub struct CodeView {
+    pub(super) guid: [u8; 16],
+    pub(super) age: u32,
+    pub(super) path: String,
+}
+
+impl CodeView {
+    /// Returns the GUID of the PDB file as stored in the record.
+    #[inline]
+    pub fn guid(&self) -> &[u8; 16] {
+        &self.guid
+    }
+
+    /// Returns the age of the PDB file.
+    #[inline]
+    pub fn age(&self) -> u32 {
+        self.age
+    }
+
+    /// Returns the path of the PDB file at build time.
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Clone for CodeView {
+    fn clone(&self) -> Self {
+        Self {
+            guid: self.guid,
+            age: self.age,
+            path: self.path.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for CodeView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "CodeView({:02x?}, {}, {:?})",
+            self.guid, self.age, self.path
+        ))
+    }
+}
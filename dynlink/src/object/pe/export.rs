@@ -0,0 +1,65 @@
+use std::fmt;
+
+/// Represents an entry of the export directory of a PE file.
+///
+/// An export either has an address in the file or forwards to an export of another DLL,
+/// written as `DLL.name` or `DLL.#ordinal`.
+pub struct PeExport {
+    pub(super) ordinal: u32,
+    pub(super) name: Option<String>,
+    pub(super) rva: u32,
+    pub(super) forwarder: Option<String>,
+}
+
+impl PeExport {
+    /// Returns the ordinal of the export, including the ordinal base of the directory.
+    #[inline]
+    pub fn ordinal(&self) -> u32 {
+        self.ordinal
+    }
+
+    /// Returns the name of the export, unless it is exported by ordinal only.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the address of the export relative to the image base,
+    /// which points to the forwarder string for forwarded exports.
+    #[inline]
+    pub fn rva(&self) -> u32 {
+        self.rva
+    }
+
+    /// Returns the export this one forwards to.
+    #[inline]
+    pub fn forwarder(&self) -> Option<&str> {
+        self.forwarder.as_deref()
+    }
+}
+
+impl Clone for PeExport {
+    fn clone(&self) -> Self {
+        Self {
+            ordinal: self.ordinal,
+            name: self.name.clone(),
+            rva: self.rva,
+            forwarder: self.forwarder.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for PeExport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.forwarder {
+            Some(forwarder) => f.write_fmt(format_args!(
+                "PeExport({}, {:?}, {:?})",
+                self.ordinal, self.name, forwarder
+            )),
+            None => f.write_fmt(format_args!(
+                "PeExport({}, {:?}, {:#x})",
+                self.ordinal, self.name, self.rva
+            )),
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use std::{borrow, fmt, fs, path};
+
+use crate::object::{
+    pe::{CodeView, ImportedSymbol, PeExport, PeImport, SectionHeader, IMAGE_FILE_DLL},
+    reader::Reader,
+    Architecture, ObjectError,
+};
+
+const DOS_MAGIC: &[u8] = b"MZ";
+const PE_SIGNATURE: &[u8] = b"PE\0\0";
+
+const IMAGE_NT_OPTIONAL_HDR32_MAGIC: u16 = 0x10b;
+const IMAGE_NT_OPTIONAL_HDR64_MAGIC: u16 = 0x20b;
+
+const IMAGE_DIRECTORY_ENTRY_EXPORT: usize = 0;
+const IMAGE_DIRECTORY_ENTRY_IMPORT: usize = 1;
+const IMAGE_DIRECTORY_ENTRY_DEBUG: usize = 6;
+
+const IMAGE_DEBUG_TYPE_CODEVIEW: u32 = 2;
+const CV_SIGNATURE_RSDS: &[u8] = b"RSDS";
+
+/// Represents a PE/COFF file parsed without loading it.
+///
+/// # Usage
+///
+/// `PeFile` either borrows the bytes passed to `PeFile::parse` or owns the bytes read by
+/// `PeFile::open`, so DLLs can be inspected on any platform.
+///
+/// ```no_run
+/// use dynlink::object::pe::PeFile;
+///
+/// fn main() {
+///     let file = PeFile::open("sum.dll").unwrap();
+///
+///     println!("{:?} {:?}", file.architecture(), file.dll_name().unwrap());
+///     for export in file.exports().unwrap() {
+///         println!("{} {:?} {:?}", export.ordinal(), export.name(), export.forwarder());
+///     }
+/// }
+/// ```
+pub struct PeFile<'data> {
+    data: borrow::Cow<'data, [u8]>,
+    is_64: bool,
+    machine: u16,
+    characteristics: u16,
+    image_base: u64,
+    directories: Vec<(u32, u32)>,
+    sections: Vec<SectionHeader>,
+}
+
+impl PeFile<'static> {
+    /// Reads the PE file specified by `path`.
+    pub fn open(path: impl AsRef<path::Path>) -> Result<Self, ObjectError> {
+        Self::new(borrow::Cow::Owned(fs::read(path)?))
+    }
+}
+
+impl<'data> PeFile<'data> {
+    /// Parses the COFF header, the optional header and the section table of `data`.
+    pub fn parse(data: &'data [u8]) -> Result<Self, ObjectError> {
+        Self::new(borrow::Cow::Borrowed(data))
+    }
+
+    fn new(data: borrow::Cow<'data, [u8]>) -> Result<Self, ObjectError> {
+        if !data.starts_with(DOS_MAGIC) {
+            return Err(ObjectError::UnknownFormat);
+        }
+
+        let mut file = Self {
+            data,
+            is_64: false,
+            machine: 0,
+            characteristics: 0,
+            image_base: 0,
+            directories: Vec::new(),
+            sections: Vec::new(),
+        };
+
+        file.read_headers()?;
+        Ok(file)
+    }
+
+    fn reader(&self) -> Reader<'_> {
+        Reader::new(&self.data, true)
+    }
+
+    fn read_headers(&mut self) -> Result<(), ObjectError> {
+        let reader = Reader::new(&self.data, true);
+
+        let pe = u64::from(reader.u32(0x3c, "DOS header")?);
+        if reader.bytes(pe, 4, "PE header")? != PE_SIGNATURE {
+            return Err(ObjectError::Malformed("PE header"));
+        }
+
+        let machine = reader.u16(pe + 4, "COFF header")?;
+        let section_count = reader.u16(pe + 6, "COFF header")?;
+        let optional_size = reader.u16(pe + 20, "COFF header")?;
+        let characteristics = reader.u16(pe + 22, "COFF header")?;
+
+        let optional = pe + 24;
+
+        // ImageBase is widened in PE32+, which also drops BaseOfData
+        let (is_64, image_base, directory_count, directories) =
+            match reader.u16(optional, "optional header")? {
+                IMAGE_NT_OPTIONAL_HDR32_MAGIC => (
+                    false,
+                    u64::from(reader.u32(optional + 28, "optional header")?),
+                    reader.u32(optional + 92, "optional header")?,
+                    optional + 96,
+                ),
+                IMAGE_NT_OPTIONAL_HDR64_MAGIC => (
+                    true,
+                    reader.u64(optional + 24, "optional header")?,
+                    reader.u32(optional + 108, "optional header")?,
+                    optional + 112,
+                ),
+                _ => return Err(ObjectError::Malformed("optional header")),
+            };
+
+        let mut data_directories = Vec::new();
+        for i in 0..u64::from(directory_count.min(16)) {
+            let directory = directories + i * 8;
+
+            data_directories.push((
+                reader.u32(directory, "data directory")?,
+                reader.u32(directory + 4, "data directory")?,
+            ));
+        }
+
+        let mut sections = Vec::new();
+        let table = optional + u64::from(optional_size);
+        for i in 0..u64::from(section_count) {
+            let section = table + i * 40;
+
+            let name = reader.bytes(section, 8, "section header")?;
+            let name = name.split(|it| *it == 0).next().unwrap_or_default();
+
+            sections.push(SectionHeader {
+                name: String::from_utf8_lossy(name).into_owned(),
+                virtual_size: reader.u32(section + 8, "section header")?,
+                virtual_address: reader.u32(section + 12, "section header")?,
+                raw_size: reader.u32(section + 16, "section header")?,
+                raw_offset: reader.u32(section + 20, "section header")?,
+                characteristics: reader.u32(section + 36, "section header")?,
+            });
+        }
+
+        self.is_64 = is_64;
+        self.machine = machine;
+        self.characteristics = characteristics;
+        self.image_base = image_base;
+        self.directories = data_directories;
+        self.sections = sections;
+
+        Ok(())
+    }
+
+    /// Checks whether the file is of the PE32+ format.
+    #[inline]
+    pub fn is_64(&self) -> bool {
+        self.is_64
+    }
+
+    /// Returns the COFF `Machine` of the file.
+    #[inline]
+    pub fn machine(&self) -> u16 {
+        self.machine
+    }
+
+    /// Returns the architecture the file is built for.
+    pub fn architecture(&self) -> Architecture {
+        Architecture::from_pe(self.machine)
+    }
+
+    /// Returns the COFF `Characteristics` of the file, a combination of `IMAGE_FILE_*` values.
+    #[inline]
+    pub fn characteristics(&self) -> u16 {
+        self.characteristics
+    }
+
+    /// Checks whether the file is a dynamic-link library.
+    #[inline]
+    pub fn is_dll(&self) -> bool {
+        self.characteristics & IMAGE_FILE_DLL != 0
+    }
+
+    /// Returns the preferred address the file is loaded at.
+    #[inline]
+    pub fn image_base(&self) -> u64 {
+        self.image_base
+    }
+
+    /// Returns the section headers of the file.
+    #[inline]
+    pub fn sections(&self) -> &[SectionHeader] {
+        &self.sections
+    }
+
+    /// Translates the relative virtual address `rva` to the file offset.
+    fn offset_of(&self, rva: u32, what: &'static str) -> Result<u64, ObjectError> {
+        self.sections
+            .iter()
+            .find_map(|it| it.offset_of(rva))
+            .ok_or(ObjectError::Malformed(what))
+    }
+
+    /// Returns the relative virtual address and size of the data directory at `index`.
+    fn directory(&self, index: usize) -> Option<(u32, u32)> {
+        self.directories
+            .get(index)
+            .copied()
+            .filter(|it| it.0 != 0 && it.1 != 0)
+    }
+
+    /// Returns the string at the relative virtual address `rva`.
+    fn string(&self, rva: u32, what: &'static str) -> Result<String, ObjectError> {
+        let bytes = self.reader().string(self.offset_of(rva, what)?, what)?;
+
+        Ok(String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    /// Returns the name of the DLL recorded in the export directory.
+    pub fn dll_name(&self) -> Result<Option<String>, ObjectError> {
+        let Some((rva, _)) = self.directory(IMAGE_DIRECTORY_ENTRY_EXPORT) else {
+            return Ok(None);
+        };
+
+        let directory = self.offset_of(rva, "export directory")?;
+        let name = self.reader().u32(directory + 12, "export directory")?;
+
+        self.string(name, "export directory").map(Some)
+    }
+
+    /// Returns the entries of the export address table in ordinal order,
+    /// except the unused ones.
+    pub fn exports(&self) -> Result<Vec<PeExport>, ObjectError> {
+        let Some((rva, size)) = self.directory(IMAGE_DIRECTORY_ENTRY_EXPORT) else {
+            return Ok(Vec::new());
+        };
+
+        let reader = self.reader();
+        let directory = self.offset_of(rva, "export directory")?;
+
+        let base = reader.u32(directory + 16, "export directory")?;
+        let function_count = reader.u32(directory + 20, "export directory")?;
+        let name_count = reader.u32(directory + 24, "export directory")?;
+        let functions = reader.u32(directory + 28, "export directory")?;
+        let names = reader.u32(directory + 32, "export directory")?;
+        let ordinals = reader.u32(directory + 36, "export directory")?;
+
+        let mut exports = Vec::new();
+        if function_count == 0 {
+            return Ok(exports);
+        }
+
+        let functions = self.offset_of(functions, "export address table")?;
+        for i in 0..function_count {
+            let address = reader.u32(functions + u64::from(i) * 4, "export address table")?;
+            if address == 0 {
+                continue;
+            }
+
+            // addresses within the export directory point to forwarder strings
+            let forwarder = if address.wrapping_sub(rva) < size {
+                Some(self.string(address, "export forwarder")?)
+            } else {
+                None
+            };
+
+            exports.push(PeExport {
+                ordinal: base.wrapping_add(i),
+                name: None,
+                rva: address,
+                forwarder,
+            });
+        }
+
+        if name_count != 0 {
+            let names = self.offset_of(names, "export name table")?;
+            let ordinals = self.offset_of(ordinals, "export ordinal table")?;
+
+            for i in 0..u64::from(name_count) {
+                let name = reader.u32(names + i * 4, "export name table")?;
+                let index = reader.u16(ordinals + i * 2, "export ordinal table")?;
+                let ordinal = base.wrapping_add(u32::from(index));
+
+                if let Some(export) = exports.iter_mut().find(|it| it.ordinal == ordinal) {
+                    export.name = Some(self.string(name, "export name table")?);
+                }
+            }
+        }
+
+        Ok(exports)
+    }
+
+    /// Returns the import descriptors of the file in declaration order.
+    pub fn imports(&self) -> Result<Vec<PeImport>, ObjectError> {
+        let Some((rva, _)) = self.directory(IMAGE_DIRECTORY_ENTRY_IMPORT) else {
+            return Ok(Vec::new());
+        };
+
+        let reader = self.reader();
+        let mut descriptor = self.offset_of(rva, "import directory")?;
+
+        let (thunk_size, ordinal_flag) = if self.is_64 {
+            (8, 1 << 63)
+        } else {
+            (4, 1 << 31)
+        };
+
+        let mut imports = Vec::new();
+        loop {
+            let lookup = reader.u32(descriptor, "import directory")?;
+            let name = reader.u32(descriptor + 12, "import directory")?;
+            let address = reader.u32(descriptor + 16, "import directory")?;
+
+            // the directory ends with a zeroed descriptor
+            if name == 0 && address == 0 {
+                break;
+            }
+
+            // the import lookup table is optional, the bound address table is a copy of it
+            let mut thunk = match lookup {
+                0 => self.offset_of(address, "import lookup table")?,
+                lookup => self.offset_of(lookup, "import lookup table")?,
+            };
+
+            let mut symbols = Vec::new();
+            loop {
+                let entry = reader.word(thunk, self.is_64, "import lookup table")?;
+                if entry == 0 {
+                    break;
+                }
+
+                if entry & ordinal_flag != 0 {
+                    symbols.push(ImportedSymbol::Ordinal(entry as u16));
+                } else {
+                    let hint_name = self.offset_of(entry as u32, "import hint table")?;
+                    let hint = reader.u16(hint_name, "import hint table")?;
+                    let name = reader.string(hint_name + 2, "import hint table")?;
+
+                    symbols.push(ImportedSymbol::Name(
+                        hint,
+                        String::from_utf8_lossy(name).into_owned(),
+                    ));
+                }
+
+                thunk += thunk_size;
+            }
+
+            imports.push(PeImport {
+                dll: self.string(name, "import directory")?,
+                symbols,
+            });
+
+            descriptor += 20;
+        }
+
+        Ok(imports)
+    }
+
+    /// Returns the CodeView record of the debug directory, which names the matching PDB file.
+    pub fn codeview(&self) -> Result<Option<CodeView>, ObjectError> {
+        let Some((rva, size)) = self.directory(IMAGE_DIRECTORY_ENTRY_DEBUG) else {
+            return Ok(None);
+        };
+
+        let reader = self.reader();
+        let directory = self.offset_of(rva, "debug directory")?;
+
+        for i in 0..u64::from(size / 28) {
+            let entry = directory + i * 28;
+            if reader.u32(entry + 12, "debug directory")? != IMAGE_DEBUG_TYPE_CODEVIEW {
+                continue;
+            }
+
+            // PointerToRawData locates the record even if it is not mapped
+            let record = u64::from(reader.u32(entry + 24, "debug directory")?);
+            if reader.bytes(record, 4, "CodeView record")? != CV_SIGNATURE_RSDS {
+                continue;
+            }
+
+            let guid = reader.bytes(record + 4, 16, "CodeView record")?;
+            let age = reader.u32(record + 20, "CodeView record")?;
+            let path = reader.string(record + 24, "CodeView record")?;
+
+            return Ok(Some(CodeView {
+                guid: guid.try_into().unwrap(),
+                age,
+                path: String::from_utf8_lossy(path).into_owned(),
+            }));
+        }
+
+        Ok(None)
+    }
+}
+
+impl<'data> fmt::Debug for PeFile<'data> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "PeFile({}, {:#x}, {:?})",
+            self.architecture(),
+            self.characteristics,
+            self.sections
+        ))
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+
+/// Represents a symbol imported from a DLL.
+///
+/// `ImportedSymbol::Name(hint, name)` contains the name and the hint into the export name table of the DLL.
+/// `ImportedSymbol::Ordinal(ordinal)` contains the ordinal of a symbol imported by ordinal only.
+pub enum ImportedSymbol {
+    Name(u16, String),
+    Ordinal(u16),
+}
+
+impl ImportedSymbol {
+    /// Returns the name of the symbol, unless it is imported by ordinal only.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Name(_, name) => Some(name),
+            Self::Ordinal(_) => None,
+        }
+    }
+}
+
+impl Clone for ImportedSymbol {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Name(hint, name) => Self::Name(*hint, name.clone()),
+            Self::Ordinal(ordinal) => Self::Ordinal(*ordinal),
+        }
+    }
+}
+
+impl PartialEq for ImportedSymbol {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Name(hint, name), Self::Name(other_hint, other_name)) => {
+                hint == other_hint && name == other_name
+            }
+            (Self::Ordinal(ordinal), Self::Ordinal(other)) => ordinal == other,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ImportedSymbol {}
+
+impl fmt::Debug for ImportedSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name(hint, name) => f.write_fmt(format_args!("Name({}, {:?})", hint, name)),
+            Self::Ordinal(ordinal) => f.write_fmt(format_args!("Ordinal({})", ordinal)),
+        }
+    }
+}
+
+/// Represents an import descriptor of a PE file, naming a DLL and the symbols imported from it.
+pub struct PeImport {
+    pub(super) dll: String,
+    pub(super) symbols: Vec<ImportedSymbol>,
+}
+
+impl PeImport {
+    /// Returns the name of the DLL.
+    #[inline]
+    pub fn dll(&self) -> &str {
+        &self.dll
+    }
+
+    /// Returns the symbols imported from the DLL.
+    #[inline]
+    pub fn symbols(&self) -> &[ImportedSymbol] {
+        &self.symbols
+    }
+}
+
+impl Clone for PeImport {
+    fn clone(&self) -> Self {
+        Self {
+            dll: self.dll.clone(),
+            symbols: self.symbols.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for PeImport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("PeImport({:?}, {:?})", self.dll, self.symbols))
+    }
+}
@@ -0,0 +1,13 @@
+mod consts;
+mod debug;
+mod export;
+mod file;
+mod import;
+mod section;
+
+pub use consts::*;
+pub use debug::CodeView;
+pub use export::PeExport;
+pub use file::PeFile;
+pub use import::{ImportedSymbol, PeImport};
+pub use section::SectionHeader;
@@ -0,0 +1,79 @@
+use std::fmt;
+
+/// Represents a section header of a PE file.
+pub struct SectionHeader {
+    pub(super) name: String,
+    pub(super) virtual_size: u32,
+    pub(super) virtual_address: u32,
+    pub(super) raw_size: u32,
+    pub(super) raw_offset: u32,
+    pub(super) characteristics: u32,
+}
+
+impl SectionHeader {
+    /// Returns the name of the section, e.g. `.text`.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the size of the section in memory.
+    #[inline]
+    pub fn virtual_size(&self) -> u32 {
+        self.virtual_size
+    }
+
+    /// Returns the address of the section relative to the image base.
+    #[inline]
+    pub fn virtual_address(&self) -> u32 {
+        self.virtual_address
+    }
+
+    /// Returns the size of the section in the file.
+    #[inline]
+    pub fn raw_size(&self) -> u32 {
+        self.raw_size
+    }
+
+    /// Returns the file offset of the section.
+    #[inline]
+    pub fn raw_offset(&self) -> u32 {
+        self.raw_offset
+    }
+
+    /// Returns the flags of the section, a combination of `IMAGE_SCN_*` values.
+    #[inline]
+    pub fn characteristics(&self) -> u32 {
+        self.characteristics
+    }
+
+    /// Translates the relative virtual address `rva` to the file offset if the section contains it.
+    pub(super) fn offset_of(&self, rva: u32) -> Option<u64> {
+        let size = self.virtual_size.max(self.raw_size);
+
+        (rva >= self.virtual_address && rva - self.virtual_address < size)
+            .then(|| u64::from(self.raw_offset) + u64::from(rva - self.virtual_address))
+    }
+}
+
+impl Clone for SectionHeader {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            virtual_size: self.virtual_size,
+            virtual_address: self.virtual_address,
+            raw_size: self.raw_size,
+            raw_offset: self.raw_offset,
+            characteristics: self.characteristics,
+        }
+    }
+}
+
+impl fmt::Debug for SectionHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "SectionHeader({:?}, {:#x}, {:#x}, {:#x})",
+            self.name, self.virtual_address, self.virtual_size, self.characteristics
+        ))
+    }
+}
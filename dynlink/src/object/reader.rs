@@ -0,0 +1,111 @@
+use crate::object::ObjectError;
+
+/// Returns `base + offset` or a malformed error naming `what` if it overflows.
+pub(crate) fn offset(base: u64, offset: u64, what: &'static str) -> Result<u64, ObjectError> {
+    base.checked_add(offset).ok_or(ObjectError::Malformed(what))
+}
+
+/// Returns the offset of the `index`-th entry of `size` bytes in the table at `base`
+/// or a malformed error naming `what` if it overflows.
+pub(crate) fn entry(
+    base: u64,
+    index: u64,
+    size: u64,
+    what: &'static str,
+) -> Result<u64, ObjectError> {
+    index
+        .checked_mul(size)
+        .and_then(|it| base.checked_add(it))
+        .ok_or(ObjectError::Malformed(what))
+}
+
+/// Reads fixed-size integers of an object file with its byte order.
+pub(crate) struct Reader<'data> {
+    data: &'data [u8],
+    is_little: bool,
+}
+
+impl<'data> Reader<'data> {
+    pub(crate) fn new(data: &'data [u8], is_little: bool) -> Self {
+        Self { data, is_little }
+    }
+
+    /// Returns `len` bytes at `offset` or a malformed error naming `what`.
+    pub(crate) fn bytes(
+        &self,
+        offset: u64,
+        len: u64,
+        what: &'static str,
+    ) -> Result<&'data [u8], ObjectError> {
+        let start = usize::try_from(offset).map_err(|_| ObjectError::Malformed(what))?;
+        let len = usize::try_from(len).map_err(|_| ObjectError::Malformed(what))?;
+
+        start
+            .checked_add(len)
+            .and_then(|end| self.data.get(start..end))
+            .ok_or(ObjectError::Malformed(what))
+    }
+
+    pub(crate) fn u8(&self, offset: u64, what: &'static str) -> Result<u8, ObjectError> {
+        Ok(self.bytes(offset, 1, what)?[0])
+    }
+
+    pub(crate) fn u16(&self, offset: u64, what: &'static str) -> Result<u16, ObjectError> {
+        let bytes = self.bytes(offset, 2, what)?.try_into().unwrap();
+
+        Ok(if self.is_little {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    }
+
+    pub(crate) fn u32(&self, offset: u64, what: &'static str) -> Result<u32, ObjectError> {
+        let bytes = self.bytes(offset, 4, what)?.try_into().unwrap();
+
+        Ok(if self.is_little {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    pub(crate) fn u64(&self, offset: u64, what: &'static str) -> Result<u64, ObjectError> {
+        let bytes = self.bytes(offset, 8, what)?.try_into().unwrap();
+
+        Ok(if self.is_little {
+            u64::from_le_bytes(bytes)
+        } else {
+            u64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Reads a word of the object file class, which is 8 bytes wide for 64-bit files.
+    pub(crate) fn word(
+        &self,
+        offset: u64,
+        is_64: bool,
+        what: &'static str,
+    ) -> Result<u64, ObjectError> {
+        if is_64 {
+            self.u64(offset, what)
+        } else {
+            self.u32(offset, what).map(u64::from)
+        }
+    }
+
+    /// Reads a null-terminated string at `offset`.
+    pub(crate) fn string(
+        &self,
+        offset: u64,
+        what: &'static str,
+    ) -> Result<&'data [u8], ObjectError> {
+        let start = usize::try_from(offset).map_err(|_| ObjectError::Malformed(what))?;
+        let tail = self.data.get(start..).ok_or(ObjectError::Malformed(what))?;
+
+        match tail.iter().position(|it| *it == 0) {
+            Some(end) => Ok(&tail[..end]),
+            None => Err(ObjectError::Malformed(what)),
+        }
+    }
+}
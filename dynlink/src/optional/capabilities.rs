@@ -0,0 +1,176 @@
+use std::{fmt, iter};
+
+/// Represents a report of present and missing optional symbols.
+///
+/// # Usage
+///
+/// `Capabilities` is usually produced by a struct declared with the `optional_bindings!` macro
+/// and is used to log which symbols a shared object provides and to branch on them at runtime.
+///
+/// ```
+/// use dynlink::optional::Capabilities;
+///
+/// fn main() {
+///     let capabilities = Capabilities::from_iter([("sum_of", true), ("sum_of_v2", false)]);
+///
+///     assert!(capabilities.contains("sum_of"));
+///     assert!(!capabilities.contains("sum_of_v2"));
+///     assert_eq!(&["sum_of_v2"], capabilities.missing());
+///
+///     // symbols declared with aliases are reported by their declared names
+///     let capabilities = Capabilities::from_iter([("sum", Some("sum_of")), ("product", None)]);
+///
+///     assert!(capabilities.contains("sum"));
+///     assert_eq!(Some("sum_of"), capabilities.resolved_as("sum"));
+/// }
+/// ```
+pub struct Capabilities {
+    present: Vec<&'static str>,
+    resolved: Vec<&'static str>,
+    missing: Vec<&'static str>,
+}
+
+impl Capabilities {
+    /// Checks whether the symbol specified by `symbol` is present.
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.present.contains(&symbol)
+    }
+
+    /// Checks whether all symbols are present.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    /// Returns names of present symbols in declaration order.
+    pub fn present(&self) -> &[&'static str] {
+        &self.present
+    }
+
+    /// Returns names of missing symbols in declaration order.
+    pub fn missing(&self) -> &[&'static str] {
+        &self.missing
+    }
+
+    /// Returns the name the present symbol specified by `symbol` was found by,
+    /// which differs from `symbol` when it is declared with aliases.
+    pub fn resolved_as(&self, symbol: &str) -> Option<&'static str> {
+        let index = self.present.iter().position(|it| *it == symbol)?;
+        Some(self.resolved[index])
+    }
+}
+
+impl iter::FromIterator<(&'static str, bool)> for Capabilities {
+    fn from_iter<I: IntoIterator<Item = (&'static str, bool)>>(iter: I) -> Self {
+        let mut present = Vec::new();
+        let mut missing = Vec::new();
+
+        for (symbol, is_present) in iter {
+            if is_present {
+                present.push(symbol);
+            } else {
+                missing.push(symbol);
+            }
+        }
+
+        Self {
+            resolved: present.clone(),
+            present,
+            missing,
+        }
+    }
+}
+
+impl iter::FromIterator<(&'static str, Option<&'static str>)> for Capabilities {
+    fn from_iter<I: IntoIterator<Item = (&'static str, Option<&'static str>)>>(iter: I) -> Self {
+        let mut present = Vec::new();
+        let mut resolved = Vec::new();
+        let mut missing = Vec::new();
+
+        for (symbol, resolved_as) in iter {
+            match resolved_as {
+                Some(name) => {
+                    present.push(symbol);
+                    resolved.push(name);
+                }
+                None => missing.push(symbol),
+            }
+        }
+
+        Self {
+            present,
+            resolved,
+            missing,
+        }
+    }
+}
+
+impl Clone for Capabilities {
+    fn clone(&self) -> Self {
+        Self {
+            present: self.present.clone(),
+            resolved: self.resolved.clone(),
+            missing: self.missing.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Capabilities {{ present: {:?}, missing: {:?} }}",
+            self.present, self.missing
+        ))
+    }
+}
+
+impl fmt::Display for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "{} of {} symbols present",
+            self.present.len(),
+            self.present.len() + self.missing.len()
+        ))?;
+
+        if !self.missing.is_empty() {
+            f.write_fmt(format_args!(", missing: {}", self.missing.join(", ")))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod unittest {
+    use crate::optional::Capabilities;
+
+    #[test]
+    pub fn capabilities_splits_present_and_missing_symbols_test() {
+        let capabilities = Capabilities::from_iter([("a", true), ("b", false), ("c", true)]);
+
+        assert_eq!(&["a", "c"], capabilities.present());
+        assert_eq!(&["b"], capabilities.missing());
+        assert!(!capabilities.is_complete());
+    }
+
+    #[test]
+    pub fn capabilities_displays_missing_symbols_test() {
+        let capabilities = Capabilities::from_iter([("a", true), ("b", false), ("c", false)]);
+        assert_eq!(
+            "1 of 3 symbols present, missing: b, c",
+            capabilities.to_string()
+        );
+
+        let capabilities = Capabilities::from_iter([("a", true)]);
+        assert_eq!("1 of 1 symbols present", capabilities.to_string());
+    }
+
+    #[test]
+    pub fn capabilities_reports_resolved_names_of_present_symbols_test() {
+        let capabilities = Capabilities::from_iter([("a", Some("a_v2")), ("b", None)]);
+
+        assert_eq!(&["a"], capabilities.present());
+        assert_eq!(&["b"], capabilities.missing());
+        assert_eq!(Some("a_v2"), capabilities.resolved_as("a"));
+        assert_eq!(None, capabilities.resolved_as("b"));
+    }
+}
@@ -0,0 +1,87 @@
+use pointersized::PointerSized;
+
+use crate::api::Handle;
+
+/// Looks up an optional symbol from the shared object file's symbol table by name
+/// and leaks it as the type it represents.
+///
+/// Returns `None` if the symbol could not be found or its address is null.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink::{api::Handle, optional};
+///
+/// // sum.c
+/// //
+/// // int sum_of(int a, int b) {
+/// //    return a + b;
+/// // }
+///
+/// fn main() {
+///     unsafe {
+///         let handle = Handle::open("libsum.so")
+///             .expect("libsum handle was not opened");
+///
+///         let sum_fn = optional::lookup::<extern "C" fn(i32, i32) -> i32>(&handle, "sum_of");
+///
+///         if let Some(sum_fn) = sum_fn {
+///             assert_eq!(2, sum_fn(1, 1));
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Returning value of the type `T` (which can be copied) must not outlive the handle that owns it.
+/// Type `T` must be ABI compatible with the type of symbol from the shared object.
+pub unsafe fn lookup<T: PointerSized>(handle: &Handle, symbol: &str) -> Option<T> {
+    let symbol = handle.lookup::<T>(symbol).ok()?;
+
+    if !symbol.clone().leak_as_raw().is_null() {
+        Some(symbol.leak())
+    } else {
+        None
+    }
+}
+
+/// Looks up the first optional symbol of `symbols` that is found in the shared object file's
+/// symbol table with a non-null address and leaks it as the type it represents
+/// together with the name it was found by.
+///
+/// Returns `None` if none of the symbols could be found with a non-null address.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink::{api::Handle, optional};
+///
+/// fn main() {
+///     unsafe {
+///         let handle = Handle::open("libsum.so")
+///             .expect("libsum handle was not opened");
+///
+///         let sum_fn = optional::lookup_first::<extern "C" fn(i32, i32) -> i32>(
+///             &handle,
+///             &["sum_of_v2", "sum_of"],
+///         );
+///
+///         if let Some((name, sum_fn)) = sum_fn {
+///             println!("{} returned {}", name, sum_fn(1, 1));
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Returning value of the type `T` (which can be copied) must not outlive the handle that owns it.
+/// Type `T` must be ABI compatible with the type of the symbol found from the shared object.
+pub unsafe fn lookup_first<'name, T: PointerSized>(
+    handle: &Handle,
+    symbols: &[&'name str],
+) -> Option<(&'name str, T)> {
+    let (name, symbol) = handle.lookup_first::<T>(symbols).ok()?;
+    Some((name, symbol.leak()))
+}
@@ -0,0 +1,124 @@
+/// Declares a struct of optional symbols that are looked up from a shared object file.
+///
+/// Each declared field of a pointer-sized type `T` becomes a field of type `Option<T>`
+/// that is `Some` when the symbol of the same name is present and `None` when it is absent.
+/// A field declared with `as ["name", ..]` is looked up by the first of these aliases
+/// that is present instead of its name.
+/// The struct gets an unsafe `load` constructor, a `SYMBOLS` constant listing all declared
+/// symbol names and a `capabilities` method returning a `Capabilities` report.
+/// Aliased fields are reported by their names, and `Capabilities::resolved_as` returns
+/// the alias they were found by.
+///
+/// # Usage
+///
+/// ```no_run
+/// use dynlink::api::Handle;
+///
+/// // sum.c
+/// //
+/// // int sum_of(int a, int b) {
+/// //    return a + b;
+/// // }
+///
+/// dynlink::optional_bindings! {
+///     /// Optional libsum functions.
+///     pub struct LibSumFns {
+///         pub sum_of: extern "C" fn(i32, i32) -> i32,
+///         pub sum_of_three: extern "C" fn(i32, i32, i32) -> i32,
+///         pub sum: extern "C" fn(i32, i32) -> i32 as ["sum_of_v2", "sum_of"],
+///     }
+/// }
+///
+/// fn main() {
+///     unsafe {
+///         let handle = Handle::open("libsum.so")
+///             .expect("libsum handle was not opened");
+///
+///         let fns = LibSumFns::load(&handle);
+///         println!("{}", fns.capabilities());
+///
+///         if let Some(sum_of_three) = fns.sum_of_three {
+///             println!("{}", sum_of_three(1, 1, 1));
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// The declared struct must not outlive the handle it is loaded from and the declared field
+/// types must be ABI compatible with the symbols from the shared object.
+#[macro_export]
+macro_rules! optional_bindings {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident: $ty:ty $(as [$($alias:literal),+ $(,)?])?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: ::core::option::Option<$ty>,
+            )*
+            __dynlink_symbols: ::std::vec::Vec<&'static str>,
+        }
+
+        impl $name {
+            /// Names of all declared symbols in declaration order.
+            #[allow(dead_code)]
+            $vis const SYMBOLS: &'static [&'static str] = &[$(::core::stringify!($field)),*];
+
+            /// Looks up all declared symbols from the shared object file's symbol table.
+            ///
+            /// # Safety
+            ///
+            /// Returning value must not outlive the handle it is loaded from.
+            /// Declared field types must be ABI compatible with the symbols from the shared object.
+            #[allow(dead_code)]
+            $vis unsafe fn load(handle: &$crate::api::Handle) -> Self {
+                let mut symbols = ::std::vec::Vec::new();
+
+                Self {
+                    $(
+                        $field: match $crate::optional_bindings!(@lookup handle, $field $([$($alias),+])?) {
+                            ::core::option::Option::Some((symbol, found)) => {
+                                symbols.push(symbol);
+                                ::core::option::Option::Some(found)
+                            }
+                            ::core::option::Option::None => {
+                                symbols.push(::core::stringify!($field));
+                                ::core::option::Option::None
+                            }
+                        },
+                    )*
+                    __dynlink_symbols: symbols,
+                }
+            }
+
+            /// Returns a report of present and missing symbols.
+            #[allow(dead_code)]
+            $vis fn capabilities(&self) -> $crate::optional::Capabilities {
+                let fields = [$((::core::stringify!($field), self.$field.is_some())),*];
+
+                <$crate::optional::Capabilities as ::core::iter::FromIterator<_>>::from_iter(
+                    fields
+                        .into_iter()
+                        .zip(self.__dynlink_symbols.iter().copied())
+                        .map(|((field, is_present), symbol)| (field, is_present.then_some(symbol)))
+                )
+            }
+        }
+    };
+
+    (@lookup $handle:ident, $field:ident) => {
+        $crate::optional::lookup_first($handle, &[::core::stringify!($field)])
+    };
+    (@lookup $handle:ident, $field:ident [$($alias:literal),+]) => {
+        $crate::optional::lookup_first($handle, &[$($alias),+])
+    };
+}
@@ -0,0 +1,6 @@
+mod capabilities;
+mod lookup;
+mod macros;
+
+pub use capabilities::Capabilities;
+pub use lookup::{lookup, lookup_first};
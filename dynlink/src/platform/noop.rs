@@ -62,7 +62,11 @@ impl error::Error for PlatformLinkingError {}
 pub struct PlatformSymbol<'symtab, T: PointerSized>(marker::PhantomData<&'symtab T>);
 
 impl<'symtab, T: PointerSized> PlatformSymbol<'symtab, T> {
-    pub unsafe fn apply<R>(&self, _: impl Fn(T) -> R) -> R {
+    pub unsafe fn apply<R>(&self, _: impl FnOnce(T) -> R) -> R {
+        compile_error!("Unsupported platform")
+    }
+
+    pub unsafe fn get(&self) -> &T {
         compile_error!("Unsupported platform")
     }
 
@@ -73,6 +77,10 @@ impl<'symtab, T: PointerSized> PlatformSymbol<'symtab, T> {
     pub unsafe fn leak_as_raw(self) -> *mut ffi::c_void {
         compile_error!("Unsupported platform")
     }
+
+    pub unsafe fn cast<U: PointerSized>(self) -> PlatformSymbol<'symtab, U> {
+        compile_error!("Unsupported platform")
+    }
 }
 
 unsafe impl<'symtab, T: PointerSized> Send for PlatformSymbol<'symtab, T> {}
@@ -103,6 +111,10 @@ impl PlatformHandle {
     ) -> Result<PlatformSymbol<'_, T>, PlatformLinkingError> {
         compile_error!("Unsupported platform")
     }
+
+    pub unsafe fn close(self) -> Result<(), PlatformLinkingError> {
+        compile_error!("Unsupported platform")
+    }
 }
 
 unsafe impl Send for PlatformHandle {}
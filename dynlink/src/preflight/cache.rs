@@ -0,0 +1,91 @@
+use std::{fs, path};
+
+use crate::object::ObjectError;
+
+const CACHE_PATH: &str = "/etc/ld.so.cache";
+
+const OLD_MAGIC: &[u8] = b"ld.so-1.7.0";
+const NEW_MAGIC: &[u8] = b"glibc-ld.so.cache1.1";
+
+const OLD_HEADER_SIZE: usize = 16;
+const OLD_ENTRY_SIZE: usize = 12;
+const NEW_HEADER_SIZE: usize = 48;
+const NEW_ENTRY_SIZE: usize = 24;
+
+/// Represents the library names and paths of the glibc loader cache.
+pub(super) struct LoaderCache {
+    entries: Vec<(String, path::PathBuf)>,
+}
+
+impl LoaderCache {
+    /// Reads `/etc/ld.so.cache`, returning an empty cache if it is absent or has an unknown format.
+    pub(super) fn read() -> Self {
+        let entries = fs::read(CACHE_PATH)
+            .ok()
+            .and_then(|data| Self::parse(&data).ok())
+            .unwrap_or_default();
+
+        Self { entries }
+    }
+
+    /// Parses the new format of the cache, which may follow the old one.
+    fn parse(data: &[u8]) -> Result<Vec<(String, path::PathBuf)>, ObjectError> {
+        let u32_at = |offset: usize| -> Result<usize, ObjectError> {
+            data.get(offset..offset + 4)
+                .map(|it| u32::from_ne_bytes(it.try_into().unwrap()) as usize)
+                .ok_or(ObjectError::Malformed("loader cache"))
+        };
+
+        let header = if data.starts_with(NEW_MAGIC) {
+            0
+        } else if data.starts_with(OLD_MAGIC) {
+            let end = OLD_HEADER_SIZE + u32_at(OLD_MAGIC.len() + 1)? * OLD_ENTRY_SIZE;
+            end.next_multiple_of(8)
+        } else {
+            return Err(ObjectError::UnknownFormat);
+        };
+
+        if !data[header..].starts_with(NEW_MAGIC) {
+            return Err(ObjectError::UnknownFormat);
+        }
+
+        // string offsets are relative to the header of the new format
+        let string_at = |offset: usize| -> Result<String, ObjectError> {
+            let tail = data
+                .get(header + offset..)
+                .ok_or(ObjectError::Malformed("loader cache"))?;
+
+            let end = tail
+                .iter()
+                .position(|it| *it == 0)
+                .ok_or(ObjectError::Malformed("loader cache"))?;
+
+            Ok(String::from_utf8_lossy(&tail[..end]).into_owned())
+        };
+
+        let nlibs = u32_at(header + NEW_MAGIC.len())?;
+
+        let mut entries = Vec::with_capacity(nlibs);
+        for i in 0..nlibs {
+            let entry = header + NEW_HEADER_SIZE + i * NEW_ENTRY_SIZE;
+
+            let key = string_at(u32_at(entry + 4)?)?;
+            let value = string_at(u32_at(entry + 8)?)?;
+
+            entries.push((key, path::PathBuf::from(value)));
+        }
+
+        Ok(entries)
+    }
+
+    /// Returns the paths cached for the library `name`.
+    pub(super) fn lookup<'cache>(
+        &'cache self,
+        name: &'cache str,
+    ) -> impl Iterator<Item = &'cache path::Path> + 'cache {
+        self.entries
+            .iter()
+            .filter(move |it| it.0 == name)
+            .map(|it| it.1.as_path())
+    }
+}
@@ -0,0 +1,202 @@
+use std::{collections, fs, path};
+
+use crate::{
+    object::{elf::ElfFile, ObjectError},
+    preflight::{
+        search::{Requester, Search},
+        Dependency, Preflight, UnresolvedSymbol,
+    },
+};
+
+/// Represents a shared object file of the tree that has already been read.
+struct Loaded {
+    name: String,
+    soname: Option<String>,
+    canonical: path::PathBuf,
+    path: path::PathBuf,
+    file: ElfFile<'static>,
+}
+
+/// Represents the state of a dependency tree walk.
+struct Walk {
+    search: Search,
+    loaded: Vec<Loaded>,
+    missing: Vec<String>,
+}
+
+impl Walk {
+    /// Returns the dependencies `names` needed by the last of `requesters`.
+    fn needed(&mut self, names: Vec<String>, requesters: &mut Vec<Requester>) -> Vec<Dependency> {
+        names
+            .into_iter()
+            .map(|name| self.resolve(name, requesters))
+            .collect()
+    }
+
+    fn resolve(&mut self, name: String, requesters: &mut Vec<Requester>) -> Dependency {
+        let repeated = |path: Option<path::PathBuf>, name: String| Dependency {
+            name,
+            path,
+            needed: Vec::new(),
+            is_repeated: true,
+        };
+
+        // the loader reuses an already loaded object with the same name or soname
+        let loaded = self
+            .loaded
+            .iter()
+            .find(|it| it.name == name || it.soname.as_deref() == Some(name.as_str()));
+
+        if let Some(loaded) = loaded {
+            return repeated(Some(loaded.path.clone()), name);
+        }
+
+        if self.missing.contains(&name) {
+            return repeated(None, name);
+        }
+
+        let Some((path, file)) = self.search.find(&name, requesters) else {
+            self.missing.push(name.clone());
+
+            return Dependency {
+                name,
+                path: None,
+                needed: Vec::new(),
+                is_repeated: false,
+            };
+        };
+
+        let canonical = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+        if let Some(loaded) = self.loaded.iter().find(|it| it.canonical == canonical) {
+            return repeated(Some(loaded.path.clone()), name);
+        }
+
+        let names = file.needed().unwrap_or_default();
+        requesters.push(Requester::of(&file, &path));
+
+        self.loaded.push(Loaded {
+            name: name.clone(),
+            soname: file.soname().ok().flatten(),
+            canonical,
+            path: path.clone(),
+            file,
+        });
+
+        let needed = self.needed(names, requesters);
+        requesters.pop();
+
+        Dependency {
+            name,
+            path: Some(path),
+            needed,
+            is_repeated: false,
+        }
+    }
+
+    /// Returns undefined symbols of the tree that no shared object file of the tree defines.
+    fn unresolved(&self) -> Vec<UnresolvedSymbol> {
+        let symbols = self
+            .loaded
+            .iter()
+            .map(|it| it.file.symbols().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        let defined = symbols
+            .iter()
+            .flatten()
+            .filter(|it| it.is_defined())
+            .map(|it| it.name())
+            .collect::<collections::HashSet<_>>();
+
+        let mut unresolved = Vec::new();
+
+        for (loaded, symbols) in self.loaded.iter().zip(&symbols) {
+            for symbol in symbols {
+                let is_unresolved = !symbol.is_defined()
+                    && !symbol.is_weak()
+                    && !symbol.name().is_empty()
+                    && !defined.contains(symbol.name());
+
+                if is_unresolved {
+                    unresolved.push(UnresolvedSymbol {
+                        symbol: symbol.name().to_owned(),
+                        object: loaded.path.clone(),
+                    });
+                }
+            }
+        }
+
+        unresolved
+    }
+}
+
+/// Resolves the dependency tree of the shared object file specified by `path`
+/// without loading it, reporting every missing library and unresolved symbol.
+///
+/// # Usage
+///
+/// `check` is used to diagnose a failed `Handle::open`, whose error names
+/// only the first missing dependency.
+///
+/// ```no_run
+/// use dynlink::preflight;
+///
+/// fn main() {
+///     let preflight = preflight::check("libsum.so")
+///         .expect("libsum was not read");
+///
+///     if !preflight.is_complete() {
+///         eprint!("{}", preflight);
+///     }
+/// }
+/// ```
+///
+/// # Notes
+///
+/// The files are only read, so no initialization routines are executed.
+///
+/// Dependencies are searched the way the glibc loader does: in `DT_RPATH` of the shared
+/// object and the objects that need it (unless it has `DT_RUNPATH`), `LD_LIBRARY_PATH`,
+/// `DT_RUNPATH`, `/etc/ld.so.cache` and the default directories, expanding `$ORIGIN`, `$LIB`
+/// and `$PLATFORM`. `$ORIGIN` of `LD_LIBRARY_PATH` is the directory of the running executable,
+/// and `$LIB` is the multiarch library directory where it exists (e.g. `lib/x86_64-linux-gnu`).
+/// Candidates of a different class or machine are skipped.
+///
+/// Symbols are resolved by name only, ignoring versions, against the objects of the tree.
+/// Symbols provided by the executable of the process that would load the shared object,
+/// or by shared objects it has already loaded, are reported as unresolved.
+pub fn check(path: impl AsRef<path::Path>) -> Result<Preflight, ObjectError> {
+    let path = path.as_ref();
+    let file = ElfFile::open(path)?;
+
+    let mut walk = Walk {
+        search: Search::new(&file),
+        loaded: Vec::new(),
+        missing: Vec::new(),
+    };
+
+    let names = file.needed()?;
+    let mut requesters = vec![Requester::of(&file, path)];
+
+    walk.loaded.push(Loaded {
+        name: path.to_string_lossy().into_owned(),
+        soname: file.soname()?,
+        canonical: fs::canonicalize(path)?,
+        path: path.to_path_buf(),
+        file,
+    });
+
+    let needed = walk.needed(names, &mut requesters);
+    let unresolved = walk.unresolved();
+
+    Ok(Preflight {
+        root: Dependency {
+            name: path.to_string_lossy().into_owned(),
+            path: Some(path.to_path_buf()),
+            needed,
+            is_repeated: false,
+        },
+        missing: walk.missing,
+        unresolved,
+    })
+}
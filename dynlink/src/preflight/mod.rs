@@ -0,0 +1,8 @@
+mod cache;
+mod check;
+mod report;
+mod search;
+
+pub use crate::object::ObjectError;
+pub use check::check;
+pub use report::{Dependency, Preflight, UnresolvedSymbol};
@@ -0,0 +1,199 @@
+use std::{fmt, path};
+
+/// Represents a shared object file in the dependency tree and the dependencies it needs.
+pub struct Dependency {
+    pub(super) name: String,
+    pub(super) path: Option<path::PathBuf>,
+    pub(super) needed: Vec<Dependency>,
+    pub(super) is_repeated: bool,
+}
+
+impl Dependency {
+    /// Returns the name of the dependency as listed in `DT_NEEDED`,
+    /// or the checked path for the root of the tree.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the path the dependency resolves to, or `None` if it is missing.
+    #[inline]
+    pub fn path(&self) -> Option<&path::Path> {
+        self.path.as_deref()
+    }
+
+    /// Checks whether the dependency could not be found.
+    #[inline]
+    pub fn is_missing(&self) -> bool {
+        self.path.is_none()
+    }
+
+    /// Checks whether the dependency already appears earlier in the tree,
+    /// in which case its dependencies are not repeated.
+    #[inline]
+    pub fn is_repeated(&self) -> bool {
+        self.is_repeated
+    }
+
+    /// Returns the dependencies of this dependency in `DT_NEEDED` order.
+    #[inline]
+    pub fn needed(&self) -> &[Dependency] {
+        &self.needed
+    }
+
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "{:indent$}{}",
+            "",
+            self.name,
+            indent = depth * 4
+        ))?;
+
+        match &self.path {
+            Some(path) if depth > 0 => f.write_fmt(format_args!(" => {}", path.display()))?,
+            Some(_) => {}
+            None => f.write_str(" => not found")?,
+        }
+
+        f.write_str("\n")?;
+
+        for dependency in &self.needed {
+            dependency.fmt_tree(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Clone for Dependency {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            path: self.path.clone(),
+            needed: self.needed.clone(),
+            is_repeated: self.is_repeated,
+        }
+    }
+}
+
+impl fmt::Debug for Dependency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Dependency({:?}, {:?}, {:?})",
+            self.name, self.path, self.needed
+        ))
+    }
+}
+
+/// Represents an undefined symbol of a shared object file that no object in the tree defines.
+pub struct UnresolvedSymbol {
+    pub(super) symbol: String,
+    pub(super) object: path::PathBuf,
+}
+
+impl UnresolvedSymbol {
+    /// Returns the name of the symbol.
+    #[inline]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Returns the path of the shared object file that references the symbol.
+    #[inline]
+    pub fn object(&self) -> &path::Path {
+        &self.object
+    }
+}
+
+impl Clone for UnresolvedSymbol {
+    fn clone(&self) -> Self {
+        Self {
+            symbol: self.symbol.clone(),
+            object: self.object.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for UnresolvedSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "UnresolvedSymbol({:?}, {:?})",
+            self.symbol, self.object
+        ))
+    }
+}
+
+/// Represents the result of a dependency preflight of a shared object file.
+///
+/// `Preflight` is displayed as an `ldd`-like tree followed by
+/// missing libraries and unresolved symbols.
+pub struct Preflight {
+    pub(super) root: Dependency,
+    pub(super) missing: Vec<String>,
+    pub(super) unresolved: Vec<UnresolvedSymbol>,
+}
+
+impl Preflight {
+    /// Returns the dependency tree rooted at the checked shared object file.
+    #[inline]
+    pub fn root(&self) -> &Dependency {
+        &self.root
+    }
+
+    /// Returns names of all missing dependencies in the order they were encountered.
+    #[inline]
+    pub fn missing(&self) -> &[String] {
+        &self.missing
+    }
+
+    /// Returns all undefined symbols that no shared object file of the tree defines.
+    #[inline]
+    pub fn unresolved(&self) -> &[UnresolvedSymbol] {
+        &self.unresolved
+    }
+
+    /// Checks whether all dependencies and symbols were resolved.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.unresolved.is_empty()
+    }
+}
+
+impl Clone for Preflight {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            missing: self.missing.clone(),
+            unresolved: self.unresolved.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for Preflight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Preflight({:?}, {:?}, {:?})",
+            self.root, self.missing, self.unresolved
+        ))
+    }
+}
+
+impl fmt::Display for Preflight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.root.fmt_tree(f, 0)?;
+
+        for name in &self.missing {
+            f.write_fmt(format_args!("missing library: {}\n", name))?;
+        }
+
+        for unresolved in &self.unresolved {
+            f.write_fmt(format_args!(
+                "unresolved symbol: {} ({})\n",
+                unresolved.symbol,
+                unresolved.object.display()
+            ))?;
+        }
+
+        Ok(())
+    }
+}
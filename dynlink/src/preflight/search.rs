@@ -0,0 +1,296 @@
+use std::{env, fs, path};
+
+use crate::{object::elf::ElfFile, preflight::cache::LoaderCache};
+
+const EM_386: u16 = 3;
+const EM_ARM: u16 = 40;
+const EM_X86_64: u16 = 62;
+const EM_AARCH64: u16 = 183;
+const EM_RISCV: u16 = 243;
+
+/// Represents search paths of a shared object that needs dependencies.
+pub(super) struct Requester {
+    rpath: Option<String>,
+    runpath: Option<String>,
+    origin: path::PathBuf,
+}
+
+impl Requester {
+    /// Creates search paths of the shared object `file` read from `path`.
+    pub(super) fn of(file: &ElfFile<'_>, path: &path::Path) -> Self {
+        Self {
+            rpath: file.rpath().ok().flatten(),
+            runpath: file.runpath().ok().flatten(),
+            origin: origin_of(path),
+        }
+    }
+}
+
+/// Resolves dependency names to paths the way the glibc loader does.
+pub(super) struct Search {
+    is_64: bool,
+    is_little: bool,
+    machine: u16,
+    lib: String,
+    exe_origin: path::PathBuf,
+    library_path: Vec<String>,
+    cache: LoaderCache,
+}
+
+impl Search {
+    /// Creates a search for dependencies of the root shared object `file`.
+    ///
+    /// `$ORIGIN` of `LD_LIBRARY_PATH` is expanded against the executable of the running process,
+    /// since the loader expands it against the executable of the process that reads it.
+    pub(super) fn new(file: &ElfFile<'_>) -> Self {
+        let library_path = env::var_os("LD_LIBRARY_PATH")
+            .map(|it| split(&it.to_string_lossy()))
+            .unwrap_or_default();
+
+        let exe_origin = match env::current_exe() {
+            Ok(exe) => origin_of(&exe),
+            Err(_) => path::PathBuf::from("."),
+        };
+
+        Self {
+            is_64: file.is_64(),
+            is_little: file.is_little(),
+            machine: file.machine(),
+            lib: lib_dir_of(file.machine(), file.is_64()),
+            exe_origin,
+            library_path,
+            cache: LoaderCache::read(),
+        }
+    }
+
+    /// Finds the dependency `name` needed by the last of `requesters`, where the preceding
+    /// ones are the shared objects that transitively need it, starting from the root.
+    ///
+    /// Returns the path and the read file of the first compatible candidate.
+    pub(super) fn find(
+        &self,
+        name: &str,
+        requesters: &[Requester],
+    ) -> Option<(path::PathBuf, ElfFile<'static>)> {
+        if name.contains('/') {
+            return self.candidate(path::PathBuf::from(name));
+        }
+
+        let requester = requesters.last()?;
+
+        // DT_RPATH of the requester and the objects that need it is used only without DT_RUNPATH
+        if requester.runpath.is_none() {
+            for requester in requesters.iter().rev() {
+                if requester.runpath.is_some() {
+                    continue;
+                }
+
+                if let Some(rpath) = &requester.rpath {
+                    if let Some(found) = self.find_in(name, &split(rpath), &requester.origin) {
+                        return Some(found);
+                    }
+                }
+            }
+        }
+
+        if let Some(found) = self.find_in(name, &self.library_path, &self.exe_origin) {
+            return Some(found);
+        }
+
+        if let Some(runpath) = &requester.runpath {
+            if let Some(found) = self.find_in(name, &split(runpath), &requester.origin) {
+                return Some(found);
+            }
+        }
+
+        for path in self.cache.lookup(name) {
+            if let Some(found) = self.candidate(path.to_path_buf()) {
+                return Some(found);
+            }
+        }
+
+        self.find_in(name, &self.default_dirs(), &self.exe_origin)
+    }
+
+    fn find_in(
+        &self,
+        name: &str,
+        dirs: &[String],
+        origin: &path::Path,
+    ) -> Option<(path::PathBuf, ElfFile<'static>)> {
+        dirs.iter()
+            .filter_map(|dir| self.expand(dir, origin))
+            .find_map(|dir| self.candidate(dir.join(name)))
+    }
+
+    /// Reads the candidate at `path` if it is an ELF file loadable with the root.
+    fn candidate(&self, path: path::PathBuf) -> Option<(path::PathBuf, ElfFile<'static>)> {
+        match ElfFile::open(&path) {
+            Ok(file)
+                if file.is_64() == self.is_64
+                    && file.is_little() == self.is_little
+                    && file.machine() == self.machine =>
+            {
+                Some((path, file))
+            }
+            _ => None,
+        }
+    }
+
+    /// Expands the `$ORIGIN`, `$LIB` and `$PLATFORM` tokens of `dir`,
+    /// returning `None` if it has a token that can not be expanded.
+    fn expand(&self, dir: &str, origin: &path::Path) -> Option<path::PathBuf> {
+        let platform = self.platform();
+
+        let mut expanded = String::with_capacity(dir.len());
+        let mut rest = dir;
+
+        while let Some(start) = rest.find('$') {
+            expanded.push_str(&rest[..start]);
+            rest = &rest[start + 1..];
+
+            let (token, len) = match rest.strip_prefix('{') {
+                Some(braced) => {
+                    let end = braced.find('}')?;
+                    (&braced[..end], end + 2)
+                }
+                None => {
+                    let end = rest
+                        .find(|it: char| !it.is_ascii_alphanumeric() && it != '_')
+                        .unwrap_or(rest.len());
+                    (&rest[..end], end)
+                }
+            };
+
+            match token {
+                "ORIGIN" => expanded.push_str(&origin.to_string_lossy()),
+                "LIB" => expanded.push_str(&self.lib),
+                "PLATFORM" => expanded.push_str(platform?),
+                _ => return None,
+            }
+
+            rest = &rest[len..];
+        }
+
+        expanded.push_str(rest);
+        Some(path::PathBuf::from(expanded))
+    }
+
+    fn platform(&self) -> Option<&'static str> {
+        match self.machine {
+            EM_386 => Some("i686"),
+            EM_ARM => Some("arm"),
+            EM_X86_64 => Some("x86_64"),
+            EM_AARCH64 => Some("aarch64"),
+            EM_RISCV => Some("riscv64"),
+            _ => None,
+        }
+    }
+
+    /// Returns the trusted directories of the loader, including multiarch ones.
+    fn default_dirs(&self) -> Vec<String> {
+        let mut dirs = Vec::new();
+
+        if let Some(triple) = triple_of(self.machine) {
+            dirs.push(format!("/lib/{}", triple));
+            dirs.push(format!("/usr/lib/{}", triple));
+        }
+
+        if self.is_64 {
+            dirs.push(String::from("/lib64"));
+            dirs.push(String::from("/usr/lib64"));
+        }
+
+        dirs.push(String::from("/lib"));
+        dirs.push(String::from("/usr/lib"));
+        dirs
+    }
+}
+
+/// Returns the multiarch triple of the ELF `machine`.
+fn triple_of(machine: u16) -> Option<&'static str> {
+    match machine {
+        EM_386 => Some("i386-linux-gnu"),
+        EM_ARM => Some("arm-linux-gnueabihf"),
+        EM_X86_64 => Some("x86_64-linux-gnu"),
+        EM_AARCH64 => Some("aarch64-linux-gnu"),
+        EM_RISCV => Some("riscv64-linux-gnu"),
+        _ => None,
+    }
+}
+
+/// Returns the value of `$LIB`, which the loader is built with as the library directory
+/// of the system (e.g. `lib/x86_64-linux-gnu` on multiarch systems or `lib64` otherwise).
+///
+/// It is derived from the first default directory under `/` that exists.
+fn lib_dir_of(machine: u16, is_64: bool) -> String {
+    let mut dirs = Vec::new();
+
+    if let Some(triple) = triple_of(machine) {
+        dirs.push(format!("lib/{}", triple));
+    }
+
+    if is_64 {
+        dirs.push(String::from("lib64"));
+    }
+
+    dirs.into_iter()
+        .find(|it| path::Path::new("/").join(it).is_dir())
+        .unwrap_or_else(|| String::from("lib"))
+}
+
+/// Splits a colon-separated list of directories, where empty entries mean the current directory.
+fn split(dirs: &str) -> Vec<String> {
+    dirs.split([':', ';'])
+        .map(|it| if it.is_empty() { "." } else { it })
+        .map(String::from)
+        .collect()
+}
+
+/// Returns the directory of the shared object file at `path` for `$ORIGIN` expansion.
+fn origin_of(path: &path::Path) -> path::PathBuf {
+    let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => path::PathBuf::from("."),
+    }
+}
+
+#[cfg(all(test, target_os = "linux", target_arch = "x86_64"))]
+mod unittest {
+    use std::{env, path};
+
+    use crate::{object::elf::ElfFile, preflight::search::Search};
+
+    pub const LIBSUM: &str = "tests/resource/libsum-x86_64.so";
+
+    fn search() -> Search {
+        Search::new(&ElfFile::open(LIBSUM).expect("Fixture was not read"))
+    }
+
+    #[test]
+    pub fn search_expands_lib_to_existing_library_directory_test() {
+        let search = search();
+        let lib = search
+            .expand("/$LIB", path::Path::new("."))
+            .expect("$LIB was not expanded");
+
+        assert!(lib.is_dir());
+
+        if path::Path::new("/lib/x86_64-linux-gnu").is_dir() {
+            assert_eq!(path::Path::new("/lib/x86_64-linux-gnu"), lib);
+        }
+    }
+
+    #[test]
+    pub fn search_expands_origin_of_library_path_against_executable_test() {
+        let search = search();
+        let exe = env::current_exe().expect("Executable was not found");
+
+        assert_eq!(
+            exe.canonicalize().unwrap().parent().unwrap(),
+            search.exe_origin
+        );
+    }
+}
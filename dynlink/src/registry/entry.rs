@@ -0,0 +1,40 @@
+use std::{fmt, path};
+
+/// Represents a snapshot of a shared object file opened through the registry.
+pub struct RegistryEntry {
+    pub(super) path: path::PathBuf,
+    pub(super) strong_count: usize,
+}
+
+impl RegistryEntry {
+    /// Returns the canonical path of the shared object file, or the path it was opened with
+    /// if it is a bare name or could not be canonicalized.
+    #[inline]
+    pub fn path(&self) -> &path::Path {
+        &self.path
+    }
+
+    /// Returns the number of `Arc<Handle>` owners at the time of the snapshot.
+    #[inline]
+    pub fn strong_count(&self) -> usize {
+        self.strong_count
+    }
+}
+
+impl Clone for RegistryEntry {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            strong_count: self.strong_count,
+        }
+    }
+}
+
+impl fmt::Debug for RegistryEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "RegistryEntry({:?}, {})",
+            self.path, self.strong_count
+        ))
+    }
+}
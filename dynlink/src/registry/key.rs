@@ -0,0 +1,105 @@
+use std::{ffi, fs, hash, io, path};
+
+/// Identifies a shared object file in the registry.
+///
+/// Files are identified by device and inode on unix, so hard links and different
+/// spellings of the same path share a handle. Otherwise the canonical path is used.
+///
+/// Bare names (e.g. `libsum.so`) are resolved by the platform loader search rather than
+/// against the current directory, so they are identified by their spelling only.
+pub(super) enum RegistryKey {
+    #[cfg(unix)]
+    Inode(u64, u64),
+    Path(path::PathBuf),
+    Name(ffi::OsString),
+}
+
+impl RegistryKey {
+    /// Creates a key and returns it with the path the shared object file is opened by.
+    pub(super) fn of(path: &path::Path) -> (Self, path::PathBuf) {
+        if is_bare(path) {
+            return (Self::Name(path.as_os_str().to_owned()), path.to_path_buf());
+        }
+
+        match fs::canonicalize(path) {
+            Ok(canonical) => match Self::identity(&canonical) {
+                Ok(key) => (key, canonical),
+                Err(_) => (Self::Path(canonical.clone()), canonical),
+            },
+            Err(_) => (Self::Path(path.to_path_buf()), path.to_path_buf()),
+        }
+    }
+
+    #[cfg(unix)]
+    fn identity(canonical: &path::Path) -> io::Result<Self> {
+        use std::os::unix::fs::MetadataExt;
+
+        let metadata = fs::metadata(canonical)?;
+        Ok(Self::Inode(metadata.dev(), metadata.ino()))
+    }
+
+    #[cfg(not(unix))]
+    fn identity(canonical: &path::Path) -> io::Result<Self> {
+        Ok(Self::Path(canonical.to_path_buf()))
+    }
+}
+
+impl PartialEq for RegistryKey {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            #[cfg(unix)]
+            (Self::Inode(dev, ino), Self::Inode(other_dev, other_ino)) => {
+                dev == other_dev && ino == other_ino
+            }
+            (Self::Path(path), Self::Path(other_path)) => path == other_path,
+            (Self::Name(name), Self::Name(other_name)) => name == other_name,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for RegistryKey {}
+
+impl hash::Hash for RegistryKey {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        match self {
+            #[cfg(unix)]
+            Self::Inode(dev, ino) => {
+                state.write_u8(0);
+                dev.hash(state);
+                ino.hash(state);
+            }
+            Self::Path(path) => {
+                state.write_u8(1);
+                path.hash(state);
+            }
+            Self::Name(name) => {
+                state.write_u8(2);
+                name.hash(state);
+            }
+        }
+    }
+}
+
+/// Returns `true` if `path` has no directory components, so the platform loader searches for it.
+fn is_bare(path: &path::Path) -> bool {
+    let mut components = path.components();
+
+    matches!(components.next(), Some(path::Component::Normal(_))) && components.next().is_none()
+}
+
+#[cfg(test)]
+mod unittest {
+    use std::path;
+
+    use crate::registry::key::is_bare;
+
+    #[test]
+    pub fn registry_key_treats_names_without_directories_as_bare_test() {
+        assert!(is_bare(path::Path::new("libsum.so")));
+
+        assert!(!is_bare(path::Path::new("./libsum.so")));
+        assert!(!is_bare(path::Path::new("lib/libsum.so")));
+        assert!(!is_bare(path::Path::new("/usr/lib/libsum.so")));
+    }
+}
@@ -0,0 +1,6 @@
+mod entry;
+mod key;
+mod shared;
+
+pub use entry::RegistryEntry;
+pub use shared::{entries, open_shared};
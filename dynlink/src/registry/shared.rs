@@ -0,0 +1,105 @@
+use std::{collections, path, sync};
+
+use crate::{
+    api::{Handle, LinkingError},
+    registry::{key::RegistryKey, RegistryEntry},
+};
+
+struct Registered {
+    path: path::PathBuf,
+    handle: sync::Weak<Handle>,
+}
+
+static REGISTRY: sync::LazyLock<sync::Mutex<collections::HashMap<RegistryKey, Registered>>> =
+    sync::LazyLock::new(|| sync::Mutex::new(collections::HashMap::new()));
+
+fn lock() -> sync::MutexGuard<'static, collections::HashMap<RegistryKey, Registered>> {
+    REGISTRY
+        .lock()
+        .unwrap_or_else(sync::PoisonError::into_inner)
+}
+
+/// Opens shared object file specified by `path` or returns the handle
+/// that is already opened through the registry for the same file.
+///
+/// # Usage
+///
+/// Independent crates that open the same shared object file share one handle,
+/// which is closed when the last `Arc<Handle>` is dropped.
+///
+/// ```no_run
+/// use std::sync::Arc;
+///
+/// use dynlink::registry;
+///
+/// fn main() {
+///     unsafe {
+///         let handle = registry::open_shared("./libsum.so")
+///             .expect("libsum handle was not opened");
+///
+///         let shared = registry::open_shared("../lib/libsum.so")
+///             .expect("libsum handle was not opened");
+///
+///         assert!(Arc::ptr_eq(&handle, &shared));
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Shared object initialization routines that are executed when this
+/// function is called may be UB.
+///
+/// # Notes
+///
+/// The path is canonicalized and, on unix, the file is identified by device and inode.
+/// The shared object file is opened by the canonical path, so the file that was identified
+/// is the one that is loaded.
+///
+/// Bare names (e.g. `libsum.so`) are resolved by the platform loader search rather than
+/// against the current directory, so they are deduplicated by their spelling only
+/// and do not share a handle with paths to the same file.
+///
+/// The registry is locked while the shared object file is opened, so initialization
+/// routines must not call this function.
+pub unsafe fn open_shared(path: impl AsRef<path::Path>) -> Result<sync::Arc<Handle>, LinkingError> {
+    let (key, resolved) = RegistryKey::of(path.as_ref());
+
+    let mut registry = lock();
+    registry.retain(|_, registered| registered.handle.strong_count() > 0);
+
+    if let Some(handle) = registry.get(&key).and_then(|it| it.handle.upgrade()) {
+        return Ok(handle);
+    }
+
+    let handle = sync::Arc::new(Handle::open(&resolved)?);
+    registry.insert(
+        key,
+        Registered {
+            path: resolved,
+            handle: sync::Arc::downgrade(&handle),
+        },
+    );
+
+    Ok(handle)
+}
+
+/// Returns a snapshot of shared object files opened through the registry
+/// that have at least one owner, sorted by path.
+pub fn entries() -> Vec<RegistryEntry> {
+    let registry = lock();
+
+    let mut entries = registry
+        .values()
+        .filter_map(|registered| match registered.handle.strong_count() {
+            0 => None,
+            strong_count => Some(RegistryEntry {
+                path: registered.path.clone(),
+                strong_count,
+            }),
+        })
+        .collect::<Vec<_>>();
+
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    entries
+}
@@ -0,0 +1,3 @@
+mod verify;
+
+pub use verify::{Residency, UnloadError};
@@ -0,0 +1,56 @@
+use dynlink_posix::unload::{PosixResidency, PosixUnloadError};
+
+use crate::api::{Handle, LinkingError};
+
+/// Represents a reason a shared object file remains resident after closing.
+pub type Residency = PosixResidency;
+
+/// Represents an error that occurred during closing and verifying unloading.
+pub type UnloadError = PosixUnloadError;
+
+impl Handle {
+    /// Closes the handle and verifies that the shared object file is unloaded
+    /// from the process address space, reporting why it remains loaded otherwise.
+    ///
+    /// # Usage
+    ///
+    /// `close_and_verify` is used by hot-reload code that must not load a new version
+    /// of a shared object while the old one is still mapped.
+    ///
+    /// ```no_run
+    /// use dynlink::{api::Handle, unload::UnloadError};
+    ///
+    /// fn main() {
+    ///     unsafe {
+    ///         let handle = Handle::open("libsum.so")
+    ///             .expect("libsum handle was not opened");
+    ///
+    ///         match handle.close_and_verify() {
+    ///             Ok(()) => println!("libsum was unloaded"),
+    ///             Err(UnloadError::Resident(reasons)) => println!("libsum remains: {:?}", reasons),
+    ///             Err(err) => eprintln!("{}", err),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Shared object finalization routines that are executed when this
+    /// function is called may be UB.
+    ///
+    /// # Notes
+    ///
+    /// Residency is checked with `RTLD_NOLOAD` and `dl_iterate_phdr`, and the reasons
+    /// are inferred from the loaded shared objects, since the loader does not expose
+    /// reference counts. `Residency::Referenced` is reported when no other reason is found.
+    pub unsafe fn close_and_verify(self) -> Result<(), UnloadError> {
+        self.close_with(
+            |handle| dynlink_posix::unload::close_and_verify(handle),
+            |result| match result {
+                Err(UnloadError::Linking(err)) => Some(LinkingError::from(err.clone())),
+                _ => None,
+            },
+        )
+    }
+}
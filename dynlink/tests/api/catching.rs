@@ -0,0 +1,147 @@
+use std::{env, process};
+
+use dynlink::{api::Handle, registry};
+
+use crate::fixture::{LIBSUM, SYMBOL_SUM};
+
+pub const LIBCATCHING: &str = "tests/resource/libcatching-x86_64.so";
+
+pub type CallbackFn = extern "C-unwind" fn(i32) -> i32;
+pub type RunFn = extern "C-unwind" fn(CallbackFn, i32) -> i32;
+
+pub extern "C-unwind" fn validate(value: i32) -> i32 {
+    assert!(value >= 0, "negative value");
+    value
+}
+
+pub type NonUnwindingCallbackFn = extern "C" fn(i32) -> i32;
+pub type NonUnwindingRunFn = extern "C" fn(NonUnwindingCallbackFn, i32) -> i32;
+
+pub extern "C" fn validate_without_unwinding(value: i32) -> i32 {
+    assert!(value >= 0, "negative value");
+    value
+}
+
+#[test]
+pub fn symbol_converts_panic_of_closure_into_call_error() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let symbol = lib
+            .lookup::<extern "C" fn(i32, i32) -> i32>(SYMBOL_SUM)
+            .expect("Symbol was not found");
+
+        let err = symbol
+            .apply_catching(SYMBOL_SUM, |sum_fn| -> i32 {
+                panic!("sum was {}", sum_fn(1, 1))
+            })
+            .expect_err("Panic was not caught");
+
+        assert_eq!(SYMBOL_SUM, err.symbol());
+        assert_eq!(Some(String::from("sum was 2")), err.message());
+    }
+}
+
+#[test]
+pub fn symbol_calls_catching_when_nothing_unwinds() {
+    unsafe {
+        let lib = Handle::open(LIBCATCHING).expect("Shared object was not opened");
+        let run = lib
+            .lookup::<RunFn>("plugin_run")
+            .expect("Symbol was not found");
+
+        let ret = run
+            .call_catching("plugin_run", (validate, 41))
+            .expect("Symbol call unwound");
+
+        assert_eq!(42, ret);
+    }
+}
+
+#[test]
+pub fn symbol_converts_panic_unwinding_through_library_into_call_error() {
+    unsafe {
+        let lib = Handle::open(LIBCATCHING).expect("Shared object was not opened");
+        let run = lib
+            .lookup::<RunFn>("plugin_run")
+            .expect("Symbol was not found");
+
+        let err = run
+            .call_catching("plugin_run", (validate, -1))
+            .expect_err("Panic was not caught");
+
+        assert_eq!("plugin_run", err.symbol());
+        assert_eq!(Some(String::from("negative value")), err.message());
+        assert_eq!(
+            "Error occurred calling symbol plugin_run: panicked with 'negative value'",
+            err.to_string()
+        );
+    }
+}
+
+#[test]
+pub fn owned_symbol_converts_panic_unwinding_through_library_into_call_error() {
+    unsafe {
+        let handle = registry::open_shared(LIBCATCHING).expect("Shared object was not opened");
+        let run = handle
+            .lookup_owned::<RunFn>("plugin_run")
+            .expect("Symbol was not found");
+
+        drop(handle);
+
+        assert_eq!(
+            1,
+            run.call_catching((validate, 0))
+                .expect("Symbol call unwound")
+        );
+
+        let err = run
+            .call_catching((validate, -1))
+            .expect_err("Panic was not caught");
+
+        assert_eq!("plugin_run", err.symbol());
+    }
+}
+
+/// Calls `plugin_run` with a panicking `extern "C"` callback when run by
+/// `symbol_aborts_when_panic_unwinds_out_of_c_function` as a child process.
+#[test]
+pub fn symbol_aborts_when_called_by_child() {
+    if env::var_os("DYNLINK_CATCHING_ABORT").is_none() {
+        return;
+    }
+
+    unsafe {
+        let lib = Handle::open(LIBCATCHING).expect("Shared object was not opened");
+        let run = lib
+            .lookup::<NonUnwindingRunFn>("plugin_run")
+            .expect("Symbol was not found");
+
+        let _ = run.call_catching("plugin_run", (validate_without_unwinding, -1));
+    }
+}
+
+#[test]
+pub fn symbol_aborts_when_panic_unwinds_out_of_c_function() {
+    let output = process::Command::new(env::current_exe().expect("Test path was not found"))
+        .args([
+            "--exact",
+            "api::catching::symbol_aborts_when_called_by_child",
+            "--nocapture",
+            "--test-threads=1",
+        ])
+        .env("DYNLINK_CATCHING_ABORT", "1")
+        .output()
+        .expect("Child process was not run");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("negative value"));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        assert_eq!(Some(libc::SIGABRT), output.status.signal());
+    }
+}
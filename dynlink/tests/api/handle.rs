@@ -1,4 +1,6 @@
-use dynlink::api::Handle;
+use std::sync::Arc;
+
+use dynlink::api::{Handle, OwnedSymbol, RawSymbol, StaticSymbol};
 
 #[cfg(all(target_os = "linux", target_arch = "x86"))]
 pub const LIBSUM: &'static str = "tests/resource/libsum-x86.so";
@@ -74,3 +76,111 @@ pub fn handle_fails_to_find_symbol_when_symbol_does_not_exist() {
             .expect_err("Unknow symbol was found");
     }
 }
+
+#[test]
+pub fn handle_finds_first_symbol_that_exists() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+
+        let (name, sum_fn) = lib
+            .lookup_first::<extern "C" fn(i32, i32) -> i32>(&[SYMBOL_UNKNOWN, SYMBOL_SUM])
+            .expect("Symbol was not found");
+
+        assert_eq!(SYMBOL_SUM, name);
+        assert_eq!(2, sum_fn.apply(|it| it(1, 1)));
+    }
+}
+
+#[test]
+pub fn handle_fails_to_find_first_symbol_and_reports_every_attempt() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+
+        let err = lib
+            .lookup_first::<extern "C" fn(i32, i32) -> i32>(&[SYMBOL_UNKNOWN, "unknown_v2"])
+            .expect_err("Unknow symbol was found");
+
+        let names = err
+            .attempts()
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![SYMBOL_UNKNOWN, "unknown_v2"], names);
+        assert!(err.to_string().contains("unknown_v2"));
+    }
+}
+
+#[test]
+pub fn handle_finds_symbol_as_unwinding_abi_function() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+
+        let sum_fn = lib
+            .lookup::<extern "C-unwind" fn(i32, i32) -> i32>(SYMBOL_SUM)
+            .expect("Symbol was not found");
+
+        assert_eq!(2, sum_fn.apply(|it| it(1, 1)));
+    }
+}
+
+#[test]
+pub fn handle_finds_symbol_that_calls_with_tuple_of_args() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+
+        let sum_fn = lib
+            .lookup::<extern "C" fn(i32, i32) -> i32>(SYMBOL_SUM)
+            .expect("Symbol was not found");
+
+        assert_eq!(2, sum_fn.call((1, 1)));
+    }
+}
+
+#[test]
+pub fn handle_finds_raw_symbol_that_casts_to_function() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+
+        let symbol: RawSymbol<'_> = lib.lookup(SYMBOL_SUM).expect("Symbol was not found");
+        let sum_fn = symbol.cast::<extern "C" fn(i32, i32) -> i32>();
+
+        assert_eq!(2, sum_fn.call((1, 1)));
+    }
+}
+
+#[test]
+pub fn handle_finds_owned_symbol_that_keeps_handle_opened() {
+    unsafe {
+        let lib = Arc::new(Handle::open(LIBSUM).expect("Shared object was not opened"));
+
+        let sum_fn: OwnedSymbol<extern "C" fn(i32, i32) -> i32> =
+            lib.lookup_owned(SYMBOL_SUM).expect("Symbol was not found");
+
+        drop(lib);
+        assert_eq!(1, Arc::strong_count(sum_fn.handle()));
+        assert_eq!(2, sum_fn.call((1, 1)));
+    }
+}
+
+#[test]
+pub fn handle_finds_static_symbol_that_derefs_to_function() {
+    unsafe {
+        let lib: &'static Handle = Box::leak(Box::new(
+            Handle::open(LIBSUM).expect("Shared object was not opened"),
+        ));
+
+        // copied out functions stay valid, since the handle is never closed
+        let copied = {
+            let sum_fn = StaticSymbol::from(
+                lib.lookup::<extern "C" fn(i32, i32) -> i32>(SYMBOL_SUM)
+                    .expect("Symbol was not found"),
+            );
+
+            assert_eq!(2, sum_fn(1, 1));
+            *sum_fn
+        };
+
+        assert_eq!(2, copied(1, 1));
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod catching;
 #[cfg(any(
     all(
         target_os = "linux",
@@ -12,4 +14,5 @@
         any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
     ),
 ))]
+#[allow(clippy::redundant_static_lifetimes)]
 mod handle;
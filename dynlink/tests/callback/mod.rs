@@ -0,0 +1,4 @@
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod thunk;
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod trampoline;
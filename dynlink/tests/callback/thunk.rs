@@ -0,0 +1,84 @@
+use dynlink::{api::Handle, callback::Thunk};
+
+use crate::callback::trampoline::LIBCALLBACK;
+
+pub type ApplyBareFn = extern "C" fn(unsafe extern "C" fn(i32) -> i32, i32) -> i32;
+
+pub type ApplyManyFn =
+    extern "C" fn(unsafe extern "C" fn(i64, i64, i64, i64, i64, i64, i64, i64) -> i64) -> i64;
+
+pub type ApplyMixedFn =
+    extern "C" fn(unsafe extern "C" fn(i8, f32, i64, f64) -> f64, i8, f32, i64, f64) -> f64;
+
+pub type ApplyUntilFn = extern "C" fn(unsafe extern "C" fn(i32) -> i32, i32) -> i32;
+
+#[test]
+pub fn thunk_is_called_without_user_data() {
+    unsafe {
+        let lib = Handle::open(LIBCALLBACK).expect("Shared object was not opened");
+        let apply = lib
+            .lookup::<ApplyBareFn>("apply_bare")
+            .expect("Symbol was not found");
+
+        let offset = 21;
+        let thunk = Thunk::new(|value: i32| value + offset).expect("Thunk was not mapped");
+
+        assert_eq!(42, apply.call((thunk.as_fn(), 21)));
+    }
+}
+
+#[test]
+pub fn thunk_receives_stack_arguments() {
+    unsafe {
+        let lib = Handle::open(LIBCALLBACK).expect("Shared object was not opened");
+        let apply = lib
+            .lookup::<ApplyManyFn>("apply_many")
+            .expect("Symbol was not found");
+
+        let thunk = Thunk::new(
+            |a: i64, b: i64, c: i64, d: i64, e: i64, f: i64, g: i64, h: i64| {
+                a + b + c + d + e + f + g + h
+            },
+        )
+        .expect("Thunk was not mapped");
+
+        assert_eq!(36, apply.call((thunk.as_fn(),)));
+    }
+}
+
+#[test]
+pub fn thunk_receives_mixed_integer_and_float_arguments() {
+    unsafe {
+        let lib = Handle::open(LIBCALLBACK).expect("Shared object was not opened");
+        let apply = lib
+            .lookup::<ApplyMixedFn>("apply_mixed")
+            .expect("Symbol was not found");
+
+        let thunk = Thunk::new(|a: i8, b: f32, c: i64, d: f64| a as f64 + b as f64 + c as f64 + d)
+            .expect("Thunk was not mapped");
+
+        assert_eq!(109.75, apply.call((thunk.as_fn(), -1, 0.5, 110, 0.25)));
+    }
+}
+
+#[test]
+pub fn thunk_panic_stops_library_with_default_result() {
+    unsafe {
+        let lib = Handle::open(LIBCALLBACK).expect("Shared object was not opened");
+        let apply = lib
+            .lookup::<ApplyUntilFn>("apply_until")
+            .expect("Symbol was not found");
+
+        let thunk = Thunk::new(|count: i32| -> i32 {
+            if count == 3 {
+                panic!("thunk panicked");
+            }
+
+            1
+        })
+        .expect("Thunk was not mapped");
+
+        assert_eq!(3, apply.call((thunk.as_fn(), 10)));
+        assert!(thunk.take_panic().is_some());
+    }
+}
@@ -0,0 +1,71 @@
+use std::ffi;
+
+use dynlink::{api::Handle, callback::Callback};
+
+pub const LIBCALLBACK: &str = "tests/resource/libcallback-x86_64.so";
+
+pub type ApplyLeadingFn =
+    extern "C" fn(unsafe extern "C" fn(*mut ffi::c_void, i32) -> i32, *mut ffi::c_void, i32) -> i32;
+
+pub type ForEachFn =
+    extern "C" fn(*const i32, usize, unsafe extern "C" fn(i32, *mut ffi::c_void), *mut ffi::c_void);
+
+#[test]
+pub fn callback_is_called_with_leading_user_data() {
+    unsafe {
+        let lib = Handle::open(LIBCALLBACK).expect("Shared object was not opened");
+        let apply = lib
+            .lookup::<ApplyLeadingFn>("apply_leading")
+            .expect("Symbol was not found");
+
+        let offset = 21;
+        let callback = Callback::new(|value: i32| value + offset);
+
+        assert_eq!(
+            42,
+            apply.call((callback.trampoline(), callback.user_data(), 21))
+        );
+    }
+}
+
+#[test]
+pub fn callback_is_called_with_trailing_user_data() {
+    unsafe {
+        let lib = Handle::open(LIBCALLBACK).expect("Shared object was not opened");
+        let for_each = lib
+            .lookup::<ForEachFn>("for_each")
+            .expect("Symbol was not found");
+
+        let values = [1, 2, 3];
+        let mut seen = Vec::new();
+        let callback = Callback::new(|value: i32| seen.push(value * 10));
+
+        for_each.call((
+            values.as_ptr(),
+            values.len(),
+            callback.trailing_trampoline(),
+            callback.user_data(),
+        ));
+
+        drop(callback);
+        assert_eq!(vec![10, 20, 30], seen);
+    }
+}
+
+#[test]
+pub fn callback_panic_does_not_unwind_through_library() {
+    unsafe {
+        let lib = Handle::open(LIBCALLBACK).expect("Shared object was not opened");
+        let apply = lib
+            .lookup::<ApplyLeadingFn>("apply_leading")
+            .expect("Symbol was not found");
+
+        let callback = Callback::new(|_: i32| -> i32 { panic!("callback panicked") });
+        let ret = apply.call((callback.trampoline(), callback.user_data(), 1));
+
+        assert_eq!(0, ret);
+
+        let payload = callback.take_panic().expect("Panic was not kept");
+        assert_eq!(Some(&"callback panicked"), payload.downcast_ref::<&str>());
+    }
+}
@@ -0,0 +1,22 @@
+use dynlink::api::Handle;
+use pointersized::PointerSized;
+
+pub struct Wide([u8; 16]);
+
+impl pointersized::sealed::Sealed for Wide {}
+
+unsafe impl PointerSized for Wide {}
+
+fn main() {
+    unsafe {
+        // the symbol functions are instantiated when the case is built
+        let Ok(handle) = Handle::open("libsum.so") else {
+            return;
+        };
+
+        if let Ok(symbol) = handle.lookup::<Wide>("sum_of") {
+            let _ = symbol.apply(|wide| wide.0);
+            let _ = symbol.leak();
+        }
+    }
+}
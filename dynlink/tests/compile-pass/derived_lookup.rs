@@ -0,0 +1,20 @@
+use dynlink::api::Handle;
+use pointersized::PointerSized;
+
+#[derive(PointerSized)]
+#[repr(transparent)]
+pub struct SumOf(extern "C" fn(i32, i32) -> i32);
+
+fn main() {
+    unsafe {
+        // the symbol functions are instantiated when the case is built
+        let Ok(handle) = Handle::open("libsum.so") else {
+            return;
+        };
+
+        if let Ok(symbol) = handle.lookup::<SumOf>("sum_of") {
+            let _ = symbol.apply(|sum_of| (sum_of.0)(1, 1));
+            let _ = symbol.leak();
+        }
+    }
+}
@@ -0,0 +1,11 @@
+// the expected errors point into the posix symbol implementation
+#[cfg(unix)]
+#[test]
+pub fn symbols_of_wide_types_are_rejected() {
+    let cases = trybuild::TestCases::new();
+
+    // the size of a symbol type is asserted when `Symbol::apply` or `Symbol::leak` is instantiated,
+    // so the cases are built rather than checked, which trybuild does when any of them must pass
+    cases.pass("tests/compile-pass/*.rs");
+    cases.compile_fail("tests/compile-fail/*.rs");
+}
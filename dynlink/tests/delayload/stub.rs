@@ -0,0 +1,176 @@
+use std::{
+    env, process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use dynlink::{api::Handle, delayload::MissingSymbol};
+
+use crate::fixture::LIBSUM;
+
+static MISSING_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn on_missing(symbol: &MissingSymbol) -> i32 {
+    assert_eq!("libsum", symbol.library());
+    assert_eq!("product_of", symbol.symbol());
+
+    MISSING_CALLS.fetch_add(1, Ordering::SeqCst);
+    -1
+}
+
+dynlink::delay_load! {
+    pub struct LibSumFns: "libsum" {
+        pub extern "C" fn sum_of(a: i32, b: i32) -> i32 => panic;
+        pub extern "C" fn sum_of_three(a: i32, b: i32, c: i32) -> i32 => default(-1);
+        pub extern "C" fn product_of(a: i32, b: i32) -> i32 => callback(on_missing);
+        pub extern "Rust" fn quotient_of(a: i32, b: i32) -> i32 => panic;
+    }
+}
+
+dynlink::delay_load! {
+    pub struct LibSumAbortingFns: "libsum" {
+        pub extern "C" fn quotient_of(a: i32, b: i32) -> i32 => panic;
+    }
+}
+
+dynlink::delay_load! {
+    pub struct LibSumNamedFns: "libsum" {
+        pub extern "C" fn capabilities(a: i32, b: i32) -> i32 as ["sum_of"] => panic;
+        pub extern "C" fn handle(a: i32, b: i32) -> i32 as ["sum_of"] => panic;
+    }
+}
+
+dynlink::delay_load! {
+    pub struct LibSumAliasedFns: "libsum" {
+        pub extern "C" fn sum(a: i32, b: i32) -> i32 as ["sum_of_v2", "sum_of"] => panic;
+        pub extern "C" fn renamed(a: i32, b: i32) -> i32 as ["renamed_v2", "renamed"] => default(-1);
+    }
+}
+
+#[test]
+pub fn delay_load_resolves_present_symbols() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumFns::load(&lib);
+
+        assert_eq!(2, (fns.sum_of)(1, 1));
+    }
+}
+
+#[test]
+pub fn delay_load_stub_returns_default_value() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumFns::load(&lib);
+
+        assert_eq!(-1, (fns.sum_of_three)(1, 1, 1));
+    }
+}
+
+#[test]
+pub fn delay_load_stub_calls_callback() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumFns::load(&lib);
+
+        assert_eq!(-1, (fns.product_of)(1, 1));
+        assert_eq!(1, MISSING_CALLS.load(Ordering::SeqCst));
+    }
+}
+
+#[test]
+pub fn delay_load_stub_panics_with_library_and_symbol_names() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumFns::load(&lib);
+
+        let payload =
+            std::panic::catch_unwind(|| (fns.quotient_of)(1, 1)).expect_err("Stub did not panic");
+
+        assert_eq!(
+            Some("Symbol quotient_of is missing in libsum"),
+            payload.downcast_ref::<String>().map(String::as_str)
+        );
+    }
+}
+
+#[test]
+pub fn delay_load_reports_stubbed_symbols_as_missing() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let capabilities = LibSumFns::load(&lib).capabilities();
+
+        assert_eq!(&["sum_of"], capabilities.present());
+        assert_eq!(
+            &["sum_of_three", "product_of", "quotient_of"],
+            capabilities.missing()
+        );
+    }
+}
+
+#[test]
+pub fn delay_load_resolves_first_alias_that_exists() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumAliasedFns::load(&lib);
+
+        assert_eq!(2, (fns.sum)(1, 1));
+        assert_eq!(-1, (fns.renamed)(1, 1));
+        assert_eq!(&["sum"], fns.capabilities().present());
+        assert_eq!(&["renamed"], fns.capabilities().missing());
+        assert_eq!(Some("sum_of"), fns.capabilities().resolved_as("sum"));
+    }
+}
+
+/// Calls the `extern "C"` panicking stub when run by
+/// `delay_load_stub_aborts_when_abi_does_not_unwind` as a child process.
+#[test]
+pub fn delay_load_stub_aborts_when_called_by_child() {
+    if env::var_os("DYNLINK_DELAYLOAD_ABORT").is_none() {
+        return;
+    }
+
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumAbortingFns::load(&lib);
+
+        let _ = std::panic::catch_unwind(|| (fns.quotient_of)(1, 1));
+    }
+}
+
+#[test]
+pub fn delay_load_stub_aborts_when_abi_does_not_unwind() {
+    let output = process::Command::new(env::current_exe().expect("Test path was not found"))
+        .args([
+            "--exact",
+            "delayload::stub::delay_load_stub_aborts_when_called_by_child",
+            "--nocapture",
+            "--test-threads=1",
+        ])
+        .env("DYNLINK_DELAYLOAD_ABORT", "1")
+        .output()
+        .expect("Child process was not run");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("Symbol quotient_of is missing in libsum"));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        assert_eq!(Some(libc::SIGABRT), output.status.signal());
+    }
+}
+
+#[test]
+pub fn delay_load_declares_functions_named_like_generated_items() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumNamedFns::load(&lib);
+
+        assert_eq!(2, (fns.capabilities)(1, 1));
+        assert_eq!(2, (fns.handle)(1, 1));
+        assert!(fns.capabilities().is_complete());
+    }
+}
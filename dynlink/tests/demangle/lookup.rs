@@ -0,0 +1,120 @@
+use dynlink::{api::Handle, demangle::DemangleError};
+
+pub const LIBMANGLED: &str = "tests/resource/libmangled-x86_64.so";
+
+pub const SYMBOL_TWIN_A: &str = "_ZN7mycrate4twin17h5a1b2c3d4e5f6071E";
+pub const SYMBOL_TWIN_B: &str = "_ZN7mycrate4twin17h9f8e7d6c5b4a3921E";
+
+#[test]
+pub fn lookup_demangled_finds_v0_mangled_symbol() {
+    unsafe {
+        let lib = Handle::open(LIBMANGLED).expect("Shared object was not opened");
+
+        let func = lib
+            .lookup_demangled::<extern "C" fn(i32) -> i32>("mycrate::module::func")
+            .expect("Symbol was not found");
+
+        assert_eq!(2, func.apply(|it| it(1)));
+    }
+}
+
+#[test]
+pub fn lookup_demangled_finds_legacy_mangled_symbol() {
+    unsafe {
+        let lib = Handle::open(LIBMANGLED).expect("Shared object was not opened");
+
+        let legacy = lib
+            .lookup_demangled::<extern "C" fn(i32) -> i32>("mycrate::module::legacy")
+            .expect("Symbol was not found");
+
+        assert_eq!(3, legacy.apply(|it| it(1)));
+    }
+}
+
+#[test]
+pub fn lookup_demangled_reports_all_candidates_when_ambiguous() {
+    unsafe {
+        let lib = Handle::open(LIBMANGLED).expect("Shared object was not opened");
+
+        let err = lib
+            .lookup_demangled::<extern "C" fn() -> i32>("mycrate::twin")
+            .expect_err("Ambiguous symbol was found");
+
+        match err {
+            DemangleError::Ambiguous(name, mut candidates) => {
+                candidates.sort();
+
+                assert_eq!("mycrate::twin", name);
+                assert_eq!(vec![SYMBOL_TWIN_A, SYMBOL_TWIN_B], candidates);
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+}
+
+#[test]
+pub fn lookup_demangled_fails_when_path_is_not_exported() {
+    unsafe {
+        let lib = Handle::open(LIBMANGLED).expect("Shared object was not opened");
+
+        let err = lib
+            .lookup_demangled::<extern "C" fn() -> i32>("mycrate::module")
+            .expect_err("Unknown symbol was found");
+
+        assert!(matches!(err, DemangleError::NotFound(name) if name == "mycrate::module"));
+    }
+}
+
+#[test]
+pub fn lookup_cpp_finds_overload_by_signature() {
+    unsafe {
+        let lib = Handle::open(LIBMANGLED).expect("Shared object was not opened");
+
+        let area = lib
+            .lookup_cpp::<extern "C" fn(i32) -> i32>("geometry::Shape::area(int)")
+            .expect("Symbol was not found");
+
+        let area_of = lib
+            .lookup_cpp::<extern "C" fn(i32, i32) -> i32>("geometry::Shape::area(int,int)")
+            .expect("Symbol was not found");
+
+        assert_eq!(9, area.apply(|it| it(3)));
+        assert_eq!(6, area_of.apply(|it| it(2, 3)));
+    }
+}
+
+#[test]
+pub fn lookup_cpp_finds_unique_name_without_signature() {
+    unsafe {
+        let lib = Handle::open(LIBMANGLED).expect("Shared object was not opened");
+
+        let scale = lib
+            .lookup_cpp::<extern "C" fn(f64) -> f64>("geometry::Shape::scale")
+            .expect("Symbol was not found");
+
+        assert_eq!(3.0, scale.apply(|it| it(1.5)));
+    }
+}
+
+#[test]
+pub fn lookup_cpp_reports_overloads_when_ambiguous() {
+    unsafe {
+        let lib = Handle::open(LIBMANGLED).expect("Shared object was not opened");
+
+        let err = lib
+            .lookup_cpp::<extern "C" fn(i32) -> i32>("geometry::Shape::area")
+            .expect_err("Ambiguous symbol was found");
+
+        match err {
+            DemangleError::Ambiguous(_, mut candidates) => {
+                candidates.sort();
+
+                assert_eq!(
+                    vec!["_ZN8geometry5Shape4areaEi", "_ZN8geometry5Shape4areaEii"],
+                    candidates
+                );
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+}
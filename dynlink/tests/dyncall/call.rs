@@ -0,0 +1,208 @@
+use std::ffi;
+
+use dynlink::{
+    api::{Handle, RawSymbol},
+    dyncall::{Signature, Type, Value},
+};
+
+pub const LIBDYNCALL: &str = "tests/resource/libdyncall-x86_64.so";
+
+pub fn call(name: &str, args: Vec<Type>, ret: Type, values: &[Value]) -> Value {
+    unsafe {
+        let lib = Handle::open(LIBDYNCALL).expect("Shared object was not opened");
+        let symbol: RawSymbol<'_> = lib.lookup(name).expect("Symbol was not found");
+
+        symbol
+            .call_dynamic(&Signature::new(args, ret), values)
+            .expect("Symbol was not called")
+    }
+}
+
+pub fn point() -> Type {
+    Type::Struct(vec![Type::I32, Type::I32])
+}
+
+pub fn vec2() -> Type {
+    Type::Struct(vec![Type::F64, Type::F64])
+}
+
+pub fn rgb() -> Type {
+    Type::Struct(vec![Type::F32, Type::F32, Type::F32])
+}
+
+pub fn big() -> Type {
+    Type::Struct(vec![Type::I64, Type::I64, Type::I64])
+}
+
+#[test]
+pub fn dyncall_extends_and_truncates_small_integers() {
+    let negated = call("negate_i8", vec![Type::I8], Type::I8, &[Value::I8(-5)]);
+    assert_eq!(Value::I8(5), negated);
+
+    let widened = call(
+        "widen_u8",
+        vec![Type::U8, Type::U8],
+        Type::U16,
+        &[Value::U8(200), Value::U8(3)],
+    );
+    assert_eq!(Value::U16(600), widened);
+}
+
+#[test]
+pub fn dyncall_passes_integers_beyond_registers_on_stack() {
+    let values = (1..=10).map(Value::I64).collect::<Vec<_>>();
+    let sum = call("sum_i64x10", vec![Type::I64; 10], Type::I64, &values);
+
+    assert_eq!(Value::I64(55), sum);
+}
+
+#[test]
+pub fn dyncall_passes_floats_beyond_registers_on_stack() {
+    let values = (1..=10).map(|it| Value::F64(it as f64)).collect::<Vec<_>>();
+    let sum = call("sum_f64x10", vec![Type::F64; 10], Type::F64, &values);
+
+    assert_eq!(Value::F64(55.0), sum);
+}
+
+#[test]
+pub fn dyncall_passes_mixed_integers_and_floats() {
+    let mixed = call(
+        "mix_of",
+        vec![Type::I8, Type::F32, Type::I64, Type::F64, Type::U32],
+        Type::F64,
+        &[
+            Value::I8(-1),
+            Value::F32(0.5),
+            Value::I64(10),
+            Value::F64(0.25),
+            Value::U32(100),
+        ],
+    );
+    assert_eq!(Value::F64(109.75), mixed);
+
+    let halved = call("halve_f32", vec![Type::F32], Type::F32, &[Value::F32(3.0)]);
+    assert_eq!(Value::F32(1.5), halved);
+}
+
+#[test]
+pub fn dyncall_passes_pointers_and_returns_void() {
+    let mut out: i32 = 0;
+
+    let ret = call(
+        "store_i32",
+        vec![Type::Pointer, Type::I32],
+        Type::Void,
+        &[
+            Value::Pointer(&mut out as *mut i32 as *mut ffi::c_void),
+            Value::I32(42),
+        ],
+    );
+
+    assert_eq!(Value::Void, ret);
+    assert_eq!(42, out);
+}
+
+#[test]
+pub fn dyncall_passes_and_returns_integer_structs() {
+    let sum = call(
+        "point_add",
+        vec![point(), point()],
+        point(),
+        &[
+            Value::Struct(vec![Value::I32(1), Value::I32(2)]),
+            Value::Struct(vec![Value::I32(10), Value::I32(20)]),
+        ],
+    );
+
+    assert_eq!(Value::Struct(vec![Value::I32(11), Value::I32(22)]), sum);
+}
+
+#[test]
+pub fn dyncall_passes_and_returns_float_structs() {
+    let scaled = call(
+        "vec2_scale",
+        vec![vec2(), Type::F64],
+        vec2(),
+        &[
+            Value::Struct(vec![Value::F64(1.5), Value::F64(-2.0)]),
+            Value::F64(2.0),
+        ],
+    );
+    assert_eq!(
+        Value::Struct(vec![Value::F64(3.0), Value::F64(-4.0)]),
+        scaled
+    );
+
+    let gray = call(
+        "rgb_gray",
+        vec![rgb()],
+        rgb(),
+        &[Value::Struct(vec![
+            Value::F32(0.0),
+            Value::F32(1.5),
+            Value::F32(3.0),
+        ])],
+    );
+    assert_eq!(
+        Value::Struct(vec![Value::F32(1.5), Value::F32(1.5), Value::F32(1.5)]),
+        gray
+    );
+}
+
+#[test]
+pub fn dyncall_returns_mixed_class_structs() {
+    let tagged = call(
+        "tagged_make",
+        vec![Type::I32, Type::F64],
+        Type::Struct(vec![Type::I32, Type::F64]),
+        &[Value::I32(7), Value::F64(0.5)],
+    );
+
+    assert_eq!(Value::Struct(vec![Value::I32(7), Value::F64(0.5)]), tagged);
+}
+
+#[test]
+pub fn dyncall_passes_and_returns_large_structs_in_memory() {
+    let made = call(
+        "big_make",
+        vec![Type::I64, Type::I64, Type::I64],
+        big(),
+        &[Value::I64(1), Value::I64(2), Value::I64(3)],
+    );
+    assert_eq!(
+        Value::Struct(vec![Value::I64(1), Value::I64(2), Value::I64(3)]),
+        made
+    );
+
+    let sum = call("big_sum", vec![big()], Type::I64, &[made]);
+    assert_eq!(Value::I64(6), sum);
+}
+
+#[test]
+pub fn dyncall_passes_struct_on_stack_when_registers_are_taken() {
+    let mut args = vec![Type::I64; 6];
+    args.push(point());
+
+    let mut values = (1..=6).map(Value::I64).collect::<Vec<_>>();
+    values.push(Value::Struct(vec![Value::I32(100), Value::I32(200)]));
+
+    let sum = call("point_sum_after", args, Type::I64, &values);
+    assert_eq!(Value::I64(321), sum);
+}
+
+#[test]
+pub fn dyncall_calls_variadic_functions_with_promoted_arguments() {
+    let sum = call(
+        "sum_variadic",
+        vec![Type::I32, Type::F64, Type::F64, Type::F64],
+        Type::F64,
+        &[
+            Value::I32(3),
+            Value::F64(1.0),
+            Value::F64(2.0),
+            Value::F64(3.5),
+        ],
+    );
+
+    assert_eq!(Value::F64(6.5), sum);
+}
@@ -0,0 +1,7 @@
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod call;
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod symbol;
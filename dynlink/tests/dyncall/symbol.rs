@@ -0,0 +1,43 @@
+use dynlink::{
+    api::{Handle, RawSymbol},
+    dyncall::{DyncallError, Signature, Type, Value},
+};
+
+use crate::fixture::{LIBSUM, SYMBOL_SUM};
+
+#[test]
+pub fn raw_symbol_calls_dynamic_signature() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let symbol: RawSymbol<'_> = lib.lookup(SYMBOL_SUM).expect("Symbol was not found");
+
+        let signature = Signature::new(vec![Type::I32, Type::I32], Type::I32);
+        let sum = symbol
+            .call_dynamic(&signature, &[Value::I32(1), Value::I32(1)])
+            .expect("Symbol was not called");
+
+        assert_eq!(Value::I32(2), sum);
+    }
+}
+
+#[test]
+pub fn raw_symbol_fails_to_call_with_mismatched_arguments() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let symbol: RawSymbol<'_> = lib.lookup(SYMBOL_SUM).expect("Symbol was not found");
+
+        let signature = Signature::new(vec![Type::I32, Type::I32], Type::I32);
+        let err = symbol
+            .call_dynamic(&signature, &[Value::I32(1), Value::I64(1)])
+            .expect_err("Symbol was called with mismatched arguments");
+
+        assert!(matches!(
+            err,
+            DyncallError::ArgumentType(1, Type::I32, Type::I64)
+        ));
+        assert_eq!(
+            "Error occurred dynamic call: argument 1 is int32_t, given int64_t",
+            err.to_string()
+        );
+    }
+}
@@ -13,3 +13,121 @@
     target_os = "windows",
 ))]
 pub mod api;
+
+pub mod callback;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "haiku",
+    target_os = "windows",
+))]
+pub mod delayload;
+
+#[cfg(any(
+    all(
+        target_os = "linux",
+        any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
+    ),
+    all(
+        target_os = "macos",
+        any(target_arch = "aarch64", target_arch = "x86_64"),
+    ),
+    all(
+        target_os = "windows",
+        any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
+    ),
+))]
+pub mod fixture;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "haiku",
+    target_os = "windows",
+))]
+pub mod lazy;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "haiku",
+    target_os = "windows",
+))]
+pub mod loader;
+
+pub mod object;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "haiku",
+    target_os = "windows",
+))]
+pub mod optional;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "haiku",
+    target_os = "windows",
+))]
+pub mod registry;
+
+#[cfg(target_os = "linux")]
+pub mod demangle;
+
+#[cfg(target_os = "linux")]
+pub mod dyncall;
+
+#[cfg(target_os = "linux")]
+pub mod exports;
+
+#[cfg(target_os = "linux")]
+pub mod hook;
+
+#[cfg(target_os = "linux")]
+pub mod unload;
+
+#[cfg(target_os = "linux")]
+pub mod preflight;
@@ -0,0 +1,77 @@
+use std::{ffi, mem};
+
+use dynlink::{api::Handle, exports::Pattern};
+
+pub const LIBPLUGIN: &str = "tests/resource/libplugin-x86_64.so";
+
+#[test]
+pub fn lookup_matching_yields_matching_exports_with_symbols() {
+    unsafe {
+        let lib = Handle::open(LIBPLUGIN).expect("Shared object was not opened");
+
+        let mut commands = lib
+            .lookup_matching(Pattern::glob("plugin_cmd_*"))
+            .expect("Exports were not read")
+            .map(|(name, symbol)| {
+                let command_fn: extern "C" fn() -> i32 = mem::transmute(symbol.leak_as_raw());
+                (name, command_fn())
+            })
+            .collect::<Vec<_>>();
+
+        commands.sort();
+
+        assert_eq!(
+            vec![
+                (String::from("plugin_cmd_start"), 1),
+                (String::from("plugin_cmd_status"), 3),
+                (String::from("plugin_cmd_stop"), 2),
+            ],
+            commands
+        );
+    }
+}
+
+#[test]
+pub fn lookup_matching_yields_data_exports() {
+    unsafe {
+        let lib = Handle::open(LIBPLUGIN).expect("Shared object was not opened");
+
+        let versions = lib
+            .lookup_matching(Pattern::prefix("plugin_version"))
+            .expect("Exports were not read")
+            .map(|(_, symbol)| *symbol.leak_as_raw().cast::<ffi::c_int>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![4], versions);
+    }
+}
+
+#[test]
+pub fn lookup_matching_matches_whole_names_with_anchored_regex() {
+    unsafe {
+        let lib = Handle::open(LIBPLUGIN).expect("Shared object was not opened");
+
+        let names = lib
+            .lookup_matching(Pattern::regex("^plugin_(init|cmd_stop)$").unwrap())
+            .expect("Exports were not read")
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(2, names.len());
+        assert!(names.iter().any(|it| it == "plugin_init"));
+        assert!(names.iter().any(|it| it == "plugin_cmd_stop"));
+    }
+}
+
+#[test]
+pub fn lookup_matching_yields_nothing_when_no_export_matches() {
+    unsafe {
+        let lib = Handle::open(LIBPLUGIN).expect("Shared object was not opened");
+
+        let mut matches = lib
+            .lookup_matching(Pattern::prefix("unknown_"))
+            .expect("Exports were not read");
+
+        assert!(matches.next().is_none());
+    }
+}
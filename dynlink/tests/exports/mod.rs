@@ -0,0 +1,3 @@
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod matching;
+mod pattern;
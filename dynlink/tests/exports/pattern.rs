@@ -0,0 +1,48 @@
+use dynlink::exports::Pattern;
+
+#[test]
+pub fn prefix_pattern_matches_names_starting_with_prefix() {
+    let pattern = Pattern::prefix("plugin_cmd_");
+
+    assert!(pattern.matches("plugin_cmd_start"));
+    assert!(pattern.matches("plugin_cmd_"));
+    assert!(!pattern.matches("plugin_init"));
+}
+
+#[test]
+pub fn glob_pattern_matches_wildcards() {
+    let pattern = Pattern::glob("plugin_*_st?p");
+
+    assert!(pattern.matches("plugin_cmd_stop"));
+    assert!(pattern.matches("plugin_a_b_step"));
+    assert!(!pattern.matches("plugin_cmd_stops"));
+    assert!(!pattern.matches("plugin_stop"));
+
+    assert!(Pattern::glob("*").matches(""));
+    assert!(Pattern::glob("*_v*").matches("foo_v2"));
+    assert!(!Pattern::glob("foo").matches("foo_v2"));
+}
+
+#[test]
+pub fn glob_pattern_matches_character_classes() {
+    assert!(Pattern::glob("foo_v[0-9]").matches("foo_v2"));
+    assert!(!Pattern::glob("foo_v[!0-9]").matches("foo_v2"));
+    assert!(Pattern::glob("foo_[abc]").matches("foo_b"));
+    assert!(!Pattern::glob("foo_[abc]").matches("foo_d"));
+    assert!(Pattern::glob("[]]").matches("]"));
+    // an unterminated class is a literal bracket
+    assert!(Pattern::glob("foo[").matches("foo["));
+}
+
+#[test]
+pub fn regex_pattern_matches_anywhere_unless_anchored() {
+    let pattern = Pattern::regex("cmd_(start|stop)").expect("Regex was not compiled");
+
+    assert!(pattern.matches("plugin_cmd_start"));
+    assert!(!pattern.matches("plugin_cmd_status"));
+
+    let pattern = Pattern::regex("^cmd_").expect("Regex was not compiled");
+    assert!(!pattern.matches("plugin_cmd_start"));
+
+    assert!(Pattern::regex("(").is_err());
+}
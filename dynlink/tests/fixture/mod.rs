@@ -0,0 +1,36 @@
+#[cfg(all(target_os = "linux", target_arch = "x86"))]
+pub const LIBSUM: &str = "tests/resource/libsum-x86.so";
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+pub const LIBSUM: &str = "tests/resource/libsum-x86_64.so";
+
+#[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+pub const LIBSUM: &str = "tests/resource/libsum-aarch64.so";
+
+#[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+pub const LIBSUM: &str = "tests/resource/libsum-x86_64.dylib";
+
+#[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+pub const LIBSUM: &str = "tests/resource/libsum-aarch64.dylib";
+
+#[cfg(all(target_os = "windows", target_arch = "x86"))]
+pub const LIBSUM: &str = "tests/resource/libsum-x86.dll";
+
+#[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+pub const LIBSUM: &str = "tests/resource/libsum-x86_64.dll";
+
+#[cfg(all(target_os = "windows", target_arch = "aarch64"))]
+pub const LIBSUM: &str = "tests/resource/libsum-aarch64.dll";
+
+#[cfg(target_os = "linux")]
+pub const LIBUNKNOWN: &str = "tests/resource/unknown.so";
+
+#[cfg(target_os = "macos")]
+pub const LIBUNKNOWN: &str = "tests/resource/unknown.dylib";
+
+#[cfg(target_os = "windows")]
+pub const LIBUNKNOWN: &str = "tests/resource/unknown.dll";
+
+pub const SYMBOL_SUM: &str = "sum_of";
+
+pub const SYMBOL_UNKNOWN: &str = "unknown";
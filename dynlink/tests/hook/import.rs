@@ -0,0 +1,100 @@
+use std::{ffi, thread};
+
+use dynlink::{
+    api::Handle,
+    hook::{hook_import, HookError},
+};
+
+pub const LIBIMPORT: &str = "tests/resource/libimport-x86_64.so";
+
+pub const SYMBOL_PARSE: &str = "parse_of";
+
+unsafe extern "C" fn fake_atoi(_: *const ffi::c_char) -> ffi::c_int {
+    42
+}
+
+#[test]
+pub fn hook_import_redirects_import_until_dropped() {
+    unsafe {
+        let lib = Handle::open(LIBIMPORT).expect("Shared object was not opened");
+
+        let parse_fn = lib
+            .lookup::<extern "C" fn(*const ffi::c_char) -> ffi::c_int>(SYMBOL_PARSE)
+            .expect("Symbol was not found");
+
+        assert_eq!(1, parse_fn.apply(|it| it(c"1".as_ptr())));
+
+        let hook = hook_import(
+            &lib,
+            "atoi",
+            fake_atoi as unsafe extern "C" fn(*const ffi::c_char) -> ffi::c_int,
+        )
+        .expect("Import was not hooked");
+
+        assert_eq!(42, parse_fn.apply(|it| it(c"1".as_ptr())));
+        // other modules importing the same symbol are not affected
+        assert_eq!(1, libc::atoi(c"1".as_ptr()));
+
+        drop(hook);
+        assert_eq!(1, parse_fn.apply(|it| it(c"1".as_ptr())));
+    }
+}
+
+#[test]
+pub fn hook_import_fails_when_symbol_is_not_imported() {
+    unsafe {
+        let lib = Handle::open(LIBIMPORT).expect("Shared object was not opened");
+
+        let err = hook_import(
+            &lib,
+            "unknown",
+            fake_atoi as unsafe extern "C" fn(*const ffi::c_char) -> ffi::c_int,
+        )
+        .expect_err("Unknown import was hooked");
+
+        assert!(matches!(err, HookError::NotImported));
+    }
+}
+
+#[test]
+pub fn hook_import_hooks_imports_of_same_page_concurrently() {
+    unsafe extern "C" fn fake_gmon_start() {}
+
+    let lib = unsafe { Handle::open(LIBIMPORT).expect("Shared object was not opened") };
+
+    thread::scope(|scope| {
+        scope.spawn(|| unsafe {
+            for _ in 0..200 {
+                let hook = hook_import(
+                    &lib,
+                    "atoi",
+                    fake_atoi as unsafe extern "C" fn(*const ffi::c_char) -> ffi::c_int,
+                )
+                .expect("Import was not hooked");
+
+                drop(hook);
+            }
+        });
+
+        scope.spawn(|| unsafe {
+            for _ in 0..200 {
+                let hook = hook_import(
+                    &lib,
+                    "__gmon_start__",
+                    fake_gmon_start as unsafe extern "C" fn(),
+                )
+                .expect("Import was not hooked");
+
+                drop(hook);
+            }
+        });
+    });
+
+    unsafe {
+        let parse_fn = lib
+            .lookup::<extern "C" fn(*const ffi::c_char) -> ffi::c_int>(SYMBOL_PARSE)
+            .expect("Symbol was not found");
+
+        assert_eq!(1, parse_fn.apply(|it| it(c"1".as_ptr())));
+    }
+}
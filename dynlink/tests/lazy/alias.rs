@@ -0,0 +1,15 @@
+use crate::optional::lookup::LIBNULLALIAS;
+
+dynlink::lazy_library! {
+    static LIBNULLALIAS_LAZY: LibNullAlias = LIBNULLALIAS;
+
+    extern "C" fn sum(a: i32, b: i32) -> i32 as ["sum_of_v2", "sum_of"];
+}
+
+#[test]
+pub fn lazy_library_skips_alias_with_null_address() {
+    unsafe {
+        let sum = LIBNULLALIAS_LAZY.sum(1, 1).expect("Symbol was unavailable");
+        assert_eq!(2, sum);
+    }
+}
@@ -0,0 +1,119 @@
+use std::thread;
+
+use crate::fixture::{LIBSUM, LIBUNKNOWN};
+
+dynlink::lazy_library! {
+    static LIBSUM_LAZY: LibSum = LIBSUM;
+
+    extern "C" fn sum_of(a: i32, b: i32) -> i32;
+    extern "C" fn unknown(a: i32, b: i32) -> i32;
+    extern "C" fn sum(a: i32, b: i32) -> i32 as ["sum_of_v2", "sum_of"];
+    extern "C" fn renamed(a: i32, b: i32) -> i32 as ["renamed_v2", "renamed"];
+}
+
+dynlink::lazy_library! {
+    static LIBUNKNOWN_LAZY: LibUnknown = LIBUNKNOWN;
+
+    extern "C" fn sum_of(a: i32, b: i32) -> i32;
+}
+
+dynlink::lazy_library! {
+    /// Lazily opened libsum kept by `#[used]`, which is valid on statics only.
+    #[used]
+    static LIBSUM_USED: LibSumUsed = LIBSUM;
+
+    extern "C" fn sum_of(a: i32, b: i32) -> i32;
+}
+
+dynlink::lazy_library! {
+    static LIBSUM_NAMED: LibSumNamed = LIBSUM;
+
+    extern "C" fn library(a: i32, b: i32) -> i32 as ["sum_of"];
+}
+
+#[test]
+pub fn lazy_library_calls_function_when_symbol_exists() {
+    unsafe {
+        let sum = LIBSUM_LAZY.sum_of(1, 1).expect("Symbol was unavailable");
+        assert_eq!(2, sum);
+    }
+}
+
+#[test]
+pub fn lazy_library_fails_with_symbol_error_when_symbol_does_not_exist() {
+    unsafe {
+        let err = LIBSUM_LAZY
+            .unknown(1, 1)
+            .expect_err("Unknown symbol was available");
+
+        assert!(matches!(
+            err,
+            dynlink::lazy::LazyError::Symbol("unknown", _)
+        ));
+    }
+}
+
+#[test]
+pub fn lazy_library_calls_function_by_first_alias_that_exists() {
+    unsafe {
+        let sum = LIBSUM_LAZY.sum(1, 1).expect("Symbol was unavailable");
+        assert_eq!(2, sum);
+    }
+}
+
+#[test]
+pub fn lazy_library_fails_with_aliases_error_when_no_alias_exists() {
+    unsafe {
+        let err = LIBSUM_LAZY
+            .renamed(1, 1)
+            .expect_err("Unknown symbol was available");
+
+        match err {
+            dynlink::lazy::LazyError::Aliases("renamed", err) => {
+                assert_eq!(2, err.attempts().len());
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+}
+
+#[test]
+pub fn lazy_library_fails_with_library_error_when_path_does_not_exist() {
+    unsafe {
+        let err = LIBUNKNOWN_LAZY
+            .sum_of(1, 1)
+            .expect_err("Unknown shared object was available");
+
+        assert!(matches!(err, dynlink::lazy::LazyError::Library(path, _) if path == LIBUNKNOWN));
+    }
+}
+
+#[test]
+pub fn lazy_library_resolves_once_under_concurrent_first_calls() {
+    let sums = (0..16)
+        .map(|it| thread::spawn(move || unsafe { LIBSUM_LAZY.sum_of(it, it) }))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|it| it.join().expect("Thread panicked"))
+        .collect::<Vec<_>>();
+
+    for (it, sum) in sums.into_iter().enumerate() {
+        assert_eq!(Ok(2 * it as i32), sum.map_err(|_| ()));
+    }
+}
+
+#[test]
+pub fn lazy_library_applies_attributes_to_static_only() {
+    unsafe {
+        let sum = LIBSUM_USED.sum_of(1, 1).expect("Symbol was unavailable");
+        assert_eq!(2, sum);
+    }
+}
+
+#[test]
+pub fn lazy_library_declares_function_named_library() {
+    unsafe {
+        let sum = LIBSUM_NAMED.library(1, 1).expect("Symbol was unavailable");
+        assert_eq!(2, sum);
+    }
+}
@@ -0,0 +1,65 @@
+use std::{ffi::OsStr, sync::Arc};
+
+use dynlink::{
+    api::Handle,
+    loader::{self, LoaderEvent, RecordingHooks},
+};
+
+use crate::fixture::{LIBSUM, LIBUNKNOWN, SYMBOL_SUM, SYMBOL_UNKNOWN};
+
+fn is_open(event: &LoaderEvent, path: &str, is_ok: bool) -> bool {
+    matches!(event, LoaderEvent::Open { .. })
+        && event.path() == OsStr::new(path)
+        && event.is_ok() == is_ok
+}
+
+fn is_lookup(event: &LoaderEvent, path: &str, symbol: &str, is_ok: bool) -> bool {
+    match event {
+        LoaderEvent::Lookup { symbol: name, .. } => {
+            event.path() == OsStr::new(path) && name == symbol && event.is_ok() == is_ok
+        }
+        _ => false,
+    }
+}
+
+fn is_close(event: &LoaderEvent, path: &str) -> bool {
+    matches!(event, LoaderEvent::Close { .. }) && event.path() == OsStr::new(path) && event.is_ok()
+}
+
+// Hooks are process-wide, so all assertions share a single test
+// and tolerate events of handles opened by concurrently running tests.
+#[test]
+pub fn recording_hooks_record_open_lookup_and_close_events() {
+    let hooks = Arc::new(RecordingHooks::new());
+    loader::set_hooks(hooks.clone());
+
+    unsafe {
+        let handle = Handle::open(LIBSUM).expect("Handle was not opened");
+        assert_eq!(OsStr::new(LIBSUM), handle.path());
+
+        let _ = handle
+            .lookup::<extern "C" fn(i32, i32) -> i32>(SYMBOL_SUM)
+            .expect("Symbol was not found");
+
+        let _ = handle
+            .lookup::<extern "C" fn(i32, i32) -> i32>(SYMBOL_UNKNOWN)
+            .expect_err("Unknown symbol was found");
+
+        drop(handle);
+
+        let _ = Handle::open(LIBUNKNOWN).expect_err("Unknown handle was opened");
+    }
+
+    let _ = loader::take_hooks().expect("Hooks were not installed");
+    let events = hooks.take();
+
+    assert!(events.iter().any(|it| is_open(it, LIBSUM, true)));
+    assert!(events
+        .iter()
+        .any(|it| is_lookup(it, LIBSUM, SYMBOL_SUM, true)));
+    assert!(events
+        .iter()
+        .any(|it| is_lookup(it, LIBSUM, SYMBOL_UNKNOWN, false)));
+    assert!(events.iter().any(|it| is_close(it, LIBSUM)));
+    assert!(events.iter().any(|it| is_open(it, LIBUNKNOWN, false)));
+}
@@ -0,0 +1,78 @@
+// tracing hooks take precedence over log hooks when both features are enabled
+#![cfg(all(
+    not(feature = "tracing"),
+    any(
+        all(
+            target_os = "linux",
+            any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
+        ),
+        all(
+            target_os = "macos",
+            any(target_arch = "aarch64", target_arch = "x86_64"),
+        ),
+        all(
+            target_os = "windows",
+            any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
+        ),
+    )
+))]
+
+#[path = "fixture/mod.rs"]
+#[allow(dead_code)]
+mod fixture;
+
+use std::sync::Mutex;
+
+use dynlink::api::Handle;
+
+use crate::fixture::{LIBSUM, SYMBOL_SUM};
+
+struct RecordingLogger(Mutex<Vec<String>>);
+
+impl log::Log for RecordingLogger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.target() == "dynlink::loader"
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if self.enabled(record.metadata()) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", record.level(), record.args()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: RecordingLogger = RecordingLogger(Mutex::new(Vec::new()));
+
+#[test]
+pub fn log_hooks_emit_open_lookup_and_close_records() {
+    log::set_logger(&LOGGER).expect("Logger was not set");
+    log::set_max_level(log::LevelFilter::Debug);
+
+    unsafe {
+        let handle = Handle::open(LIBSUM).expect("Handle was not opened");
+
+        let _ = handle
+            .lookup::<extern "C" fn(i32, i32) -> i32>(SYMBOL_SUM)
+            .expect("Symbol was not found");
+
+        drop(handle);
+    }
+
+    let records = LOGGER.0.lock().unwrap();
+    let path = format!("{:?}", LIBSUM);
+
+    assert!(records
+        .iter()
+        .any(|it| it.starts_with(&format!("DEBUG Opened {}", path))));
+    assert!(records
+        .iter()
+        .any(|it| it.starts_with(&format!("DEBUG Looked up {} in {}", SYMBOL_SUM, path))));
+    assert!(records
+        .iter()
+        .any(|it| it.starts_with(&format!("DEBUG Closed {}", path))));
+}
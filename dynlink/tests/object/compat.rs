@@ -0,0 +1,88 @@
+use dynlink::object::{self, Architecture, CompatibilityError, Format, Header};
+
+use crate::object::header::{
+    LIBSUM_AARCH64_SO, LIBSUM_X86_64_DLL, LIBSUM_X86_64_DYLIB, LIBSUM_X86_64_SO, LIBSUM_X86_SO,
+};
+
+#[test]
+pub fn check_compatible_succeeds_when_file_matches_process() {
+    let header = object::check_compatible(LIBSUM_X86_64_SO).expect("Fixture was incompatible");
+
+    assert_eq!(&[Architecture::X86_64], header.architectures());
+}
+
+#[test]
+pub fn check_compatible_fails_with_both_architectures_when_architecture_differs() {
+    for (path, found) in [
+        (LIBSUM_AARCH64_SO, Architecture::Aarch64),
+        (LIBSUM_X86_SO, Architecture::X86),
+    ] {
+        let err = object::check_compatible(path).expect_err("Fixture was compatible");
+
+        match &err {
+            CompatibilityError::Architecture(expected, architectures) => {
+                assert_eq!(Architecture::X86_64, *expected);
+                assert_eq!(std::slice::from_ref(&found), architectures.as_slice());
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+
+        let message = err.to_string();
+        assert!(message.contains("x86_64"));
+        assert!(message.contains(found.name()));
+    }
+}
+
+#[test]
+pub fn check_compatible_fails_with_both_formats_when_format_differs() {
+    for (path, found) in [
+        (LIBSUM_X86_64_DLL, Format::Pe),
+        (LIBSUM_X86_64_DYLIB, Format::MachO),
+    ] {
+        let err = object::check_compatible(path).expect_err("Fixture was compatible");
+
+        match err {
+            CompatibilityError::Format(expected, format) => {
+                assert_eq!(Format::Elf, expected);
+                assert_eq!(found, format);
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+    }
+}
+
+#[test]
+pub fn check_host_fails_with_both_byte_orders_when_byte_order_differs() {
+    // big-endian x86_64 file with the machine swapped accordingly
+    let mut data = std::fs::read(LIBSUM_X86_64_SO).expect("Fixture was not read");
+    data[5] = 2;
+    data.swap(18, 19);
+
+    let header = Header::parse(&data).expect("Header was not parsed");
+    assert_eq!(&[Architecture::X86_64], header.architectures());
+
+    let err = header.check_host().expect_err("Fixture was compatible");
+
+    assert!(matches!(err, CompatibilityError::ByteOrder(true, false)));
+    assert_eq!(
+        "Object file is incompatible: expected little-endian byte order, found big-endian",
+        err.to_string()
+    );
+}
+
+#[test]
+pub fn check_host_fails_with_architecture_when_x32_file_is_checked() {
+    let mut data = std::fs::read(LIBSUM_X86_64_SO).expect("Fixture was not read");
+    data[4] = 1;
+
+    let header = Header::parse(&data).expect("Header was not parsed");
+    let err = header.check_host().expect_err("Fixture was compatible");
+
+    match err {
+        CompatibilityError::Architecture(expected, architectures) => {
+            assert_eq!(Architecture::X86_64, expected);
+            assert_eq!(vec![Architecture::Unknown(62)], architectures);
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
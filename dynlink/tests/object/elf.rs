@@ -0,0 +1,203 @@
+use std::fs;
+
+use dynlink::object::{
+    elf::{
+        ElfFile, ET_DYN, PF_R, PF_X, PT_DYNAMIC, PT_GNU_STACK, PT_LOAD, PT_NOTE, STB_GLOBAL,
+        STT_FUNC,
+    },
+    Architecture, ObjectError, ObjectInfo, OsAbi,
+};
+
+use crate::object::header::{
+    LIBSUM_AARCH64_SO, LIBSUM_X86_64_DLL, LIBSUM_X86_64_SO, LIBSUM_X86_SO,
+};
+
+pub const LIBIMPORT_X86_64_SO: &str = "tests/resource/libimport-x86_64.so";
+pub const LIBVERSIONED_X86_64_SO: &str = "tests/resource/libversioned-x86_64.so";
+
+fn open(path: &str) -> ElfFile<'static> {
+    ElfFile::open(path).expect("ELF file was not parsed")
+}
+
+#[test]
+pub fn elf_file_reads_headers_of_every_class() {
+    for (path, architecture, is_64) in [
+        (LIBSUM_X86_SO, Architecture::X86, false),
+        (LIBSUM_X86_64_SO, Architecture::X86_64, true),
+        (LIBSUM_AARCH64_SO, Architecture::Aarch64, true),
+    ] {
+        let file = open(path);
+
+        assert_eq!(is_64, file.is_64());
+        assert!(file.is_little());
+        assert_eq!(ET_DYN, file.file_type());
+        assert_eq!(architecture, file.architecture());
+        assert_eq!(OsAbi::SystemV, file.os_abi());
+
+        let headers = file.program_headers();
+        assert!(headers.iter().any(|it| it.kind() == PT_DYNAMIC));
+        assert!(headers
+            .iter()
+            .any(|it| it.kind() == PT_LOAD && it.flags() == PF_R | PF_X));
+    }
+}
+
+#[test]
+pub fn elf_file_exports_defined_symbols_of_every_class() {
+    for path in [LIBSUM_X86_SO, LIBSUM_X86_64_SO, LIBSUM_AARCH64_SO] {
+        let exports = open(path).exports().expect("Exports were not read");
+        let sum_of = exports
+            .iter()
+            .find(|it| it.name() == "sum_of")
+            .expect("sum_of was not exported");
+
+        assert_eq!(STT_FUNC, sum_of.kind());
+        assert_eq!(STB_GLOBAL, sum_of.binding());
+        assert_ne!(0, sum_of.value());
+        assert!(exports.iter().all(|it| it.is_defined()));
+    }
+}
+
+#[test]
+pub fn elf_file_borrows_parsed_bytes() {
+    let data = fs::read(LIBSUM_X86_64_SO).expect("Fixture was not read");
+    let file = ElfFile::parse(&data).expect("ELF file was not parsed");
+
+    assert_eq!(Some("libsum.so".to_owned()), file.soname().unwrap());
+}
+
+#[test]
+pub fn elf_file_reads_imports_and_required_versions() {
+    let file = open(LIBIMPORT_X86_64_SO);
+
+    let imports = file.imports().expect("Imports were not read");
+    let atoi = imports
+        .iter()
+        .find(|it| it.name() == "atoi")
+        .expect("atoi was not imported");
+    assert_eq!(Some("GLIBC_2.2.5"), atoi.version());
+    assert!(!imports.iter().any(|it| it.name() == "parse_of"));
+
+    let needs = file.version_needs().expect("Version needs were not read");
+    let libc = needs
+        .iter()
+        .find(|it| it.file() == "libc.so.6")
+        .expect("libc.so.6 was not required");
+    assert!(libc.versions().iter().any(|it| it.name() == "GLIBC_2.2.5"));
+}
+
+#[test]
+pub fn elf_file_reads_version_definitions_and_symbol_versions() {
+    let file = open(LIBVERSIONED_X86_64_SO);
+
+    let definitions = file
+        .version_definitions()
+        .expect("Version definitions were not read");
+    let names = definitions.iter().map(|it| it.name()).collect::<Vec<_>>();
+    assert_eq!(vec!["libversioned.so", "VERS_1", "VERS_2"], names);
+    assert_eq!(&["VERS_1".to_owned()], definitions[2].parents());
+
+    let exports = file.exports().expect("Exports were not read");
+    let versions = exports
+        .iter()
+        .map(|it| (it.name(), it.version(), it.is_hidden()))
+        .collect::<Vec<_>>();
+    assert_eq!(2, versions.len());
+    assert!(versions.contains(&("versioned_of", Some("VERS_1"), false)));
+    assert!(versions.contains(&("versioned_of_v2", Some("VERS_2"), false)));
+}
+
+#[test]
+pub fn elf_file_reads_dynamic_names_and_build_id() {
+    let file = open(LIBVERSIONED_X86_64_SO);
+
+    assert_eq!(Some("libversioned.so".to_owned()), file.soname().unwrap());
+    assert_eq!(
+        Some("/opt/versioned/lib".to_owned()),
+        file.runpath().unwrap()
+    );
+    assert_eq!(None, file.rpath().unwrap());
+    assert_eq!(
+        20,
+        file.build_id()
+            .unwrap()
+            .expect("Build ID was missing")
+            .len()
+    );
+}
+
+#[test]
+pub fn elf_file_fails_when_file_is_not_elf() {
+    let err = ElfFile::open(LIBSUM_X86_64_DLL).expect_err("DLL was parsed");
+
+    assert!(matches!(err, ObjectError::UnknownFormat));
+}
+
+/// Reads everything the file describes, which must fail instead of panicking on malformed input.
+fn read_all(data: &[u8]) {
+    if let Ok(file) = ElfFile::parse(data) {
+        let _ = file.build_id();
+        let _ = file.needed();
+        let _ = file.soname();
+        let _ = file.version_definitions();
+        let _ = file.version_needs();
+        let _ = file.symbols();
+    }
+
+    let _ = ObjectInfo::parse(data);
+}
+
+/// Returns the offset of the first program header of `kind` in a 64-bit little-endian file.
+fn program_header_of(data: &[u8], kind: u32) -> usize {
+    let phoff = u64::from_le_bytes(data[32..40].try_into().unwrap()) as usize;
+    let phentsize = u16::from_le_bytes(data[54..56].try_into().unwrap()) as usize;
+    let phnum = u16::from_le_bytes(data[56..58].try_into().unwrap()) as usize;
+
+    (0..phnum)
+        .map(|it| phoff + it * phentsize)
+        .find(|it| u32::from_le_bytes(data[*it..*it + 4].try_into().unwrap()) == kind)
+        .expect("Program header was not found")
+}
+
+#[test]
+pub fn elf_file_fails_when_note_size_overflows() {
+    let mut data = fs::read(LIBSUM_X86_64_SO).expect("Fixture was not read");
+
+    // turns the stack header into a note that starts at the program headers and never ends
+    let note = program_header_of(&data, PT_GNU_STACK);
+    data[note..note + 4].copy_from_slice(&PT_NOTE.to_le_bytes());
+    data[note + 8..note + 16].copy_from_slice(&64_u64.to_le_bytes());
+    data[note + 32..note + 40].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    let err = ElfFile::parse(&data)
+        .expect("ELF file was not parsed")
+        .build_id()
+        .expect_err("Build ID was read");
+
+    assert!(matches!(err, ObjectError::Malformed(_)));
+    read_all(&data);
+}
+
+#[test]
+pub fn elf_file_fails_when_section_header_offset_overflows() {
+    let mut data = fs::read(LIBSUM_X86_64_SO).expect("Fixture was not read");
+    data[40..48].copy_from_slice(&(u64::MAX - 2).to_le_bytes());
+
+    let err = ElfFile::parse(&data).expect_err("ELF file was parsed");
+
+    assert!(matches!(err, ObjectError::Malformed(_)));
+}
+
+#[test]
+pub fn elf_file_does_not_panic_when_words_are_mutated() {
+    let data = fs::read(LIBSUM_X86_64_SO).expect("Fixture was not read");
+
+    for offset in (0..data.len() - 8).step_by(4) {
+        for value in [u64::MAX, u64::MAX / 2 + 1, 0] {
+            let mut mutated = data.clone();
+            mutated[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+
+            read_all(&mutated);
+        }
+    }
+}
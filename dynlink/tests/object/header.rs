@@ -0,0 +1,134 @@
+use dynlink::object::{Architecture, Format, Header, ObjectError, OsAbi};
+
+pub const LIBSUM_X86_SO: &str = "tests/resource/libsum-x86.so";
+pub const LIBSUM_X86_64_SO: &str = "tests/resource/libsum-x86_64.so";
+pub const LIBSUM_AARCH64_SO: &str = "tests/resource/libsum-aarch64.so";
+pub const LIBSUM_X86_64_DYLIB: &str = "tests/resource/libsum-x86_64.dylib";
+pub const LIBSUM_AARCH64_DYLIB: &str = "tests/resource/libsum-aarch64.dylib";
+pub const LIBSUM_X86_DLL: &str = "tests/resource/libsum-x86.dll";
+pub const LIBSUM_X86_64_DLL: &str = "tests/resource/libsum-x86_64.dll";
+pub const LIBSUM_AARCH64_DLL: &str = "tests/resource/libsum-aarch64.dll";
+
+fn header_of(path: &str) -> Header {
+    Header::read(path).expect("Header was not read")
+}
+
+#[test]
+pub fn header_reads_elf_architectures() {
+    for (path, architecture) in [
+        (LIBSUM_X86_SO, Architecture::X86),
+        (LIBSUM_X86_64_SO, Architecture::X86_64),
+        (LIBSUM_AARCH64_SO, Architecture::Aarch64),
+    ] {
+        let header = header_of(path);
+
+        assert_eq!(Format::Elf, *header.format());
+        assert_eq!(&[architecture], header.architectures());
+        assert_eq!(Some(&OsAbi::SystemV), header.os_abi());
+        assert_eq!(Some(true), header.is_little());
+    }
+
+    assert_eq!(Some(false), header_of(LIBSUM_X86_SO).is_64());
+    assert_eq!(Some(true), header_of(LIBSUM_X86_64_SO).is_64());
+    assert_eq!(Some(true), header_of(LIBSUM_AARCH64_SO).is_64());
+}
+
+#[test]
+pub fn header_reads_unknown_architecture_when_class_does_not_match_machine() {
+    // x86_64 machine in the 32-bit class, as used by the x32 ABI
+    let mut data = std::fs::read(LIBSUM_X86_64_SO).expect("Fixture was not read");
+    data[4] = 1;
+
+    let header = Header::parse(&data).expect("Header was not parsed");
+
+    assert_eq!(Some(false), header.is_64());
+    assert_eq!(&[Architecture::Unknown(62)], header.architectures());
+}
+
+#[test]
+pub fn header_reads_macho_architectures() {
+    for (path, architecture) in [
+        (LIBSUM_X86_64_DYLIB, Architecture::X86_64),
+        (LIBSUM_AARCH64_DYLIB, Architecture::Aarch64),
+    ] {
+        let header = header_of(path);
+
+        assert_eq!(Format::MachO, *header.format());
+        assert_eq!(&[architecture], header.architectures());
+        assert_eq!(None, header.os_abi());
+        assert_eq!(None, header.is_64());
+    }
+}
+
+#[test]
+pub fn header_reads_pe_architectures() {
+    for (path, architecture) in [
+        (LIBSUM_X86_DLL, Architecture::X86),
+        (LIBSUM_X86_64_DLL, Architecture::X86_64),
+        (LIBSUM_AARCH64_DLL, Architecture::Aarch64),
+    ] {
+        let header = header_of(path);
+
+        assert_eq!(Format::Pe, *header.format());
+        assert_eq!(&[architecture], header.architectures());
+    }
+}
+
+/// Builds a fat Mach-O file of the x86_64 and aarch64 dylib fixtures.
+pub fn fat_macho() -> Vec<u8> {
+    let x86_64 = std::fs::read(LIBSUM_X86_64_DYLIB).expect("Fixture was not read");
+    let aarch64 = std::fs::read(LIBSUM_AARCH64_DYLIB).expect("Fixture was not read");
+
+    // fat_header and fat_arch entries with offsets aligned to 4 KiB
+    let mut fat = Vec::new();
+    fat.extend_from_slice(&0xcafe_babe_u32.to_be_bytes());
+    fat.extend_from_slice(&2_u32.to_be_bytes());
+
+    let x86_64_offset = 0x1000_u32;
+    let aarch64_offset = (x86_64_offset + x86_64.len() as u32).next_multiple_of(0x1000);
+
+    for (cputype, offset, size) in [
+        (0x0100_0007_u32, x86_64_offset, x86_64.len() as u32),
+        (0x0100_000c_u32, aarch64_offset, aarch64.len() as u32),
+    ] {
+        for field in [cputype, 0, offset, size, 12] {
+            fat.extend_from_slice(&field.to_be_bytes());
+        }
+    }
+
+    fat.resize(x86_64_offset as usize, 0);
+    fat.extend_from_slice(&x86_64);
+    fat.resize(aarch64_offset as usize, 0);
+    fat.extend_from_slice(&aarch64);
+
+    fat
+}
+
+#[test]
+pub fn header_reads_fat_macho_architectures() {
+    let fat = fat_macho();
+    let header = Header::parse(&fat).expect("Header was not parsed");
+
+    assert_eq!(Format::MachO, *header.format());
+    assert_eq!(
+        &[Architecture::X86_64, Architecture::Aarch64],
+        header.architectures()
+    );
+}
+
+#[test]
+pub fn header_fails_when_format_is_unknown() {
+    let err = Header::parse(b"#!/bin/sh\n").expect_err("Unknown format was parsed");
+
+    assert!(matches!(err, ObjectError::UnknownFormat));
+}
+
+#[test]
+pub fn header_fails_when_elf_class_is_invalid() {
+    let mut data = std::fs::read(LIBSUM_X86_64_SO).expect("Fixture was not read");
+    data[4] = 3;
+
+    let err = Header::parse(&data).expect_err("Invalid class was parsed");
+
+    assert!(matches!(err, ObjectError::Malformed("ELF class")));
+}
@@ -0,0 +1,103 @@
+use dynlink::object::{Architecture, BuildId, Format, ObjectError, ObjectInfo};
+
+use crate::object::{
+    elf::{LIBIMPORT_X86_64_SO, LIBVERSIONED_X86_64_SO},
+    header::{
+        fat_macho, LIBSUM_AARCH64_DLL, LIBSUM_AARCH64_DYLIB, LIBSUM_AARCH64_SO, LIBSUM_X86_64_DLL,
+        LIBSUM_X86_64_DYLIB, LIBSUM_X86_64_SO, LIBSUM_X86_DLL, LIBSUM_X86_SO,
+    },
+    pe::synthetic_dll,
+};
+
+fn open(path: &str) -> ObjectInfo {
+    ObjectInfo::open(path).expect("Object file was not parsed")
+}
+
+#[test]
+pub fn object_info_reports_same_exports_in_every_format() {
+    for (path, format, architecture) in [
+        (LIBSUM_X86_SO, Format::Elf, Architecture::X86),
+        (LIBSUM_X86_64_SO, Format::Elf, Architecture::X86_64),
+        (LIBSUM_AARCH64_SO, Format::Elf, Architecture::Aarch64),
+        (LIBSUM_X86_64_DYLIB, Format::MachO, Architecture::X86_64),
+        (LIBSUM_AARCH64_DYLIB, Format::MachO, Architecture::Aarch64),
+        (LIBSUM_X86_DLL, Format::Pe, Architecture::X86),
+        (LIBSUM_X86_64_DLL, Format::Pe, Architecture::X86_64),
+        (LIBSUM_AARCH64_DLL, Format::Pe, Architecture::Aarch64),
+    ] {
+        let info = open(path);
+
+        assert_eq!(format, *info.format());
+        assert_eq!(architecture, *info.architecture());
+        assert_eq!(std::slice::from_ref(&architecture), info.architectures());
+        assert!(
+            info.exports().iter().any(|it| it == "sum_of"),
+            "sum_of was not exported by {}",
+            path
+        );
+    }
+}
+
+#[test]
+pub fn object_info_reports_identity_in_every_format() {
+    for (path, name) in [
+        (LIBSUM_X86_64_SO, "libsum.so"),
+        (LIBSUM_X86_64_DYLIB, "libsum.dylib"),
+        (LIBSUM_X86_64_DLL, "sum.dll"),
+    ] {
+        assert_eq!(Some(name), open(path).name());
+    }
+
+    let elf = open(LIBVERSIONED_X86_64_SO);
+    assert!(matches!(elf.build_id(), Some(BuildId::Gnu(_))));
+    assert_eq!(
+        "a83dbcbf3d989615ff09beea1195dd75200c6b83",
+        elf.build_id().unwrap().to_string()
+    );
+
+    let macho = open(LIBSUM_X86_64_DYLIB);
+    assert_eq!(
+        "15AD40FF-E727-31F9-8AF8-093F74EC3BF8",
+        macho.build_id().expect("UUID was missing").to_string()
+    );
+
+    let pe = ObjectInfo::parse(&synthetic_dll()).expect("Object file was not parsed");
+    assert_eq!(
+        "3F2504E04F8911D39A0C0305E82C33013",
+        pe.build_id()
+            .expect("PDB identifier was missing")
+            .to_string()
+    );
+}
+
+#[test]
+pub fn object_info_reports_dependencies_in_every_format() {
+    let elf = open(LIBIMPORT_X86_64_SO);
+    assert_eq!(&["libc.so.6"], elf.dependencies());
+
+    let macho = open(LIBSUM_AARCH64_DYLIB);
+    assert_eq!(&["/usr/lib/libSystem.B.dylib"], macho.dependencies());
+
+    let pe = ObjectInfo::parse(&synthetic_dll()).expect("Object file was not parsed");
+    assert_eq!(&["user32.dll"], pe.dependencies());
+}
+
+#[test]
+pub fn object_info_describes_one_file_of_fat_macho() {
+    let info = ObjectInfo::parse(&fat_macho()).expect("Object file was not parsed");
+
+    assert_eq!(Format::MachO, *info.format());
+    assert_eq!(
+        &[Architecture::X86_64, Architecture::Aarch64],
+        info.architectures()
+    );
+    assert!(info.architectures().contains(info.architecture()));
+    assert_eq!(Some("libsum.dylib"), info.name());
+}
+
+#[test]
+pub fn object_info_fails_when_format_is_unknown() {
+    let err = ObjectInfo::parse(b"#!/bin/sh\n").expect_err("Unknown format was parsed");
+
+    assert!(matches!(err, ObjectError::UnknownFormat));
+}
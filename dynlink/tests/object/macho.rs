@@ -0,0 +1,191 @@
+use dynlink::object::{
+    macho::{
+        DylibVersion, FatFile, MachOFile, EXPORT_SYMBOL_FLAGS_REEXPORT, LC_LOAD_DYLIB,
+        LC_LOAD_WEAK_DYLIB, LC_REEXPORT_DYLIB, MH_DYLIB,
+    },
+    Architecture, ObjectError,
+};
+
+use crate::object::header::{fat_macho, LIBSUM_AARCH64_DYLIB, LIBSUM_X86_64_DYLIB};
+
+fn open(path: &str) -> MachOFile<'static> {
+    MachOFile::open(path).expect("Mach-O file was not parsed")
+}
+
+/// Builds an arm64 dylib with rpaths, weak and re-exported dependencies, and an exports trie
+/// holding a re-export and a regular export.
+fn synthetic_dylib() -> Vec<u8> {
+    fn command(cmd: u32, fields: &[u32], string: &str) -> Vec<u8> {
+        let offset = 8 + fields.len() as u32 * 4;
+        let size = (offset as usize + string.len() + 1).next_multiple_of(8);
+
+        let mut command = Vec::new();
+        for field in [cmd, size as u32].iter().chain(fields) {
+            command.extend_from_slice(&field.to_le_bytes());
+        }
+        command.extend_from_slice(string.as_bytes());
+        command.resize(size, 0);
+        command
+    }
+
+    // name offset, timestamp, current and compatibility versions
+    let commands = [
+        command(
+            0xd,
+            &[24, 0, 0x0002_0100, 0x0001_0000],
+            "@rpath/libsynthetic.dylib",
+        ),
+        command(
+            0x8000_001f,
+            &[24, 0, 0x0001_0000, 0x0001_0000],
+            "@rpath/libbase.dylib",
+        ),
+        command(
+            0x8000_0018,
+            &[24, 0, 0x0001_0000, 0x0001_0000],
+            "/opt/lib/libweak.dylib",
+        ),
+        command(0x8000_001c, &[12], "@loader_path/../lib"),
+        command(0x8000_001c, &[12], "/opt/lib"),
+    ];
+
+    // root with edges to a re-export of the first dependency and to a regular export
+    let mut trie = vec![0, 2];
+    trie.extend_from_slice(b"_alias\0");
+    trie.push(18);
+    trie.extend_from_slice(b"_local\0");
+    trie.push(32);
+    trie.extend_from_slice(&[12, 0x08, 1]);
+    trie.extend_from_slice(b"_original\0");
+    trie.push(0);
+    trie.extend_from_slice(&[3, 0, 0xb4, 0x24, 0]);
+
+    let size = commands.iter().map(|it| it.len()).sum::<usize>() + 16;
+    let trie_offset = 32 + size as u32;
+
+    let mut data = Vec::new();
+    for field in [0xfeed_facf, 0x0100_000c, 0, 6, 6, size as u32, 0, 0] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    for command in &commands {
+        data.extend_from_slice(command);
+    }
+    for field in [0x8000_0033, 16, trie_offset, trie.len() as u32] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(&trie);
+    data
+}
+
+#[test]
+pub fn macho_file_reads_install_name_and_dependencies_of_every_architecture() {
+    for (path, architecture) in [
+        (LIBSUM_X86_64_DYLIB, Architecture::X86_64),
+        (LIBSUM_AARCH64_DYLIB, Architecture::Aarch64),
+    ] {
+        let file = open(path);
+
+        assert!(file.is_64());
+        assert_eq!(architecture, file.architecture());
+        assert_eq!(MH_DYLIB, file.file_type());
+        assert!(file.uuid().is_some());
+        assert!(file.rpaths().is_empty());
+
+        let install_name = file.install_name().expect("Install name was missing");
+        assert_eq!("libsum.dylib", install_name.name());
+        assert_eq!("1.0.0", install_name.current_version().to_string());
+        assert_eq!("1.0.0", install_name.compatibility_version().to_string());
+
+        let dependencies = file.dependencies();
+        assert_eq!(1, dependencies.len());
+        assert_eq!(LC_LOAD_DYLIB, dependencies[0].command());
+        assert_eq!("/usr/lib/libSystem.B.dylib", dependencies[0].name());
+        assert_eq!(1351, dependencies[0].current_version().major());
+    }
+}
+
+#[test]
+pub fn macho_file_reads_exports_trie_of_every_architecture() {
+    for path in [LIBSUM_X86_64_DYLIB, LIBSUM_AARCH64_DYLIB] {
+        let exports = open(path).exports().expect("Exports were not read");
+        let sum_of = exports
+            .iter()
+            .find(|it| it.name() == "_sum_of")
+            .expect("_sum_of was not exported");
+
+        assert!(!sum_of.is_reexport());
+        assert_ne!(0, sum_of.address());
+    }
+}
+
+#[test]
+pub fn macho_file_reads_rpaths_and_reexports() {
+    let data = synthetic_dylib();
+    let file = MachOFile::parse(&data).expect("Mach-O file was not parsed");
+
+    let install_name = file.install_name().expect("Install name was missing");
+    assert_eq!("@rpath/libsynthetic.dylib", install_name.name());
+    assert_eq!(DylibVersion(0x0002_0100), *install_name.current_version());
+
+    assert_eq!(&["@loader_path/../lib", "/opt/lib"], file.rpaths());
+
+    let dependencies = file.dependencies();
+    assert_eq!(LC_REEXPORT_DYLIB, dependencies[0].command());
+    assert_eq!(LC_LOAD_WEAK_DYLIB, dependencies[1].command());
+    assert!(dependencies[1].is_weak());
+
+    let exports = file.exports().expect("Exports were not read");
+    assert_eq!(2, exports.len());
+
+    assert_eq!("_alias", exports[0].name());
+    assert_eq!(EXPORT_SYMBOL_FLAGS_REEXPORT, exports[0].flags());
+    assert_eq!(Some((1, "_original")), exports[0].reexport());
+
+    assert_eq!("_local", exports[1].name());
+    assert_eq!(0x1234, exports[1].address());
+    assert_eq!(None, exports[1].reexport());
+}
+
+#[test]
+pub fn fat_file_parses_file_of_every_architecture() {
+    let data = fat_macho();
+    let fat = FatFile::parse(&data).expect("Fat file was not parsed");
+
+    let architectures = fat
+        .arches()
+        .iter()
+        .map(|it| it.architecture())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![Architecture::X86_64, Architecture::Aarch64],
+        architectures
+    );
+
+    for file in fat.files().expect("Files were not parsed") {
+        assert_eq!(
+            Some("libsum.dylib"),
+            file.install_name().map(|it| it.name())
+        );
+    }
+
+    let aarch64 = fat
+        .file(&Architecture::Aarch64)
+        .expect("aarch64 file was missing")
+        .expect("aarch64 file was not parsed");
+    assert_eq!(Architecture::Aarch64, aarch64.architecture());
+    assert!(fat.file(&Architecture::X86).is_none());
+}
+
+#[test]
+pub fn macho_file_fails_when_file_is_fat_or_not_macho() {
+    let fat = fat_macho();
+
+    assert!(matches!(
+        MachOFile::parse(&fat),
+        Err(ObjectError::UnknownFormat)
+    ));
+    assert!(matches!(
+        FatFile::open(LIBSUM_X86_64_DYLIB),
+        Err(ObjectError::UnknownFormat)
+    ));
+}
@@ -0,0 +1,8 @@
+mod elf;
+mod header;
+mod info;
+mod macho;
+mod pe;
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod compat;
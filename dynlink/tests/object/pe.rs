@@ -0,0 +1,210 @@
+use dynlink::object::{
+    pe::{ImportedSymbol, PeFile, IMAGE_FILE_DLL},
+    Architecture, ObjectError,
+};
+
+use crate::object::header::{
+    LIBSUM_AARCH64_DLL, LIBSUM_X86_64_DLL, LIBSUM_X86_64_SO, LIBSUM_X86_DLL,
+};
+
+pub const CODEVIEW_GUID: [u8; 16] = [
+    0xe0, 0x04, 0x25, 0x3f, 0x89, 0x4f, 0xd3, 0x11, 0x9a, 0x0c, 0x03, 0x05, 0xe8, 0x2c, 0x33, 0x01,
+];
+
+fn open(path: &str) -> PeFile<'static> {
+    PeFile::open(path).expect("PE file was not parsed")
+}
+
+/// Builds a PE32+ DLL with one section holding an export directory with a forwarded export
+/// and an export by ordinal only, an import directory with imports by name and by ordinal,
+/// and a debug directory with a CodeView record.
+pub fn synthetic_dll() -> Vec<u8> {
+    const SECTION: u32 = 0x1000;
+    const RAW: usize = 0x200;
+
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    let mut data = vec![0; RAW + 0x400];
+
+    // DOS header, PE signature and COFF header
+    put(&mut data, 0, b"MZ");
+    put(&mut data, 0x3c, &0x40_u32.to_le_bytes());
+    put(&mut data, 0x40, b"PE\0\0");
+    put(&mut data, 0x44, &0x8664_u16.to_le_bytes());
+    put(&mut data, 0x46, &1_u16.to_le_bytes());
+    put(&mut data, 0x54, &0xf0_u16.to_le_bytes());
+    put(&mut data, 0x56, &0x2022_u16.to_le_bytes());
+
+    // optional header with the export and import data directories
+    put(&mut data, 0x58, &0x20b_u16.to_le_bytes());
+    put(&mut data, 0x58 + 24, &0x1_8000_0000_u64.to_le_bytes());
+    put(&mut data, 0x58 + 108, &16_u32.to_le_bytes());
+    for (i, (rva, size)) in [(SECTION, 0x120_u32), (SECTION + 0x200, 40)]
+        .into_iter()
+        .enumerate()
+    {
+        put(&mut data, 0x58 + 112 + i * 8, &rva.to_le_bytes());
+        put(&mut data, 0x58 + 116 + i * 8, &size.to_le_bytes());
+    }
+
+    put(
+        &mut data,
+        0x58 + 112 + 6 * 8,
+        &(SECTION + 0x300).to_le_bytes(),
+    );
+    put(&mut data, 0x58 + 116 + 6 * 8, &28_u32.to_le_bytes());
+
+    // section header
+    put(&mut data, 0x148, b".rdata");
+    for (offset, field) in [(8, 0x400), (12, SECTION), (16, 0x400), (20, RAW as u32)] {
+        put(&mut data, 0x148 + offset, &field.to_le_bytes());
+    }
+
+    // export directory, address, name and ordinal tables and strings
+    for (offset, field) in [
+        (12, SECTION + 0x100),
+        (16, 1),
+        (20, 2),
+        (24, 1),
+        (28, SECTION + 0x40),
+        (32, SECTION + 0x50),
+        (36, SECTION + 0x60),
+    ] {
+        put(&mut data, RAW + offset, &field.to_le_bytes());
+    }
+    put(&mut data, RAW + 0x40, &(SECTION + 0x80).to_le_bytes());
+    put(&mut data, RAW + 0x44, &0x2000_u32.to_le_bytes());
+    put(&mut data, RAW + 0x50, &(SECTION + 0x110).to_le_bytes());
+    put(&mut data, RAW + 0x60, &0_u16.to_le_bytes());
+    put(&mut data, RAW + 0x80, b"KERNEL32.HeapAlloc\0");
+    put(&mut data, RAW + 0x100, b"synthetic.dll\0");
+    put(&mut data, RAW + 0x110, b"alloc\0");
+
+    // import descriptor followed by a zeroed one, lookup and address tables and strings
+    put(&mut data, RAW + 0x200, &(SECTION + 0x240).to_le_bytes());
+    put(&mut data, RAW + 0x20c, &(SECTION + 0x280).to_le_bytes());
+    put(&mut data, RAW + 0x210, &(SECTION + 0x260).to_le_bytes());
+    for table in [0x240, 0x260] {
+        put(
+            &mut data,
+            RAW + table,
+            &u64::from(SECTION + 0x290).to_le_bytes(),
+        );
+        put(&mut data, RAW + table + 8, &(1_u64 << 63 | 7).to_le_bytes());
+    }
+    put(&mut data, RAW + 0x280, b"user32.dll\0");
+    put(&mut data, RAW + 0x290, &5_u16.to_le_bytes());
+    put(&mut data, RAW + 0x292, b"MessageBoxA\0");
+
+    // debug directory entry and CodeView record with the GUID, age and PDB path
+    put(&mut data, RAW + 0x30c, &2_u32.to_le_bytes());
+    put(&mut data, RAW + 0x310, &0x26_u32.to_le_bytes());
+    put(&mut data, RAW + 0x314, &(SECTION + 0x320).to_le_bytes());
+    put(&mut data, RAW + 0x318, &(RAW as u32 + 0x320).to_le_bytes());
+    put(&mut data, RAW + 0x320, b"RSDS");
+    put(&mut data, RAW + 0x324, &CODEVIEW_GUID);
+    put(&mut data, RAW + 0x334, &3_u32.to_le_bytes());
+    put(&mut data, RAW + 0x338, b"synthetic.pdb\0");
+
+    data
+}
+
+#[test]
+pub fn pe_file_reads_headers_of_every_machine() {
+    for (path, architecture, is_64) in [
+        (LIBSUM_X86_DLL, Architecture::X86, false),
+        (LIBSUM_X86_64_DLL, Architecture::X86_64, true),
+        (LIBSUM_AARCH64_DLL, Architecture::Aarch64, true),
+    ] {
+        let file = open(path);
+
+        assert_eq!(is_64, file.is_64());
+        assert_eq!(architecture, file.architecture());
+        assert!(file.is_dll());
+        assert_ne!(0, file.characteristics() & IMAGE_FILE_DLL);
+        assert!(file.sections().iter().any(|it| it.name() == ".text"));
+    }
+}
+
+#[test]
+pub fn pe_file_reads_exports_of_every_machine() {
+    for path in [LIBSUM_X86_DLL, LIBSUM_X86_64_DLL, LIBSUM_AARCH64_DLL] {
+        let file = open(path);
+        assert_eq!(Some("sum.dll".to_owned()), file.dll_name().unwrap());
+
+        let exports = file.exports().expect("Exports were not read");
+        let sum_of = exports
+            .iter()
+            .find(|it| it.name() == Some("sum_of"))
+            .expect("sum_of was not exported");
+
+        assert_eq!(8, sum_of.ordinal());
+        assert_eq!(None, sum_of.forwarder());
+        assert_ne!(0, sum_of.rva());
+        assert!(file.imports().expect("Imports were not read").is_empty());
+    }
+}
+
+#[test]
+pub fn pe_file_reads_forwarders_and_exports_by_ordinal() {
+    let data = synthetic_dll();
+    let file = PeFile::parse(&data).expect("PE file was not parsed");
+
+    assert_eq!(0x1_8000_0000, file.image_base());
+    assert_eq!(Some("synthetic.dll".to_owned()), file.dll_name().unwrap());
+
+    let exports = file.exports().expect("Exports were not read");
+    assert_eq!(2, exports.len());
+
+    assert_eq!(1, exports[0].ordinal());
+    assert_eq!(Some("alloc"), exports[0].name());
+    assert_eq!(Some("KERNEL32.HeapAlloc"), exports[0].forwarder());
+
+    assert_eq!(2, exports[1].ordinal());
+    assert_eq!(None, exports[1].name());
+    assert_eq!(0x2000, exports[1].rva());
+}
+
+#[test]
+pub fn pe_file_reads_imports_by_name_and_ordinal() {
+    let data = synthetic_dll();
+    let imports = PeFile::parse(&data)
+        .and_then(|it| it.imports())
+        .expect("Imports were not read");
+
+    assert_eq!(1, imports.len());
+    assert_eq!("user32.dll", imports[0].dll());
+    assert_eq!(
+        &[
+            ImportedSymbol::Name(5, "MessageBoxA".to_owned()),
+            ImportedSymbol::Ordinal(7)
+        ],
+        imports[0].symbols()
+    );
+}
+
+#[test]
+pub fn pe_file_reads_codeview_record() {
+    let data = synthetic_dll();
+    let codeview = PeFile::parse(&data)
+        .and_then(|it| it.codeview())
+        .expect("CodeView record was not read")
+        .expect("CodeView record was missing");
+
+    assert_eq!(&CODEVIEW_GUID, codeview.guid());
+    assert_eq!(3, codeview.age());
+    assert_eq!("synthetic.pdb", codeview.path());
+
+    for path in [LIBSUM_X86_DLL, LIBSUM_X86_64_DLL, LIBSUM_AARCH64_DLL] {
+        assert!(open(path).codeview().unwrap().is_none());
+    }
+}
+
+#[test]
+pub fn pe_file_fails_when_file_is_not_pe() {
+    let err = PeFile::open(LIBSUM_X86_64_SO).expect_err("ELF file was parsed");
+
+    assert!(matches!(err, ObjectError::UnknownFormat));
+}
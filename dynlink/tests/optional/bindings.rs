@@ -0,0 +1,88 @@
+use dynlink::api::Handle;
+
+use crate::fixture::LIBSUM;
+
+dynlink::optional_bindings! {
+    pub struct LibSumFns {
+        pub sum_of: extern "C" fn(i32, i32) -> i32,
+        pub unknown: extern "C" fn(i32, i32) -> i32,
+    }
+}
+
+dynlink::optional_bindings! {
+    pub struct LibSumAliasedFns {
+        pub sum: extern "C" fn(i32, i32) -> i32 as ["sum_of_v2", "sum_of"],
+        pub renamed: extern "C" fn(i32, i32) -> i32 as ["renamed_v2", "renamed"],
+    }
+}
+
+dynlink::optional_bindings! {
+    pub struct LibSumNamedFns {
+        pub symbols: extern "C" fn(i32, i32) -> i32 as ["sum_of"],
+    }
+}
+
+#[test]
+pub fn optional_bindings_fills_present_symbols() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumFns::load(&lib);
+
+        let sum_fn = fns.sum_of.expect("Symbol was not found");
+        assert_eq!(2, sum_fn(1, 1));
+    }
+}
+
+#[test]
+pub fn optional_bindings_leaves_absent_symbols_empty() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumFns::load(&lib);
+
+        assert!(fns.unknown.is_none());
+    }
+}
+
+#[test]
+pub fn optional_bindings_reports_capabilities() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let capabilities = LibSumFns::load(&lib).capabilities();
+
+        assert_eq!(&["sum_of", "unknown"], LibSumFns::SYMBOLS);
+        assert_eq!(&["sum_of"], capabilities.present());
+        assert_eq!(&["unknown"], capabilities.missing());
+        assert!(capabilities.contains("sum_of"));
+        assert!(!capabilities.is_complete());
+    }
+}
+
+#[test]
+pub fn optional_bindings_fills_fields_by_first_alias_that_exists() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumAliasedFns::load(&lib);
+
+        let sum_fn = fns.sum.expect("Symbol was not found");
+        assert_eq!(2, sum_fn(1, 1));
+        assert!(fns.renamed.is_none());
+
+        let capabilities = fns.capabilities();
+        assert_eq!(&["sum"], capabilities.present());
+        assert_eq!(&["renamed"], capabilities.missing());
+        assert_eq!(Some("sum_of"), capabilities.resolved_as("sum"));
+        assert_eq!(&["sum", "renamed"], LibSumAliasedFns::SYMBOLS);
+    }
+}
+
+#[test]
+pub fn optional_bindings_declares_field_named_symbols() {
+    unsafe {
+        let lib = Handle::open(LIBSUM).expect("Shared object was not opened");
+        let fns = LibSumNamedFns::load(&lib);
+
+        let sum_fn = fns.symbols.expect("Symbol was not found");
+        assert_eq!(2, sum_fn(1, 1));
+        assert_eq!(&["symbols"], fns.capabilities().present());
+    }
+}
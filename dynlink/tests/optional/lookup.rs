@@ -0,0 +1,57 @@
+use dynlink::{api::Handle, optional};
+
+pub const LIBNULLALIAS: &str = "tests/resource/libnullalias-x86_64.so";
+
+dynlink::optional_bindings! {
+    pub struct LibNullAliasFns {
+        pub sum: extern "C" fn(i32, i32) -> i32 as ["sum_of_v2", "sum_of"],
+    }
+}
+
+#[test]
+pub fn lookup_first_skips_alias_with_null_address() {
+    unsafe {
+        let lib = Handle::open(LIBNULLALIAS).expect("Shared object was not opened");
+
+        assert!(optional::lookup::<extern "C" fn(i32, i32) -> i32>(&lib, "sum_of_v2").is_none());
+
+        let (name, sum_fn) = optional::lookup_first::<extern "C" fn(i32, i32) -> i32>(
+            &lib,
+            &["sum_of_v2", "sum_of"],
+        )
+        .expect("Symbol was not found");
+
+        assert_eq!("sum_of", name);
+        assert_eq!(2, sum_fn(1, 1));
+    }
+}
+
+#[test]
+pub fn optional_bindings_skip_alias_with_null_address() {
+    unsafe {
+        let lib = Handle::open(LIBNULLALIAS).expect("Shared object was not opened");
+        let fns = LibNullAliasFns::load(&lib);
+
+        let sum_fn = fns.sum.expect("Symbol was not found");
+        assert_eq!(2, sum_fn(1, 1));
+        assert_eq!(&["sum"], fns.capabilities().present());
+        assert_eq!(Some("sum_of"), fns.capabilities().resolved_as("sum"));
+    }
+}
+
+#[test]
+pub fn handle_lookup_first_records_alias_with_null_address_as_failed_attempt() {
+    unsafe {
+        let lib = Handle::open(LIBNULLALIAS).expect("Shared object was not opened");
+
+        let (name, _) = lib
+            .lookup_first::<extern "C" fn(i32, i32) -> i32>(&["sum_of_v2", "sum_of"])
+            .expect("Symbol was not found");
+        assert_eq!("sum_of", name);
+
+        let err = lib
+            .lookup_first::<extern "C" fn(i32, i32) -> i32>(&["sum_of_v2"])
+            .expect_err("Null symbol was found");
+        assert_eq!("sum_of_v2", err.attempts()[0].0);
+    }
+}
@@ -0,0 +1,63 @@
+use std::io;
+
+use dynlink::preflight::{self, ObjectError};
+
+use crate::fixture::LIBUNKNOWN;
+
+pub const LIBPREFLIGHT: &str = "tests/resource/libpreflight-x86_64.so";
+
+pub const LIBIMPORT: &str = "tests/resource/libimport-x86_64.so";
+
+#[test]
+pub fn check_resolves_dependencies_through_origin_runpath() {
+    let preflight = preflight::check(LIBPREFLIGHT).expect("Preflight was not checked");
+
+    let dependency = preflight
+        .root()
+        .needed()
+        .iter()
+        .find(|it| it.name() == "libpreflightdep-x86_64.so")
+        .expect("Dependency was not listed");
+
+    let path = dependency.path().expect("Dependency was not resolved");
+    assert!(path.ends_with("resource/libpreflightdep-x86_64.so"));
+
+    let libc = preflight
+        .root()
+        .needed()
+        .iter()
+        .find(|it| it.name() == "libc.so.6")
+        .expect("libc was not listed");
+
+    assert!(!libc.is_missing());
+}
+
+#[test]
+pub fn check_reports_missing_library_and_unresolved_symbols() {
+    let preflight = preflight::check(LIBPREFLIGHT).expect("Preflight was not checked");
+
+    assert!(!preflight.is_complete());
+    assert_eq!(&["libabsent.so"], preflight.missing());
+
+    let symbols = preflight
+        .unresolved()
+        .iter()
+        .map(|it| it.symbol())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec!["absent_of"], symbols);
+}
+
+#[test]
+pub fn check_reports_complete_tree_when_dependencies_exist() {
+    let preflight = preflight::check(LIBIMPORT).expect("Preflight was not checked");
+
+    assert!(preflight.is_complete(), "{}", preflight);
+}
+
+#[test]
+pub fn check_fails_when_path_does_not_exist() {
+    let err = preflight::check(LIBUNKNOWN).expect_err("Unknown preflight was checked");
+
+    assert!(matches!(err, ObjectError::Io(io::ErrorKind::NotFound)));
+}
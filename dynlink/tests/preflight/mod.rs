@@ -0,0 +1,2 @@
+#[cfg(target_arch = "x86_64")]
+mod check;
@@ -0,0 +1,174 @@
+use std::{env, fs, path, process, sync::Arc};
+
+use dynlink::registry;
+
+use crate::fixture::{LIBSUM, LIBUNKNOWN};
+
+/// Copies the fixture to a file with its own identity, so tests running
+/// concurrently don't share registry entries.
+fn copy_of_libsum(name: &str) -> path::PathBuf {
+    let extension = path::Path::new(LIBSUM)
+        .extension()
+        .expect("Fixture has no extension");
+
+    let copy = env::temp_dir()
+        .join(format!("dynlink-registry-{}-{}", process::id(), name))
+        .with_extension(extension);
+
+    fs::copy(LIBSUM, &copy).expect("Fixture was not copied");
+    copy
+}
+
+#[test]
+pub fn open_shared_returns_same_handle_when_path_spelled_differently() {
+    let path = path::Path::new(LIBSUM);
+    let parent = path.parent().expect("Fixture has no parent");
+    let respelled = parent
+        .join("..")
+        .join(parent.file_name().expect("Fixture parent has no name"))
+        .join(path.file_name().expect("Fixture has no name"));
+
+    unsafe {
+        let handle = registry::open_shared(path).expect("Handle was not opened");
+        let shared = registry::open_shared(&respelled).expect("Handle was not opened");
+
+        assert!(Arc::ptr_eq(&handle, &shared));
+    }
+}
+
+#[cfg(unix)]
+#[test]
+pub fn open_shared_returns_same_handle_when_file_hard_linked() {
+    let copy = copy_of_libsum("hardlink");
+    let link = copy.with_file_name(format!(
+        "{}-link",
+        copy.file_name().unwrap().to_string_lossy()
+    ));
+
+    let _ = fs::remove_file(&link);
+    fs::hard_link(&copy, &link).expect("Hard link was not created");
+
+    unsafe {
+        let handle = registry::open_shared(&copy).expect("Handle was not opened");
+        let shared = registry::open_shared(&link).expect("Handle was not opened");
+
+        assert!(Arc::ptr_eq(&handle, &shared));
+    }
+
+    let _ = fs::remove_file(&link);
+    let _ = fs::remove_file(&copy);
+}
+
+/// Removes the file when dropped, so it is cleaned up even when an assertion fails.
+#[cfg(target_os = "linux")]
+struct RemoveOnDrop(path::PathBuf);
+
+#[cfg(target_os = "linux")]
+impl Drop for RemoveOnDrop {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+pub fn open_shared_does_not_resolve_bare_name_against_current_directory() {
+    let extension = path::Path::new(LIBSUM)
+        .extension()
+        .expect("Fixture has no extension");
+
+    let directory = path::Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let copy = RemoveOnDrop(
+        directory
+            .join(format!("libdynlink-registry-{}-cwd", process::id()))
+            .with_extension(extension),
+    );
+
+    fs::copy(LIBSUM, &copy.0).expect("Fixture was not copied");
+
+    // the current directory is process wide, so the lookup runs in a child process started in
+    // the directory of the copy and without a search path that could point to it
+    let output = process::Command::new(env::current_exe().expect("Test path was not found"))
+        .args([
+            "--exact",
+            "registry::shared::open_shared_does_not_resolve_bare_name_when_run_by_child",
+            "--nocapture",
+            "--test-threads=1",
+        ])
+        .current_dir(directory)
+        .env_remove("LD_LIBRARY_PATH")
+        .env(
+            "DYNLINK_REGISTRY_BARE_NAME",
+            copy.0.file_name().expect("Copy has no name"),
+        )
+        .output()
+        .expect("Child process was not run");
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Opens the copy named by `DYNLINK_REGISTRY_BARE_NAME` from the current directory when run by
+/// `open_shared_does_not_resolve_bare_name_against_current_directory` as a child process.
+#[cfg(target_os = "linux")]
+#[test]
+pub fn open_shared_does_not_resolve_bare_name_when_run_by_child() {
+    let Some(bare) = env::var_os("DYNLINK_REGISTRY_BARE_NAME") else {
+        return;
+    };
+
+    // bare names are searched by the platform loader, which does not look into the current directory
+    let bare = path::PathBuf::from(bare);
+    let relative = path::Path::new(".").join(&bare);
+
+    unsafe {
+        let handle = registry::open_shared(&relative).expect("Handle was not opened");
+        let _ =
+            registry::open_shared(&bare).expect_err("Bare name was opened from current directory");
+
+        drop(handle);
+    }
+}
+
+#[test]
+pub fn entries_report_strong_count_of_shared_handle() {
+    let copy = copy_of_libsum("entries");
+    let canonical = fs::canonicalize(&copy).expect("Copy was not canonicalized");
+
+    let strong_count = || {
+        registry::entries()
+            .into_iter()
+            .find(|it| it.path() == canonical)
+            .map(|it| it.strong_count())
+    };
+
+    unsafe {
+        let handle = registry::open_shared(&copy).expect("Handle was not opened");
+        assert_eq!(Some(1), strong_count());
+
+        let shared = registry::open_shared(&copy).expect("Handle was not opened");
+        assert_eq!(Some(2), strong_count());
+
+        drop(handle);
+        assert_eq!(Some(1), strong_count());
+
+        drop(shared);
+        assert_eq!(None, strong_count());
+    }
+
+    let _ = fs::remove_file(&copy);
+}
+
+#[test]
+pub fn open_shared_fails_when_path_does_not_exist() {
+    unsafe {
+        let _ = registry::open_shared(LIBUNKNOWN).expect_err("Unknown handle was opened");
+    }
+
+    assert!(registry::entries()
+        .iter()
+        .all(|it| it.path() != path::Path::new(LIBUNKNOWN)));
+}
@@ -0,0 +1,36 @@
+// gcc -O0 -fPIC -shared -Wl,-soname,libcallback.so -s -o libcallback-x86_64.so callback.c
+
+#include <stddef.h>
+#include <stdint.h>
+
+int apply_leading(int (*cb)(void *, int), void *data, int value) {
+    return cb(data, value);
+}
+
+void for_each(const int32_t *values, size_t len, void (*cb)(int32_t, void *), void *data) {
+    for (size_t i = 0; i < len; i++) {
+        cb(values[i], data);
+    }
+}
+
+int apply_bare(int (*cb)(int), int value) {
+    return cb(value);
+}
+
+int64_t apply_many(int64_t (*cb)(int64_t, int64_t, int64_t, int64_t, int64_t, int64_t, int64_t, int64_t)) {
+    return cb(1, 2, 3, 4, 5, 6, 7, 8);
+}
+
+double apply_mixed(double (*cb)(int8_t, float, int64_t, double), int8_t a, float b, int64_t c, double d) {
+    return cb(a, b, c, d);
+}
+
+int apply_until(int (*cb)(int), int limit) {
+    int count = 0;
+
+    while (count < limit && cb(count)) {
+        count++;
+    }
+
+    return count;
+}
@@ -0,0 +1,5 @@
+// gcc -O0 -fPIC -fexceptions -shared -Wl,-soname,libcatching.so -s -o libcatching-x86_64.so catching.c
+
+int plugin_run(int (*cb)(int), int value) {
+    return cb(value) + 1;
+}
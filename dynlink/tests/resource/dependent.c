@@ -0,0 +1,7 @@
+// gcc -O0 -fPIC -shared -Wl,-soname,libdependent.so -s -o libdependent-x86_64.so dependent.c libneeded-x86_64.so
+
+int needed_of(void);
+
+int dependent_of(void) {
+    return needed_of() + 1;
+}
@@ -0,0 +1,110 @@
+// gcc -O0 -fPIC -shared -Wl,-soname,libdyncall.so -s -o libdyncall-x86_64.so dyncall.c
+
+#include <stdarg.h>
+#include <stdint.h>
+
+struct point {
+    int32_t x;
+    int32_t y;
+};
+
+struct vec2 {
+    double x;
+    double y;
+};
+
+struct rgb {
+    float r;
+    float g;
+    float b;
+};
+
+struct tagged {
+    int32_t tag;
+    double value;
+};
+
+struct big {
+    int64_t a;
+    int64_t b;
+    int64_t c;
+};
+
+int8_t negate_i8(int8_t a) {
+    return -a;
+}
+
+uint16_t widen_u8(uint8_t a, uint8_t b) {
+    return (uint16_t) a * b;
+}
+
+int64_t sum_i64x10(int64_t a, int64_t b, int64_t c, int64_t d, int64_t e,
+                   int64_t f, int64_t g, int64_t h, int64_t i, int64_t j) {
+    return a + b + c + d + e + f + g + h + i + j;
+}
+
+double sum_f64x10(double a, double b, double c, double d, double e,
+                  double f, double g, double h, double i, double j) {
+    return a + b + c + d + e + f + g + h + i + j;
+}
+
+double mix_of(int8_t a, float b, int64_t c, double d, uint32_t e) {
+    return a + b + c + d + e;
+}
+
+float halve_f32(float a) {
+    return a / 2;
+}
+
+void store_i32(int32_t *out, int32_t value) {
+    *out = value;
+}
+
+struct point point_add(struct point a, struct point b) {
+    struct point sum = { a.x + b.x, a.y + b.y };
+    return sum;
+}
+
+struct vec2 vec2_scale(struct vec2 v, double k) {
+    struct vec2 scaled = { v.x * k, v.y * k };
+    return scaled;
+}
+
+struct rgb rgb_gray(struct rgb c) {
+    float gray = (c.r + c.g + c.b) / 3;
+    struct rgb result = { gray, gray, gray };
+    return result;
+}
+
+struct tagged tagged_make(int32_t tag, double value) {
+    struct tagged result = { tag, value };
+    return result;
+}
+
+struct big big_make(int64_t a, int64_t b, int64_t c) {
+    struct big result = { a, b, c };
+    return result;
+}
+
+int64_t big_sum(struct big b) {
+    return b.a + b.b + b.c;
+}
+
+// passes the struct on the stack once the integer registers are taken
+int64_t point_sum_after(int64_t a, int64_t b, int64_t c, int64_t d, int64_t e, int64_t f,
+                        struct point p) {
+    return a + b + c + d + e + f + p.x + p.y;
+}
+
+double sum_variadic(int32_t count, ...) {
+    va_list args;
+    va_start(args, count);
+
+    double sum = 0;
+    for (int32_t i = 0; i < count; i++) {
+        sum += va_arg(args, double);
+    }
+
+    va_end(args);
+    return sum;
+}
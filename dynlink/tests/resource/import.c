@@ -0,0 +1,7 @@
+// gcc -O0 -fno-builtin -fPIC -shared -Wl,-z,relro,-z,now -Wl,-soname,libimport.so -s -o libimport-x86_64.so import.c
+
+#include <stdlib.h>
+
+int parse_of(const char *s) {
+    return atoi(s);
+}
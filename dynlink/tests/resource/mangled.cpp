@@ -0,0 +1,45 @@
+// g++ -O0 -fPIC -shared -Wl,-soname,libmangled.so -s -o libmangled-x86_64.so mangled.cpp
+
+namespace geometry {
+class Shape {
+  public:
+    static int area(int side);
+    static int area(int width, int height);
+    static double scale(double factor);
+};
+
+int Shape::area(int side) {
+    return side * side;
+}
+
+int Shape::area(int width, int height) {
+    return width * height;
+}
+
+double Shape::scale(double factor) {
+    return factor * 2.0;
+}
+} // namespace geometry
+
+// symbols mangled as Rust would mangle `mycrate::module::func` (v0),
+// `mycrate::module::legacy` (legacy) and `mycrate::twin` (legacy, two hashes)
+extern "C" int rust_v0_func(int a) __asm__("_RNvNtCs1234_7mycrate6module4func");
+extern "C" int rust_legacy_func(int a) __asm__("_ZN7mycrate6module6legacy17h0123456789abcdefE");
+extern "C" int rust_twin_a(void) __asm__("_ZN7mycrate4twin17h5a1b2c3d4e5f6071E");
+extern "C" int rust_twin_b(void) __asm__("_ZN7mycrate4twin17h9f8e7d6c5b4a3921E");
+
+extern "C" int rust_v0_func(int a) {
+    return a + 1;
+}
+
+extern "C" int rust_legacy_func(int a) {
+    return a + 2;
+}
+
+extern "C" int rust_twin_a(void) {
+    return 1;
+}
+
+extern "C" int rust_twin_b(void) {
+    return 2;
+}
@@ -0,0 +1,5 @@
+// gcc -O0 -fPIC -shared -Wl,-soname,libneeded.so -s -o libneeded-x86_64.so needed.c
+
+int needed_of(void) {
+    return 1;
+}
@@ -0,0 +1,5 @@
+// gcc -O0 -fPIC -shared -Wl,-z,nodelete -Wl,-soname,libnodelete.so -s -o libnodelete-x86_64.so nodelete.c
+
+int nodelete_of(void) {
+    return 1;
+}
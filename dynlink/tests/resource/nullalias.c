@@ -0,0 +1,8 @@
+// gcc -O0 -fPIC -shared -Wl,-soname,libnullalias.so -s -o libnullalias-x86_64.so nullalias.c
+
+// sum_of_v2 is an absolute symbol with a null address, as left by a disabled implementation
+__asm__(".globl sum_of_v2\n.type sum_of_v2, @function\n.set sum_of_v2, 0");
+
+int sum_of(int a, int b) {
+    return a + b;
+}
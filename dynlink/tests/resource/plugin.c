@@ -0,0 +1,19 @@
+// gcc -O0 -fPIC -shared -Wl,-soname,libplugin.so -s -o libplugin-x86_64.so plugin.c
+
+int plugin_cmd_start(void) {
+    return 1;
+}
+
+int plugin_cmd_stop(void) {
+    return 2;
+}
+
+int plugin_cmd_status(void) {
+    return 3;
+}
+
+int plugin_init(void) {
+    return 0;
+}
+
+int plugin_version = 4;
@@ -0,0 +1,16 @@
+// echo 'int absent_of(void) { return 0; }' | gcc -x c -fPIC -shared -Wl,-soname,libabsent.so -o /tmp/libabsent.so -
+// gcc -O0 -fPIC -shared -Wl,-soname,libpreflight.so -Wl,--enable-new-dtags,-rpath,'$ORIGIN' -s -o libpreflight-x86_64.so preflight.c libpreflightdep-x86_64.so /tmp/libabsent.so
+
+#include <stdlib.h>
+
+int dep_of(void);
+int absent_of(void);
+
+// preflight must only read the file, so this constructor never runs
+__attribute__((constructor)) static void preflight_init(void) {
+    abort();
+}
+
+int preflight_of(void) {
+    return dep_of() + absent_of();
+}
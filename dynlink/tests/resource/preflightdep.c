@@ -0,0 +1,5 @@
+// gcc -O0 -fPIC -shared -Wl,-soname,libpreflightdep-x86_64.so -s -o libpreflightdep-x86_64.so preflightdep.c
+
+int dep_of(void) {
+    return 1;
+}
@@ -0,0 +1,11 @@
+// g++ -O0 -fPIC -shared -Wl,-soname,libunique.so -s -o libunique-x86_64.so unique.cpp
+
+template <typename T> struct Counter {
+    static int value;
+};
+
+template <typename T> int Counter<T>::value = 1;
+
+extern "C" int unique_of(void) {
+    return Counter<int>::value;
+}
@@ -0,0 +1,5 @@
+// gcc -O0 -fPIC -shared -Wl,-soname,libunload.so -s -o libunload-x86_64.so unload.c
+
+int unload_of(void) {
+    return 1;
+}
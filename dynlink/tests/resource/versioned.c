@@ -0,0 +1,9 @@
+// gcc -O0 -fPIC -shared -Wl,--version-script=versioned.map -Wl,--build-id=sha1 -Wl,-soname,libversioned.so -Wl,-rpath,/opt/versioned/lib -s -o libversioned-x86_64.so versioned.c
+
+int versioned_of(void) {
+    return 1;
+}
+
+int versioned_of_v2(void) {
+    return 2;
+}
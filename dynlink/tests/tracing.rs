@@ -0,0 +1,84 @@
+#![cfg(any(
+    all(
+        target_os = "linux",
+        any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
+    ),
+    all(
+        target_os = "macos",
+        any(target_arch = "aarch64", target_arch = "x86_64"),
+    ),
+    all(
+        target_os = "windows",
+        any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
+    ),
+))]
+
+#[path = "fixture/mod.rs"]
+#[allow(dead_code)]
+mod fixture;
+
+use std::sync::{Arc, Mutex};
+
+use dynlink::api::Handle;
+
+use crate::fixture::{LIBSUM, SYMBOL_SUM};
+
+struct RecordingSubscriber(Arc<Mutex<Vec<String>>>);
+
+struct MessageVisitor<'a>(&'a mut Vec<String>);
+
+impl tracing::field::Visit for MessageVisitor<'_> {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.push(format!("{:?}", value));
+        }
+    }
+}
+
+impl tracing::Subscriber for RecordingSubscriber {
+    fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        tracing::span::Id::from_u64(1)
+    }
+
+    fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+    fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let metadata = event.metadata();
+
+        assert_eq!("dynlink::loader", metadata.target());
+        assert!(metadata.fields().field("path").is_some());
+        assert!(metadata.fields().field("elapsed").is_some());
+
+        event.record(&mut MessageVisitor(&mut self.0.lock().unwrap()));
+    }
+
+    fn enter(&self, _: &tracing::span::Id) {}
+
+    fn exit(&self, _: &tracing::span::Id) {}
+}
+
+#[test]
+pub fn tracing_hooks_emit_open_lookup_and_close_events() {
+    let messages = Arc::new(Mutex::new(Vec::new()));
+
+    tracing::subscriber::with_default(RecordingSubscriber(messages.clone()), || unsafe {
+        let handle = Handle::open(LIBSUM).expect("Handle was not opened");
+
+        let _ = handle
+            .lookup::<extern "C" fn(i32, i32) -> i32>(SYMBOL_SUM)
+            .expect("Symbol was not found");
+
+        drop(handle);
+    });
+
+    assert_eq!(
+        vec!["opened", "looked up", "closed"],
+        *messages.lock().unwrap()
+    );
+}
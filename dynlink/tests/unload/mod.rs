@@ -0,0 +1,2 @@
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod verify;
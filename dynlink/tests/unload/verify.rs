@@ -0,0 +1,78 @@
+use dynlink::{
+    api::Handle,
+    unload::{Residency, UnloadError},
+};
+
+pub const LIBUNLOAD: &str = "tests/resource/libunload-x86_64.so";
+
+pub const LIBNEEDED: &str = "tests/resource/libneeded-x86_64.so";
+
+pub const LIBDEPENDENT: &str = "tests/resource/libdependent-x86_64.so";
+
+pub const LIBNODELETE: &str = "tests/resource/libnodelete-x86_64.so";
+
+pub const LIBUNIQUE: &str = "tests/resource/libunique-x86_64.so";
+
+fn resident_reasons(result: Result<(), UnloadError>) -> Vec<Residency> {
+    match result {
+        Err(UnloadError::Resident(reasons)) => reasons,
+        other => panic!("Shared object was not resident: {:?}", other),
+    }
+}
+
+#[test]
+pub fn close_and_verify_reports_referenced_until_last_handle_closed() {
+    unsafe {
+        let handle = Handle::open(LIBUNLOAD).expect("Handle was not opened");
+        let other = Handle::open(LIBUNLOAD).expect("Handle was not opened");
+
+        let reasons = resident_reasons(handle.close_and_verify());
+        assert!(matches!(reasons.as_slice(), [Residency::Referenced]));
+
+        other
+            .close_and_verify()
+            .expect("Shared object was not unloaded");
+    }
+}
+
+#[test]
+pub fn close_and_verify_reports_dependents_when_needed_by_other_object() {
+    unsafe {
+        let needed = Handle::open(LIBNEEDED).expect("Handle was not opened");
+        let dependent = Handle::open(LIBDEPENDENT).expect("Handle was not opened");
+
+        let reasons = resident_reasons(needed.close_and_verify());
+        assert!(reasons.iter().any(|it| match it {
+            Residency::Dependents(names) => names
+                .iter()
+                .any(|name| name.to_bytes().ends_with(b"libdependent-x86_64.so")),
+            _ => false,
+        }));
+
+        dependent
+            .close_and_verify()
+            .expect("Shared object was not unloaded");
+    }
+}
+
+#[test]
+pub fn close_and_verify_reports_nodelete_when_marked_as_nodelete() {
+    unsafe {
+        let handle = Handle::open(LIBNODELETE).expect("Handle was not opened");
+
+        let reasons = resident_reasons(handle.close_and_verify());
+        assert!(reasons.iter().any(|it| matches!(it, Residency::NoDelete)));
+    }
+}
+
+#[test]
+pub fn close_and_verify_reports_unique_symbols_when_defined() {
+    unsafe {
+        let handle = Handle::open(LIBUNIQUE).expect("Handle was not opened");
+
+        let reasons = resident_reasons(handle.close_and_verify());
+        assert!(reasons
+            .iter()
+            .any(|it| matches!(it, Residency::UniqueSymbols)));
+    }
+}
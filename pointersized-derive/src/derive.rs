@@ -0,0 +1,95 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Expands `#[derive(PointerSized)]` of `input`.
+pub(crate) fn expand(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    if !is_transparent(&input.attrs)? {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "PointerSized can only be derived for #[repr(transparent)] structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        syn::Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "PointerSized can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut pointers = fields.iter().filter(|it| !is_phantom_data(&it.ty));
+
+    let field = match (pointers.next(), pointers.next()) {
+        (Some(field), None) => field,
+        _ => return Err(syn::Error::new_spanned(
+            fields,
+            "PointerSized can only be derived for structs with one field that is not PhantomData",
+        )),
+    };
+
+    let name = &input.ident;
+    let field_ty = &field.ty;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut where_clause = where_clause
+        .cloned()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+
+    where_clause
+        .predicates
+        .push(syn::parse_quote!(#field_ty: ::pointersized::PointerSized));
+
+    // generic structs are checked by the field bound only, since constants can't use their parameters
+    let size_assertion = if input.generics.params.is_empty() {
+        quote! {
+            const _: () = <#name as ::pointersized::PointerSized>::ASSERT_POINTER_SIZED;
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::pointersized::sealed::Sealed for #name #ty_generics #where_clause {}
+
+        // SAFETY: a transparent struct has the layout of its only pointer-sized field.
+        unsafe impl #impl_generics ::pointersized::PointerSized for #name #ty_generics #where_clause {}
+
+        #size_assertion
+    })
+}
+
+/// Checks whether `attrs` contain `#[repr(transparent)]`.
+fn is_transparent(attrs: &[syn::Attribute]) -> syn::Result<bool> {
+    let mut is_transparent = false;
+
+    for attr in attrs.iter().filter(|it| it.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("transparent") {
+                is_transparent = true;
+            } else if meta.input.peek(syn::token::Paren) {
+                // skips arguments of other representations, e.g. `align(8)`
+                let _args;
+                syn::parenthesized!(_args in meta.input);
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(is_transparent)
+}
+
+/// Checks whether `ty` is a path to `PhantomData`.
+fn is_phantom_data(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|it| it.ident == "PhantomData"),
+        _ => false,
+    }
+}
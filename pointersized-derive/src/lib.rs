@@ -0,0 +1,24 @@
+//! Marking of pointer-sized types. Derive macro.
+//!
+//! This library provides `#[derive(PointerSized)]` for `#[repr(transparent)]` newtypes
+//! of pointer-sized types and is re-exported by the `pointersized` crate.
+
+mod derive;
+
+use proc_macro::TokenStream;
+
+/// Derives `PointerSized` for a `#[repr(transparent)]` struct whose only non-`PhantomData`
+/// field is pointer-sized.
+///
+/// The field type is required to implement `PointerSized`, and the size of non-generic
+/// structs is asserted to be the size of a pointer at compile time. The derive also implements
+/// the sealing trait of `PointerSized`, which is the only supported way to implement it.
+#[proc_macro_derive(PointerSized)]
+pub fn derive_pointer_sized(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match derive::expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
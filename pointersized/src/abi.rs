@@ -0,0 +1,81 @@
+use std::fmt;
+
+/// Represents the calling convention of a function pointer.
+///
+/// `Abi::Rust` indicates the default Rust ABI of `fn` and `unsafe fn`.
+/// `Abi::C` and `Abi::CUnwind` indicate the `C` and `C-unwind` ABIs.
+/// `Abi::System` and `Abi::SystemUnwind` indicate the `system` and `system-unwind` ABIs.
+/// `Abi::SysV64` and `Abi::Win64` indicate the `sysv64` and `win64` ABIs of x86_64 targets.
+/// `Abi::Aapcs` indicates the `aapcs` ABI of ARM targets.
+/// `Abi::EfiApi` indicates the `efiapi` ABI of UEFI functions.
+pub enum Abi {
+    Rust,
+    C,
+    CUnwind,
+    System,
+    SystemUnwind,
+    SysV64,
+    Win64,
+    Aapcs,
+    EfiApi,
+}
+
+impl Abi {
+    /// Returns the name of the ABI as written in `extern` declarations.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Rust => "Rust",
+            Self::C => "C",
+            Self::CUnwind => "C-unwind",
+            Self::System => "system",
+            Self::SystemUnwind => "system-unwind",
+            Self::SysV64 => "sysv64",
+            Self::Win64 => "win64",
+            Self::Aapcs => "aapcs",
+            Self::EfiApi => "efiapi",
+        }
+    }
+
+    /// Checks whether a panic or foreign exception may unwind out of functions of the ABI.
+    pub const fn is_unwind(&self) -> bool {
+        matches!(self, Self::Rust | Self::CUnwind | Self::SystemUnwind)
+    }
+}
+
+impl Clone for Abi {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for Abi {}
+
+impl PartialEq for Abi {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for Abi {}
+
+impl fmt::Debug for Abi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rust => f.write_str("Rust"),
+            Self::C => f.write_str("C"),
+            Self::CUnwind => f.write_str("CUnwind"),
+            Self::System => f.write_str("System"),
+            Self::SystemUnwind => f.write_str("SystemUnwind"),
+            Self::SysV64 => f.write_str("SysV64"),
+            Self::Win64 => f.write_str("Win64"),
+            Self::Aapcs => f.write_str("Aapcs"),
+            Self::EfiApi => f.write_str("EfiApi"),
+        }
+    }
+}
+
+impl fmt::Display for Abi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("extern {:?}", self.name()))
+    }
+}
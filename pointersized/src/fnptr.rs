@@ -0,0 +1,125 @@
+use crate::{Abi, PointerSized};
+
+/// Function pointer introspection.
+///
+/// This trait is implemented for the same functions as `PointerSized`, except C variadic
+/// functions, and describes their arguments, return type and ABI at the type level.
+///
+/// # Example
+///
+/// ```
+/// use pointersized::{Abi, FnPtr};
+///
+/// extern "C" fn sum_of(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// fn describe<F: FnPtr>(_: F) -> String {
+///     format!("{} fn with {} arguments", F::ABI, F::ARITY)
+/// }
+///
+/// fn main() {
+///     let sum_fn = sum_of as extern "C" fn(i32, i32) -> i32;
+///
+///     assert_eq!(Abi::C, <extern "C" fn(i32, i32) -> i32 as FnPtr>::ABI);
+///     assert_eq!("extern \"C\" fn with 2 arguments", describe(sum_fn));
+///     assert_eq!(2, unsafe { sum_fn.call((1, 1)) });
+/// }
+/// ```
+pub trait FnPtr: PointerSized + Copy {
+    /// Tuple of the argument types.
+    type Args;
+
+    /// Return type.
+    type Output;
+
+    /// Number of arguments.
+    const ARITY: usize;
+
+    /// Calling convention.
+    const ABI: Abi;
+
+    /// Calls the function with the arguments of the `args` tuple.
+    ///
+    /// # Safety
+    ///
+    /// The function must be safe to call with `args`, which is required for `unsafe` functions
+    /// and for functions resolved from shared objects whose signatures are not checked.
+    unsafe fn call(self, args: Self::Args) -> Self::Output;
+}
+
+#[cfg(test)]
+mod unittest {
+    use std::any;
+
+    use crate::{Abi, FnPtr};
+
+    extern "C" fn sum_of(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    unsafe fn negate(a: i64) -> i64 {
+        -a
+    }
+
+    fn answer() -> u8 {
+        42
+    }
+
+    pub fn type_name_of_args<F: FnPtr>() -> &'static str {
+        any::type_name::<F::Args>()
+    }
+
+    pub fn type_name_of_output<F: FnPtr>() -> &'static str {
+        any::type_name::<F::Output>()
+    }
+
+    #[test]
+    pub fn functions_describe_arguments_and_output() {
+        assert_eq!(
+            "(i32, i32)",
+            type_name_of_args::<extern "C" fn(i32, i32) -> i32>()
+        );
+        assert_eq!("(i64,)", type_name_of_args::<unsafe fn(i64) -> i64>());
+        assert_eq!("()", type_name_of_args::<fn()>());
+
+        assert_eq!(
+            "i32",
+            type_name_of_output::<extern "C" fn(i32, i32) -> i32>()
+        );
+        assert_eq!("()", type_name_of_output::<fn()>());
+    }
+
+    #[test]
+    pub fn functions_describe_arity() {
+        assert_eq!(0, <fn() as FnPtr>::ARITY);
+        assert_eq!(1, <unsafe fn(i64) -> i64 as FnPtr>::ARITY);
+        assert_eq!(2, <extern "C" fn(i32, i32) -> i32 as FnPtr>::ARITY);
+    }
+
+    #[test]
+    pub fn functions_describe_abi() {
+        assert_eq!(Abi::Rust, <fn() as FnPtr>::ABI);
+        assert_eq!(Abi::Rust, <unsafe fn() as FnPtr>::ABI);
+        assert_eq!(Abi::C, <extern "C" fn() as FnPtr>::ABI);
+        assert_eq!(Abi::C, <unsafe extern "C" fn() as FnPtr>::ABI);
+        assert_eq!(Abi::CUnwind, <extern "C-unwind" fn() as FnPtr>::ABI);
+        assert_eq!(Abi::System, <extern "system" fn() as FnPtr>::ABI);
+        assert_eq!(
+            Abi::SystemUnwind,
+            <extern "system-unwind" fn() as FnPtr>::ABI
+        );
+
+        assert!(<extern "C-unwind" fn() as FnPtr>::ABI.is_unwind());
+        assert!(!<extern "C" fn() as FnPtr>::ABI.is_unwind());
+    }
+
+    #[test]
+    pub fn functions_are_called_with_argument_tuples() {
+        unsafe {
+            assert_eq!(2, (sum_of as extern "C" fn(i32, i32) -> i32).call((1, 1)));
+            assert_eq!(-1, (negate as unsafe fn(i64) -> i64).call((1,)));
+            assert_eq!(42, (answer as fn() -> u8).call(()));
+        }
+    }
+}
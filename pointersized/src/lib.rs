@@ -1,5 +1,12 @@
 //! Marking of pointer-sized types.
 
+mod abi;
+mod fnptr;
 mod pointersized;
+#[doc(hidden)]
+pub mod sealed;
 
+pub use abi::Abi;
+pub use fnptr::FnPtr;
 pub use pointersized::PointerSized;
+pub use pointersized_derive::PointerSized;
@@ -1,98 +1,189 @@
-use std::ffi;
+use std::{mem, ptr};
+
+use crate::{sealed::Sealed, Abi, FnPtr};
 
 /// Pointer-sized types marker.
 ///
-/// This trait is implemented for opaque c-pointers and functions.
+/// This trait is implemented for raw pointers, `NonNull` pointers and functions
+/// (including C variadic functions) with up to 32 arguments, and for `Option` of
+/// `NonNull` pointers and functions, which have the same size due to the null-pointer niche.
+///
+/// Functions are covered for the Rust, `C`, `C-unwind`, `system` and `system-unwind` ABIs
+/// on every target, and for the `sysv64`, `win64`, `aapcs` and `efiapi` ABIs on the targets
+/// that support them.
+///
+/// `#[repr(transparent)]` newtypes of pointer-sized types implement it with `#[derive(PointerSized)]`,
+/// which is the supported way for other crates. The trait is sealed only by convention:
+/// the hidden `sealed::Sealed` supertrait is public for the derive, so other crates can still
+/// implement both traits manually, which is unsupported.
+///
+/// # Safety
+///
+/// Implementing types must have the size of a pointer, since symbol addresses are read as them.
+/// `PointerSized::ASSERT_POINTER_SIZED` fails to evaluate otherwise, which is reported when
+/// a function asserting it (e.g. `Symbol::apply` or `Symbol::leak` of `dynlink`) is instantiated
+/// for the type, so it is caught by `cargo build` but not by `cargo check`.
 ///
 /// # Example
 ///
 /// ```no_run
-/// use std::ffi;
+/// use std::{ffi, ptr};
 ///
 /// use pointersized::PointerSized;
 ///
+/// #[derive(PointerSized)]
+/// #[repr(transparent)]
+/// struct Callback(extern "C" fn(i32) -> i32);
+///
 /// fn assert_pointer_sized<T: PointerSized>() {}
 ///
 /// fn main() {
 ///     assert_pointer_sized::<*const ffi::c_void>();
-///     assert_pointer_sized::<*mut ffi::c_void>();
+///     assert_pointer_sized::<*mut i32>();
+///     assert_pointer_sized::<ptr::NonNull<u8>>();
 ///     assert_pointer_sized::<fn(i32, i32) -> i32>();
+///     assert_pointer_sized::<Option<extern "C" fn(i32, i32) -> i32>>();
+///     assert_pointer_sized::<unsafe extern "C" fn(*const ffi::c_char, ...) -> ffi::c_int>();
+///     assert_pointer_sized::<Callback>();
 /// }
 /// ```
-pub trait PointerSized {}
-
-impl PointerSized for *const ffi::c_void {}
-impl PointerSized for *mut ffi::c_void {}
-
-macro_rules! impl_pointer_sized {
-    ($($abi:tt)*) => {
-        impl<R> PointerSized for $($abi)*() -> R {}
-
-        impl<R, A1> PointerSized for $($abi)*(A1) -> R {}
-
-        impl<R, A1, A2> PointerSized for $($abi)*(A1, A2) -> R {}
-
-        impl<R, A1, A2, A3> PointerSized for $($abi)*(A1, A2, A3) -> R {}
+pub unsafe trait PointerSized: Sized + Sealed {
+    /// Asserts that the size of the type is the size of a pointer when evaluated.
+    #[doc(hidden)]
+    const ASSERT_POINTER_SIZED: () = assert!(
+        mem::size_of::<Self>() == mem::size_of::<*const ()>(),
+        "PointerSized type must have the size of a pointer"
+    );
+}
 
-        impl<R, A1, A2, A3, A4> PointerSized for $($abi)*(A1, A2, A3, A4) -> R {}
+macro_rules! impl_sealed_pointer_sized {
+    (impl<$($param:ident),* $(,)?> for $ty:ty) => {
+        impl<$($param),*> Sealed for $ty {}
+        unsafe impl<$($param),*> PointerSized for $ty {}
+    };
+}
 
-        impl<R, A1, A2, A3, A4, A5> PointerSized for $($abi)*(A1, A2, A3, A4, A5) -> R {}
+impl_sealed_pointer_sized!(impl<T> for *const T);
+impl_sealed_pointer_sized!(impl<T> for *mut T);
+impl_sealed_pointer_sized!(impl<T> for ptr::NonNull<T>);
+impl_sealed_pointer_sized!(impl<T> for Option<ptr::NonNull<T>>);
 
-        impl<R, A1, A2, A3, A4, A5, A6> PointerSized for $($abi)*(A1, A2, A3, A4, A5, A6) -> R {}
+macro_rules! impl_pointer_sized {
+    (@arities $kind:ident [$($abi:tt)*] [$($arg:ident)*]) => {
+        impl_pointer_sized!(@impl $kind [$($abi)*] $($arg)*);
+    };
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7> PointerSized for $($abi)*(A1, A2, A3, A4, A5, A6, A7) -> R {}
+    (@arities $kind:ident [$($abi:tt)*] [$($arg:ident)*] $next:ident $($rest:ident)*) => {
+        impl_pointer_sized!(@impl $kind [$($abi)*] $($arg)*);
+        impl_pointer_sized!(@arities $kind [$($abi)*] [$($arg)* $next] $($rest)*);
+    };
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7, A8> PointerSized for $($abi)*(
-            A1, A2, A3, A4, A5, A6, A7, A8
-        ) -> R {}
+    (@impl $kind:ident [$($abi:tt)*] $($arg:ident)*) => {
+        impl_sealed_pointer_sized!(impl<R, $($arg),*> for $($abi)*($($arg),*) -> R);
+        impl_sealed_pointer_sized!(impl<R, $($arg),*> for Option<$($abi)*($($arg),*) -> R>);
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7, A8, A9> PointerSized for $($abi)*(
-            A1, A2, A3, A4, A5, A6, A7, A8, A9
-        ) -> R {}
+        impl<R, $($arg),*> FnPtr for $($abi)*($($arg),*) -> R {
+            type Args = ($($arg,)*);
+            type Output = R;
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10> PointerSized for $($abi)*(
-            A1, A2, A3, A4, A5, A6, A7, A8, A9, A10
-        ) -> R {}
+            const ARITY: usize = <[&str]>::len(&[$(stringify!($arg)),*]);
+            const ABI: Abi = Abi::$kind;
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11> PointerSized for $($abi)*(
-            A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11
-        ) -> R {}
+            #[inline]
+            #[allow(non_snake_case, unused_unsafe)]
+            unsafe fn call(self, ($($arg,)*): Self::Args) -> R {
+                unsafe { self($($arg),*) }
+            }
+        }
+    };
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12> PointerSized for $($abi)*(
-            A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12
-        ) -> R {}
+    ($kind:ident => $($abi:tt)*) => {
+        impl_pointer_sized!(
+            @arities $kind [$($abi)*] []
+            A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16
+            A17 A18 A19 A20 A21 A22 A23 A24 A25 A26 A27 A28 A29 A30 A31 A32
+        );
+    };
+}
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13> PointerSized for $($abi)*(
-            A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13
-        ) -> R {}
+macro_rules! impl_pointer_sized_variadic {
+    (@arities [$($abi:tt)*] [$($arg:ident)*]) => {};
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14> PointerSized for $($abi)*(
-            A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14
-        ) -> R {}
+    (@arities [$($abi:tt)*] [$($arg:ident)*] $next:ident $($rest:ident)*) => {
+        // C variadic functions have at least one named argument
+        impl_sealed_pointer_sized!(impl<R, $($arg,)* $next> for $($abi)*($($arg,)* $next, ...) -> R);
+        impl_sealed_pointer_sized!(impl<R, $($arg,)* $next> for Option<$($abi)*($($arg,)* $next, ...) -> R>);
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15> PointerSized for $($abi)*(
-            A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15
-        ) -> R {}
+        impl_pointer_sized_variadic!(@arities [$($abi)*] [$($arg)* $next] $($rest)*);
+    };
 
-        impl<R, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16> PointerSized for $($abi)*(
-            A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16
-        ) -> R {}
+    ($($abi:tt)*) => {
+        impl_pointer_sized_variadic!(
+            @arities [$($abi)*] []
+            A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16
+            A17 A18 A19 A20 A21 A22 A23 A24 A25 A26 A27 A28 A29 A30 A31 A32
+        );
     };
 }
 
-impl_pointer_sized!(fn);
-impl_pointer_sized!(extern "C" fn);
-impl_pointer_sized!(unsafe extern "C" fn);
-impl_pointer_sized!(extern "system" fn);
-impl_pointer_sized!(unsafe extern "system" fn);
+impl_pointer_sized!(Rust => fn);
+impl_pointer_sized!(Rust => unsafe fn);
+impl_pointer_sized!(C => extern "C" fn);
+impl_pointer_sized!(C => unsafe extern "C" fn);
+impl_pointer_sized!(CUnwind => extern "C-unwind" fn);
+impl_pointer_sized!(CUnwind => unsafe extern "C-unwind" fn);
+impl_pointer_sized!(System => extern "system" fn);
+impl_pointer_sized!(System => unsafe extern "system" fn);
+impl_pointer_sized!(SystemUnwind => extern "system-unwind" fn);
+impl_pointer_sized!(SystemUnwind => unsafe extern "system-unwind" fn);
+
+#[cfg(target_arch = "x86_64")]
+impl_pointer_sized!(SysV64 => extern "sysv64" fn);
+#[cfg(target_arch = "x86_64")]
+impl_pointer_sized!(SysV64 => unsafe extern "sysv64" fn);
+#[cfg(target_arch = "x86_64")]
+impl_pointer_sized!(Win64 => extern "win64" fn);
+#[cfg(target_arch = "x86_64")]
+impl_pointer_sized!(Win64 => unsafe extern "win64" fn);
+
+#[cfg(target_arch = "arm")]
+impl_pointer_sized!(Aapcs => extern "aapcs" fn);
+#[cfg(target_arch = "arm")]
+impl_pointer_sized!(Aapcs => unsafe extern "aapcs" fn);
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "riscv32",
+    target_arch = "riscv64"
+))]
+impl_pointer_sized!(EfiApi => extern "efiapi" fn);
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "riscv32",
+    target_arch = "riscv64"
+))]
+impl_pointer_sized!(EfiApi => unsafe extern "efiapi" fn);
+
+impl_pointer_sized_variadic!(extern "C" fn);
+impl_pointer_sized_variadic!(unsafe extern "C" fn);
+impl_pointer_sized_variadic!(extern "C-unwind" fn);
+impl_pointer_sized_variadic!(unsafe extern "C-unwind" fn);
 
 #[cfg(test)]
 mod unittest {
-    use std::ffi;
+    use std::{ffi, ptr};
 
     use crate::PointerSized;
 
-    pub const fn assert_pointer_sized<T: PointerSized>() {}
+    pub const fn assert_pointer_sized<T: PointerSized>() {
+        T::ASSERT_POINTER_SIZED
+    }
 
     #[test]
     pub fn const_raw_pointer_marked_as_pointer_sized() {
@@ -104,6 +195,57 @@ mod unittest {
         assert_pointer_sized::<*mut ffi::c_void>();
     }
 
+    #[test]
+    pub fn typed_raw_pointers_marked_as_pointer_sized() {
+        assert_pointer_sized::<*const i32>();
+        assert_pointer_sized::<*mut u8>();
+        assert_pointer_sized::<*const *mut ffi::c_char>();
+        assert_pointer_sized::<*mut [u8; 16]>();
+    }
+
+    #[test]
+    pub fn non_null_pointers_marked_as_pointer_sized() {
+        assert_pointer_sized::<ptr::NonNull<i32>>();
+        assert_pointer_sized::<Option<ptr::NonNull<ffi::c_void>>>();
+    }
+
+    #[test]
+    pub fn optional_functions_marked_as_pointer_sized() {
+        assert_pointer_sized::<Option<fn()>>();
+        assert_pointer_sized::<Option<extern "C" fn(i32, i32) -> i32>>();
+        assert_pointer_sized::<Option<unsafe extern "C" fn(i32) -> i32>>();
+        assert_pointer_sized::<Option<extern "system" fn(i32) -> i32>>();
+        assert_pointer_sized::<Option<unsafe extern "system" fn()>>();
+    }
+
+    #[test]
+    pub fn variadic_functions_marked_as_pointer_sized() {
+        assert_pointer_sized::<extern "C" fn(*const ffi::c_char, ...) -> ffi::c_int>();
+        assert_pointer_sized::<unsafe extern "C" fn(*const ffi::c_char, ...) -> ffi::c_int>();
+        assert_pointer_sized::<unsafe extern "C" fn(i32, i32, ...)>();
+        assert_pointer_sized::<Option<unsafe extern "C" fn(*const ffi::c_char, ...) -> ffi::c_int>>(
+        );
+    }
+
+    #[test]
+    pub fn functions_with_32_arguments_marked_as_pointer_sized() {
+        #[rustfmt::skip]
+        type Fn32<R> = fn(
+            u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+            u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+        ) -> R;
+
+        #[rustfmt::skip]
+        type CFn32<R> = unsafe extern "C" fn(
+            u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+            u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+        ) -> R;
+
+        assert_pointer_sized::<Fn32<i32>>();
+        assert_pointer_sized::<CFn32<i32>>();
+        assert_pointer_sized::<Option<CFn32<()>>>();
+    }
+
     #[test]
     pub fn functions_marked_as_pointer_sized() {
         assert_pointer_sized::<fn()>();
@@ -169,4 +311,60 @@ mod unittest {
         assert_pointer_sized::<extern "system" fn(i32, i32, i32, i32, i32, i32) -> i32>();
         assert_pointer_sized::<unsafe extern "system" fn(i32, i32, i32, i32, i32, i32) -> i32>();
     }
+
+    #[test]
+    pub fn unsafe_functions_marked_as_pointer_sized() {
+        assert_pointer_sized::<unsafe fn()>();
+        assert_pointer_sized::<unsafe fn(i32) -> i32>();
+        assert_pointer_sized::<unsafe fn(i32, i32) -> i32>();
+        assert_pointer_sized::<Option<unsafe fn(i32, i32) -> i32>>();
+    }
+
+    #[test]
+    pub fn unwinding_abi_functions_marked_as_pointer_sized() {
+        assert_pointer_sized::<extern "C-unwind" fn()>();
+        assert_pointer_sized::<unsafe extern "C-unwind" fn(i32) -> i32>();
+        assert_pointer_sized::<Option<extern "C-unwind" fn(i32, i32) -> i32>>();
+        assert_pointer_sized::<unsafe extern "C-unwind" fn(*const ffi::c_char, ...) -> ffi::c_int>(
+        );
+
+        assert_pointer_sized::<extern "system-unwind" fn()>();
+        assert_pointer_sized::<unsafe extern "system-unwind" fn(i32) -> i32>();
+        assert_pointer_sized::<Option<extern "system-unwind" fn(i32, i32) -> i32>>();
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    pub fn x86_64_abi_functions_marked_as_pointer_sized() {
+        assert_pointer_sized::<extern "sysv64" fn(i32) -> i32>();
+        assert_pointer_sized::<unsafe extern "sysv64" fn(i32, i32) -> i32>();
+        assert_pointer_sized::<Option<extern "sysv64" fn()>>();
+
+        assert_pointer_sized::<extern "win64" fn(i32) -> i32>();
+        assert_pointer_sized::<unsafe extern "win64" fn(i32, i32) -> i32>();
+        assert_pointer_sized::<Option<extern "win64" fn()>>();
+    }
+
+    #[cfg(target_arch = "arm")]
+    #[test]
+    pub fn arm_abi_functions_marked_as_pointer_sized() {
+        assert_pointer_sized::<extern "aapcs" fn(i32) -> i32>();
+        assert_pointer_sized::<unsafe extern "aapcs" fn(i32, i32) -> i32>();
+        assert_pointer_sized::<Option<extern "aapcs" fn()>>();
+    }
+
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv32",
+        target_arch = "riscv64"
+    ))]
+    #[test]
+    pub fn efi_abi_functions_marked_as_pointer_sized() {
+        assert_pointer_sized::<extern "efiapi" fn(i32) -> i32>();
+        assert_pointer_sized::<unsafe extern "efiapi" fn(i32, i32) -> i32>();
+        assert_pointer_sized::<Option<extern "efiapi" fn()>>();
+    }
 }
@@ -0,0 +1,4 @@
+/// Seals `PointerSized`, so it is implemented by this crate and `#[derive(PointerSized)]`.
+///
+/// This trait is public for the derive and is not a public API, it must not be implemented manually.
+pub trait Sealed {}
@@ -0,0 +1,9 @@
+use pointersized::PointerSized;
+
+#[derive(PointerSized)]
+#[repr(transparent)]
+pub enum Callback {
+    Fn(extern "C" fn(i32) -> i32),
+}
+
+fn main() {}
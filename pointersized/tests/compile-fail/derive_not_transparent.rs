@@ -0,0 +1,6 @@
+use pointersized::PointerSized;
+
+#[derive(PointerSized)]
+pub struct Callback(extern "C" fn(i32) -> i32);
+
+fn main() {}
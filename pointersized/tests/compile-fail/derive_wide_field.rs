@@ -0,0 +1,7 @@
+use pointersized::PointerSized;
+
+#[derive(PointerSized)]
+#[repr(transparent)]
+pub struct Wide([u8; 16]);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use pointersized::PointerSized;
+
+pub struct Wide([u8; 16]);
+
+impl pointersized::sealed::Sealed for Wide {}
+
+unsafe impl PointerSized for Wide {}
+
+const _: () = <Wide as PointerSized>::ASSERT_POINTER_SIZED;
+
+fn main() {}
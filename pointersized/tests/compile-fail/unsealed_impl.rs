@@ -0,0 +1,7 @@
+use pointersized::PointerSized;
+
+pub struct Wide([u8; 16]);
+
+unsafe impl PointerSized for Wide {}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[test]
+pub fn bad_pointer_sized_impls_are_rejected() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile-fail/*.rs");
+}
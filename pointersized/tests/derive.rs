@@ -0,0 +1,41 @@
+use std::{ffi, marker::PhantomData, mem, ptr};
+
+use pointersized::PointerSized;
+
+pub const fn assert_pointer_sized<T: PointerSized>() {}
+
+#[derive(PointerSized)]
+#[repr(transparent)]
+pub struct Callback(extern "C" fn(i32) -> i32);
+
+#[derive(PointerSized)]
+#[repr(transparent)]
+pub struct Context {
+    pub raw: *mut ffi::c_void,
+}
+
+#[derive(PointerSized)]
+#[repr(transparent)]
+pub struct Typed<T>(ptr::NonNull<T>, PhantomData<T>);
+
+#[derive(PointerSized)]
+#[repr(transparent)]
+pub struct Nested(Callback);
+
+#[test]
+pub fn derive_marks_transparent_newtypes_as_pointer_sized() {
+    assert_pointer_sized::<Callback>();
+    assert_pointer_sized::<Context>();
+    assert_pointer_sized::<Nested>();
+}
+
+#[test]
+pub fn derive_marks_generic_transparent_newtypes_as_pointer_sized() {
+    assert_pointer_sized::<Typed<i32>>();
+    assert_pointer_sized::<Typed<[u8; 64]>>();
+
+    assert_eq!(
+        mem::size_of::<*const ()>(),
+        mem::size_of::<Typed<[u8; 64]>>()
+    );
+}